@@ -4,14 +4,14 @@
 
 set -e
 
-VERSION="0.1.8"
-
-echo "Building tauri-mcp DXT package v${VERSION} with Node.js wrapper (flat structure)..."
-
 # Ensure we have a release build
 echo "Building release binary..."
 cargo build --release
 
+VERSION="$(./target/release/tauri-mcp --version | awk '{print $2}')"
+
+echo "Building tauri-mcp DXT package v${VERSION} with Node.js wrapper (flat structure)..."
+
 # Create clean dxt-package directory
 rm -rf dxt-package-flat/
 mkdir -p dxt-package-flat/
@@ -25,6 +25,7 @@ chmod +x dxt-package-flat/tauri-mcp
 echo "Copying Node.js files..."
 cp server/index.js dxt-package-flat/
 cp server/package.json dxt-package-flat/
+(cd dxt-package-flat && npm pkg set version="${VERSION}")
 
 # Install dependencies
 echo "Installing Node.js dependencies..."
@@ -32,8 +33,8 @@ cd dxt-package-flat/
 npm install --production
 cd ..
 
-# Copy manifest
-cp manifest-node-flat.json dxt-package-flat/manifest.json
+# Generate manifest from the binary's tool definitions
+./target/release/tauri-mcp manifest --server node-flat --package-version "${VERSION}" --output dxt-package-flat/manifest.json
 
 # Create the DXT archive
 echo "Creating DXT archive..."
@@ -4,14 +4,14 @@
 
 set -e
 
-VERSION="0.1.8"
-
-echo "Building tauri-mcp DXT package v${VERSION} with Node.js wrapper..."
-
 # Ensure we have a release build
 echo "Building release binary..."
 cargo build --release
 
+VERSION="$(./target/release/tauri-mcp --version | awk '{print $2}')"
+
+echo "Building tauri-mcp DXT package v${VERSION} with Node.js wrapper..."
+
 # Create clean dxt-package directory
 rm -rf dxt-package-node/
 mkdir -p dxt-package-node/server
@@ -25,6 +25,7 @@ chmod +x dxt-package-node/tauri-mcp
 echo "Copying Node.js server..."
 cp server/package.json dxt-package-node/server/
 cp server/index.js dxt-package-node/server/
+(cd dxt-package-node/server && npm pkg set version="${VERSION}")
 
 # Install dependencies
 echo "Installing Node.js dependencies..."
@@ -32,8 +33,8 @@ cd dxt-package-node/server
 npm install --production
 cd ../..
 
-# Copy manifest
-cp manifest-node.json dxt-package-node/manifest.json
+# Generate manifest from the binary's tool definitions
+./target/release/tauri-mcp manifest --server node --package-version "${VERSION}" --output dxt-package-node/manifest.json
 
 # Create the DXT archive
 echo "Creating DXT archive..."
@@ -4,14 +4,14 @@
 
 set -e
 
-VERSION="0.1.5"
-
-echo "Building tauri-mcp DXT package v${VERSION}..."
-
 # Ensure we have a release build
 echo "Building release binary..."
 cargo build --release
 
+VERSION="$(./target/release/tauri-mcp --version | awk '{print $2}')"
+
+echo "Building tauri-mcp DXT package v${VERSION}..."
+
 # Create clean dxt-package directory
 rm -rf dxt-package/
 mkdir -p dxt-package/
@@ -21,32 +21,9 @@ echo "Copying binary..."
 cp target/release/tauri-mcp dxt-package/
 chmod +x dxt-package/tauri-mcp
 
-# Create manifest.json
+# Generate manifest from the binary's tool definitions
 echo "Creating manifest..."
-cat > dxt-package/manifest.json << EOF
-{
-  "dxt_version": "0.1",
-  "name": "tauri-mcp",
-  "version": "${VERSION}",
-  "description": "MCP server for testing and interacting with Tauri v2 applications",
-  "author": {
-    "name": "David Irvine",
-    "email": "david.irvine@maidsafe.net"
-  },
-  "server": {
-    "type": "binary",
-    "entry_point": "tauri-mcp",
-    "mcp_config": {
-      "command": "\${__dirname}/tauri-mcp",
-      "args": [],
-      "env": {
-        "TAURI_MCP_LOG_LEVEL": "info"
-      }
-    }
-  },
-  "license": "MIT OR Apache-2.0"
-}
-EOF
+./target/release/tauri-mcp manifest --server binary --package-version "${VERSION}" --output dxt-package/manifest.json
 
 # Create the DXT archive
 echo "Creating DXT archive..."
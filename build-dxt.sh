@@ -41,8 +41,8 @@ if [[ "$OSTYPE" == "linux-gnu"* ]]; then
     fi
 fi
 
-# Copy manifest
-cp manifest.json dist/
+# Generate manifest from the binary's tool definitions
+cargo run --release -- manifest --server binary --output dist/manifest.json
 
 # Create the DXT archive
 cd dist
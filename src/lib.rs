@@ -1,3 +1,4 @@
+pub mod manifest;
 pub mod server;
 pub mod tools;
 pub mod utils;
@@ -1,6 +1,11 @@
 use clap::Parser;
 use std::path::PathBuf;
-use tauri_mcp::{server::TauriMcpServer, Result};
+use tauri_mcp::{
+    manifest::{dxt_manifest, tools_manifest, ManifestOptions, ServerKind, ALL_PLATFORMS},
+    server::{ServerConfig, TauriMcpServer},
+    tools::diagnostics::run_environment_checks,
+    Result,
+};
 use tracing::Level;
 use tracing_subscriber::{self, EnvFilter};
 
@@ -33,11 +38,24 @@ EXAMPLES:
     
     # With debug logging
     tauri-mcp --log-level debug serve
+    
+    # Check that the environment is ready (display, input, WebDriver, DevTools)
+    tauri-mcp doctor
+    
+    # Generate the DXT manifest for the Node.js wrapper package
+    tauri-mcp manifest --server node --output dxt-package-node/manifest.json
 
 AVAILABLE TOOLS:
-    • launch_app       - Launch a Tauri application
+    • launch_app       - Launch a Tauri application or project
+    • build_app        - Build a project, return diagnostics
     • stop_app         - Stop a running app
     • get_app_logs     - Get stdout/stderr logs
+    • search_app_logs  - Search logs by regex, level and time
+    • wait_for_log     - Wait for a log line matching a regex
+    • write_stdin      - Write to an app's stdin
+    • list_processes   - Managed apps and their log files
+    • get_process_status - Exit status, crashes and panics
+    • list_crashes     - Parsed panic and crash reports across restarts
     • take_screenshot  - Capture app window
     • get_window_info  - Get window dimensions and state
     • send_keyboard_input - Send keyboard input
@@ -45,8 +63,20 @@ AVAILABLE TOOLS:
     • execute_js       - Execute JavaScript in webview
     • get_devtools_info - Get DevTools connection info
     • monitor_resources - Monitor CPU/memory usage
+    • dump_threads     - Thread states and backtraces of a hung app
+    • sample_resources - Sample CPU/memory over time with stats and leak detection
+    • set_resource_alerts - Alert when RSS, CPU, FDs or threads cross a threshold
+    • get_alerts       - Resource alert history
+    • measure_startup  - Time startup to window, first log and DOMContentLoaded over N runs
+    • list_app_data    - App config/data/cache/log directories and their files
+    • read_app_data_file - Read an app data file with JSON/SQLite previews
+    • snapshot_app_data - Copy an app's data directories aside
+    • reset_app_data   - Clear an app's data directories, optionally restoring a snapshot
+    • fs_snapshot      - Record files, sizes, mtimes and checksums of directories
+    • fs_diff          - Created, modified and deleted files since a snapshot, with diffs
     • list_ipc_handlers - List Tauri IPC commands
     • call_ipc_command - Call Tauri IPC commands
+    • server_status    - Server, process and environment status
 ")]
 struct Args {
     #[command(subcommand)]
@@ -80,6 +110,28 @@ enum Command {
         #[arg(help = "JSON arguments for the tool")]
         args: String,
     },
+    #[command(about = "Check environment readiness and print remediation hints")]
+    Doctor {
+        #[arg(long, help = "Print the report as JSON")]
+        json: bool,
+    },
+    #[command(about = "Generate the DXT manifest or tool list from the built-in tool definitions")]
+    Manifest {
+        #[arg(long, value_enum, default_value = "node", help = "How the packaged server is started")]
+        server: ServerKind,
+        
+        #[arg(long, value_name = "VERSION", help = "Package version (defaults to the crate version)")]
+        package_version: Option<String>,
+        
+        #[arg(long = "platform", value_name = "PLATFORM", value_parser = ALL_PLATFORMS.to_vec(), help = "Supported platform, repeatable (defaults to all)")]
+        platforms: Vec<String>,
+        
+        #[arg(long, help = "Emit the tool list with input schemas instead of the DXT manifest")]
+        tools: bool,
+        
+        #[arg(long, short, value_name = "FILE", help = "Write to a file instead of stdout")]
+        output: Option<PathBuf>,
+    },
 }
 
 #[tokio::main]
@@ -107,6 +159,61 @@ async fn main() -> Result<()> {
         .compact()
         .init();
     
+    // Manifest generation only needs the static tool definitions
+    if let Some(Command::Manifest { server, package_version, platforms, tools, output }) = args.command {
+        let mut options = ManifestOptions {
+            server,
+            ..ManifestOptions::default()
+        };
+        if let Some(version) = package_version {
+            options.package_version = version;
+        }
+        if !platforms.is_empty() {
+            options.platforms = platforms;
+        }
+        
+        let manifest = if tools { tools_manifest(&options) } else { dxt_manifest(&options) };
+        let content = serde_json::to_string_pretty(&manifest)?;
+        
+        match output {
+            Some(path) => tokio::fs::write(path, content + "\n").await?,
+            None => println!("{}", content),
+        }
+        return Ok(());
+    }
+    
+    // Doctor runs standalone so it still works when the server could not start
+    if let Some(Command::Doctor { json }) = args.command {
+        let config = ServerConfig::load(&args.config).await?;
+        let checks = run_environment_checks().await;
+        
+        if json {
+            let report = serde_json::json!({
+                "version": env!("CARGO_PKG_VERSION"),
+                "config_path": args.config,
+                "config": config,
+                "checks": checks,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            println!("tauri-mcp {}", env!("CARGO_PKG_VERSION"));
+            println!("config: {} ({})", args.config.display(), if args.config.exists() { "loaded" } else { "defaults" });
+            println!("{}", toml::to_string(&config).map_err(|e| tauri_mcp::TauriMcpError::ConfigError(e.to_string()))?);
+            
+            for check in &checks {
+                let mark = if check.ok { "ok  " } else { "FAIL" };
+                println!("[{}] {:<10} {}", mark, check.name, check.detail);
+                if let Some(remediation) = &check.remediation {
+                    println!("       {:<10} hint: {}", "", remediation);
+                }
+            }
+            
+            let passed = checks.iter().filter(|c| c.ok).count();
+            println!("\n{} of {} checks passed", passed, checks.len());
+        }
+        return Ok(());
+    }
+    
     let server = TauriMcpServer::new(args.config).await?;
     
     match args.command {
@@ -120,6 +227,7 @@ async fn main() -> Result<()> {
             // Print JSON result to stdout for Node.js wrapper
             println!("{}", serde_json::to_string(&result)?);
         }
+        Some(Command::Manifest { .. }) | Some(Command::Doctor { .. }) => unreachable!("handled before server startup"),
         None => {
             // Default to serve mode without printing anything
             server.serve("127.0.0.1", 3000).await?;
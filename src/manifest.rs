@@ -0,0 +1,86 @@
+use crate::tools::definitions::tool_definitions;
+use serde_json::{json, Value};
+
+/// Platforms a DXT package can declare compatibility with.
+pub const ALL_PLATFORMS: &[&str] = &["darwin", "linux", "win32"];
+
+/// How the DXT package starts the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ServerKind {
+    /// Run the Rust binary directly
+    Binary,
+    /// Run the Node.js wrapper from `server/index.js`
+    Node,
+    /// Run the Node.js wrapper from `index.js` at the package root
+    NodeFlat,
+}
+
+#[derive(Debug, Clone)]
+pub struct ManifestOptions {
+    pub server: ServerKind,
+    pub package_version: String,
+    pub platforms: Vec<String>,
+}
+
+impl Default for ManifestOptions {
+    fn default() -> Self {
+        Self {
+            server: ServerKind::Node,
+            package_version: env!("CARGO_PKG_VERSION").to_string(),
+            platforms: ALL_PLATFORMS.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+}
+
+/// Build the DXT `manifest.json` for the given packaging options.
+pub fn dxt_manifest(options: &ManifestOptions) -> Value {
+    let (server_type, entry_point, command, args) = match options.server {
+        ServerKind::Binary => ("binary", "tauri-mcp", "${__dirname}/tauri-mcp", vec!["serve"]),
+        ServerKind::Node => ("node", "server/index.js", "node", vec!["${__dirname}/server/index.js"]),
+        ServerKind::NodeFlat => ("node", "index.js", "node", vec!["${__dirname}/index.js"]),
+    };
+    
+    let tools: Vec<Value> = tool_definitions()
+        .iter()
+        .map(|tool| json!({
+            "name": tool["name"],
+            "description": tool["description"],
+        }))
+        .collect();
+    
+    json!({
+        "dxt_version": "0.1",
+        "name": "tauri-mcp",
+        "version": options.package_version,
+        "description": env!("CARGO_PKG_DESCRIPTION"),
+        "author": {
+            "name": "David Irvine",
+            "email": "david.irvine@maidsafe.net"
+        },
+        "server": {
+            "type": server_type,
+            "entry_point": entry_point,
+            "mcp_config": {
+                "command": command,
+                "args": args,
+                "env": {
+                    "TAURI_MCP_LOG_LEVEL": "info"
+                }
+            }
+        },
+        "tools": tools,
+        "compatibility": {
+            "platforms": options.platforms,
+        },
+        "license": env!("CARGO_PKG_LICENSE")
+    })
+}
+
+/// Tool list with full input schemas, as consumed by the Node.js wrapper.
+pub fn tools_manifest(options: &ManifestOptions) -> Value {
+    json!({
+        "name": "tauri-mcp",
+        "version": options.package_version,
+        "tools": tool_definitions(),
+    })
+}
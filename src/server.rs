@@ -1,17 +1,33 @@
 use crate::{Result, TauriMcpError};
 use crate::tools::{
-    process::ProcessManager,
+    process::{LaunchOptions, ProcessManager, StopOptions, StopReport},
+    supervisor::ProcessEvent,
+    logs::{log_files_in, LogFileOptions, LogQuery, LogSearch},
+    log_sources::LogSource,
+    build::{build_project, BuildOptions, BuildReport, TauriProject},
+    sampling::{sample_resources, SampleOptions},
+    alerts::AlertThresholds,
+    startup::{measure_startup, StartupOptions},
+    threads::{dump_threads, BacktraceTool},
+    crashes::list_crashes,
+    app_data::{list_app_data, read_app_data_file, reset_app_data, snapshot_app_data, AppDataTarget, ListAppDataOptions},
+    fs_snapshot::{fs_diff, fs_snapshot, FsSnapshotOptions, FsSnapshots},
+    ready::{wait_for_log, wait_until_ready, LogWaitOptions, ReadyOptions},
     window::WindowManager,
     input::InputSimulator,
     debug::DebugTools,
     ipc::IpcManager,
+    definitions::{tool_definitions, tool_names},
+    diagnostics::run_environment_checks,
 };
 use jsonrpc_core::{IoHandler, Params, Value, Error as RpcError};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Instant;
+use tokio::sync::{broadcast, RwLock};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tracing::{debug, error, info};
 
@@ -21,7 +37,10 @@ pub struct TauriMcpServer {
     input_simulator: Arc<InputSimulator>,
     debug_tools: Arc<DebugTools>,
     ipc_manager: Arc<IpcManager>,
+    fs_snapshots: Arc<parking_lot::Mutex<FsSnapshots>>,
     config: ServerConfig,
+    started_at: Instant,
+    protocol_version: Arc<parking_lot::RwLock<Option<String>>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +50,28 @@ pub struct ServerConfig {
     pub event_streaming: bool,
     pub performance_profiling: bool,
     pub network_interception: bool,
+    /// Write every launched app's output to `log_dir`; `persist_logs` on
+    /// `launch_app` overrides this per launch
+    #[serde(default)]
+    pub persist_logs: bool,
+    /// Defaults to `tauri-mcp/logs` under the system temp directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_dir: Option<PathBuf>,
+    #[serde(default = "default_log_max_bytes")]
+    pub log_max_bytes: u64,
+    #[serde(default = "default_log_max_files")]
+    pub log_max_files: usize,
+    /// Named `launch_app` argument sets, e.g. `[profiles.dev]`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, Value>,
+}
+
+fn default_log_max_bytes() -> u64 {
+    10 * 1024 * 1024
+}
+
+fn default_log_max_files() -> usize {
+    5
 }
 
 impl Default for ServerConfig {
@@ -41,19 +82,69 @@ impl Default for ServerConfig {
             event_streaming: false,
             performance_profiling: false,
             network_interception: false,
+            persist_logs: false,
+            log_dir: None,
+            log_max_bytes: default_log_max_bytes(),
+            log_max_files: default_log_max_files(),
+            profiles: HashMap::new(),
         }
     }
 }
 
+impl ServerConfig {
+    /// Load the config file, falling back to defaults when it does not exist.
+    pub async fn load(config_path: &Path) -> Result<Self> {
+        if config_path.exists() {
+            let config_str = tokio::fs::read_to_string(config_path).await?;
+            toml::from_str(&config_str).map_err(|e| TauriMcpError::ConfigError(e.to_string()))
+        } else {
+            Ok(ServerConfig::default())
+        }
+    }
+    
+    pub fn log_directory(&self) -> PathBuf {
+        self.log_dir.clone().unwrap_or_else(|| std::env::temp_dir().join("tauri-mcp").join("logs"))
+    }
+    
+    /// Log file settings for a launch, when its output is to be persisted.
+    pub fn log_file_options(&self, arguments: &Value) -> Option<LogFileOptions> {
+        let persist = arguments.get("persist_logs").and_then(|v| v.as_bool()).unwrap_or(self.persist_logs);
+        persist.then(|| LogFileOptions {
+            dir: self.log_directory(),
+            max_bytes: self.log_max_bytes,
+            max_files: self.log_max_files.max(1),
+        })
+    }
+    
+    /// `launch_app` arguments with the settings of the named `profile`
+    /// filled in underneath. Explicit arguments win; `env` is merged per variable.
+    pub fn launch_arguments(&self, arguments: &Value) -> Result<Value> {
+        let Some(name) = arguments.get("profile").and_then(|v| v.as_str()) else {
+            return Ok(arguments.clone());
+        };
+        let mut merged = self.profiles.get(name)
+            .and_then(|profile| profile.as_object())
+            .cloned()
+            .ok_or_else(|| TauriMcpError::ConfigError(format!("Unknown launch profile: {}", name)))?;
+        
+        for (key, value) in arguments.as_object().into_iter().flatten() {
+            match (merged.get_mut(key), value) {
+                (Some(Value::Object(base)), Value::Object(overrides)) if key == "env" => {
+                    base.extend(overrides.clone());
+                }
+                _ => {
+                    merged.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        
+        Ok(Value::Object(merged))
+    }
+}
 
 impl TauriMcpServer {
     pub async fn new(config_path: PathBuf) -> Result<Self> {
-        let config = if config_path.exists() {
-            let config_str = tokio::fs::read_to_string(&config_path).await?;
-            toml::from_str(&config_str).map_err(|e| TauriMcpError::ConfigError(e.to_string()))?
-        } else {
-            ServerConfig::default()
-        };
+        let config = ServerConfig::load(&config_path).await?;
         
         debug!("Initializing Tauri MCP server with config: {:?}", config);
         
@@ -63,7 +154,10 @@ impl TauriMcpServer {
             input_simulator: Arc::new(InputSimulator::new()),
             debug_tools: Arc::new(DebugTools::new()),
             ipc_manager: Arc::new(IpcManager::new()),
+            fs_snapshots: Arc::new(parking_lot::Mutex::new(FsSnapshots::new())),
             config,
+            started_at: Instant::now(),
+            protocol_version: Arc::new(parking_lot::RwLock::new(None)),
         })
     }
     
@@ -78,6 +172,10 @@ impl TauriMcpServer {
             input_simulator: Arc::clone(&self.input_simulator),
             debug_tools: Arc::clone(&self.debug_tools),
             ipc_manager: Arc::clone(&self.ipc_manager),
+            fs_snapshots: Arc::clone(&self.fs_snapshots),
+            config: self.config.clone(),
+            started_at: self.started_at,
+            protocol_version: Arc::clone(&self.protocol_version),
         };
         
         // Register all methods manually to handle MCP's named parameters
@@ -118,48 +216,52 @@ impl TauriMcpServer {
             async move { server.list_tools() }
         });
         
+        let server_clone = server.clone();
+        io.add_method("resources/list", move |_params: Params| {
+            let server = server_clone.clone();
+            async move { server.list_resources() }
+        });
+        
+        let server_clone = server.clone();
+        io.add_method("resources/read", move |params: Params| {
+            let server = server_clone.clone();
+            async move {
+                match params {
+                    Params::Map(map) => match map.get("uri").and_then(|v| v.as_str()) {
+                        Some(uri) => server.read_resource(uri),
+                        None => Err(RpcError::invalid_params("Missing uri")),
+                    },
+                    _ => Err(RpcError::invalid_params("Expected object parameters"))
+                }
+            }
+        });
+        
         let server_clone = server.clone();
         io.add_method("tools/call", move |params: Params| {
             let server = server_clone.clone();
             async move {
                 match params {
-                    Params::Map(map) => server.call_tool(Value::Object(map)),
+                    // Tool methods block on async work; keep them off the reactor
+                    Params::Map(map) => tokio::task::block_in_place(|| server.call_tool(Value::Object(map))),
                     _ => Err(RpcError::invalid_params("Expected object parameters"))
                 }
             }
         });
         
         // Register all other tool methods
-        let tool_methods = vec![
-            ("launch_app", "app_path", "args"),
-            ("stop_app", "process_id", ""),
-            ("get_app_logs", "process_id", "lines"),
-            ("take_screenshot", "process_id", "output_path"),
-            ("get_window_info", "process_id", ""),
-            ("send_keyboard_input", "process_id", "keys"),
-            ("send_mouse_click", "process_id", "x,y,button"),
-            ("execute_js", "process_id", "javascript_code"),
-            ("get_devtools_info", "process_id", ""),
-            ("monitor_resources", "process_id", ""),
-            ("list_ipc_handlers", "process_id", ""),
-            ("call_ipc_command", "process_id", "command_name,args"),
-            ("find_running_apps", "", ""),
-            ("attach_to_app", "pid", ""),
-        ];
-        
-        for (method_name, _, _) in tool_methods {
+        for method_name in tool_names() {
             let server_clone = server.clone();
-            io.add_method(method_name, move |params: Params| {
+            io.add_method(&method_name.clone(), move |params: Params| {
                 let server = server_clone.clone();
-                let method_name = method_name.to_string();
+                let method_name = method_name.clone();
                 async move {
                     match params {
-                        Params::Map(map) => {
+                        Params::Map(map) => tokio::task::block_in_place(|| {
                             server.call_tool(json!({
                                 "name": method_name,
                                 "arguments": Value::Object(map)
                             }))
-                        }
+                        }),
                         _ => Err(RpcError::invalid_params("Expected object parameters"))
                     }
                 }
@@ -168,25 +270,43 @@ impl TauriMcpServer {
         
         let stdin = tokio::io::stdin();
         let stdout = tokio::io::stdout();
-        let mut reader = BufReader::new(stdin);
+        let mut lines = BufReader::new(stdin).lines();
         let mut stdout = stdout;
         
         // Ensure stdout is not buffered for real-time communication
         use std::io::{self, Write};
         let _ = io::stdout().flush();
         
+        // Process lifecycle events go out as MCP log notifications
+        let mut events = if self.config.event_streaming {
+            Some(self.process_manager.read().await.subscribe_events())
+        } else {
+            None
+        };
+        
         // MCP server ready, waiting for JSON-RPC requests on stdin
         tracing::info!("MCP server started, waiting for requests on stdin");
         
         loop {
-            let mut line = String::new();
-            match reader.read_line(&mut line).await {
-                Ok(0) => {
+            let next_line = tokio::select! {
+                line = lines.next_line() => line,
+                event = next_event(&mut events) => {
+                    let notification = process_event_notification(&event);
+                    tracing::debug!("Sending notification: {}", notification);
+                    stdout.write_all(notification.to_string().as_bytes()).await?;
+                    stdout.write_all(b"\n").await?;
+                    stdout.flush().await?;
+                    continue;
+                }
+            };
+            
+            match next_line {
+                Ok(None) => {
                     tracing::warn!("EOF reached on stdin, server shutting down");
                     break;
                 }
-                Ok(n) => {
-                    tracing::debug!("Read {} bytes from stdin", n);
+                Ok(Some(line)) => {
+                    tracing::debug!("Read {} bytes from stdin", line.len());
                     let line = line.trim();
                     if line.is_empty() {
                         continue;
@@ -223,6 +343,7 @@ impl TauriMcpServer {
             }
         }
         
+        self.process_manager.write().await.cleanup_exited_profiles();
         Ok(())
     }
     
@@ -233,6 +354,8 @@ impl TauriMcpServer {
         // Execute tools directly in async context
         match tool_name {
             "launch_app" => {
+                let arguments = self.config.launch_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 let app_path = arguments.get("app_path")
                     .and_then(|v| v.as_str())
                     .ok_or_else(|| TauriMcpError::Other("Missing app_path".to_string()))?
@@ -243,14 +366,25 @@ impl TauriMcpServer {
                     .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
                     .unwrap_or_default();
                 
-                let mut manager = self.process_manager.write().await;
-                let process_id = manager.launch_app(&app_path, args).await
+                let mut options = LaunchOptions::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                options.log_file = self.config.log_file_options(&arguments);
+                let build_options = BuildOptions::from_arguments(&arguments);
+                let ready_options = ReadyOptions::from_arguments(&arguments)
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
-                Ok(json!({
-                    "process_id": process_id,
-                    "status": "launched"
-                }))
+                launch_app_report(&self.process_manager, &app_path, args, options, &build_options, ready_options.as_ref()).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "build_app" => {
+                let project_path = arguments.get("project_path")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing project_path".to_string()))?;
+                
+                let report = build_app_report(project_path, &BuildOptions::from_arguments(&arguments)).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                Ok(report)
             },
             "stop_app" => {
                 let process_id = arguments.get("process_id")
@@ -258,13 +392,13 @@ impl TauriMcpServer {
                     .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?
                     .to_string();
                 
+                let options = StopOptions::from_arguments(&arguments);
+                
                 let mut manager = self.process_manager.write().await;
-                manager.stop_app(&process_id).await
+                let report = manager.stop_app(&process_id, options).await
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
-                Ok(json!({
-                    "status": "stopped"
-                }))
+                Ok(stop_report_json(report))
             },
             "get_app_logs" => {
                 let process_id = arguments.get("process_id")
@@ -272,16 +406,136 @@ impl TauriMcpServer {
                     .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?
                     .to_string();
                 
-                let lines = arguments.get("lines")
-                    .and_then(|v| v.as_u64())
-                    .map(|n| n as usize);
+                let query = LogQuery::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                let manager = self.process_manager.read().await;
+                let page = manager.get_app_logs(&process_id, &query).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                Ok(json!(page))
+            },
+            "search_app_logs" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?;
+                
+                let search = LogSearch::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                let manager = self.process_manager.read().await;
+                let result = manager.search_app_logs(process_id, &search)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                Ok(json!(result))
+            },
+            "wait_for_log" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?;
+                
+                let options = LogWaitOptions::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                wait_for_log(&self.process_manager, process_id, &options).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "sample_resources" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?;
+                
+                let options = SampleOptions::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                sample_resources(&self.process_manager, process_id, options).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "measure_startup" => {
+                let arguments = self.config.launch_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                let app_path = arguments.get("app_path")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing app_path".to_string()))?
+                    .to_string();
+                
+                let args = arguments.get("args")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                    .unwrap_or_default();
+                
+                let mut options = LaunchOptions::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                options.log_file = self.config.log_file_options(&arguments);
+                let startup_options = StartupOptions::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                measure_startup_report(&self.process_manager, &app_path, args, options, &BuildOptions::from_arguments(&arguments), startup_options).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "set_resource_alerts" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?;
+                
+                let thresholds = AlertThresholds::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                let status = if thresholds.is_some() { "monitoring" } else { "cleared" };
+                
+                let mut manager = self.process_manager.write().await;
+                manager.set_resource_alerts(process_id, thresholds)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                Ok(json!({
+                    "process_id": process_id,
+                    "status": status
+                }))
+            },
+            "get_alerts" => {
+                let process_id = arguments.get("process_id").and_then(|v| v.as_str());
+                let since_seq = arguments.get("since_seq").and_then(|v| v.as_u64()).unwrap_or(0);
                 
                 let manager = self.process_manager.read().await;
-                let logs = manager.get_app_logs(&process_id, lines).await
+                manager.get_alerts(process_id, since_seq)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "list_processes" => {
+                let manager = self.process_manager.read().await;
+                Ok(process_list(&self.config, &manager))
+            },
+            "get_process_status" => {
+                let process_id = arguments.get("process_id").and_then(|v| v.as_str());
+                
+                let manager = self.process_manager.read().await;
+                let processes = manager.process_status(process_id)
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
                 Ok(json!({
-                    "logs": logs
+                    "processes": processes
+                }))
+            },
+            "write_stdin" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?
+                    .to_string();
+                
+                let data = arguments.get("data")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing data".to_string()))?
+                    .to_string();
+                
+                let close = arguments.get("close")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                
+                let mut manager = self.process_manager.write().await;
+                let bytes_written = manager.write_stdin(&process_id, &data, close).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                Ok(json!({
+                    "bytes_written": bytes_written,
+                    "closed": close
                 }))
             },
             "take_screenshot" => {
@@ -294,7 +548,8 @@ impl TauriMcpServer {
                     .and_then(|v| v.as_str())
                     .map(|p| PathBuf::from(p));
                 
-                let screenshot_data = self.window_manager.take_screenshot(&process_id, output_path).await
+                let display = self.process_manager.read().await.display_for(&process_id);
+                let screenshot_data = self.window_manager.take_screenshot(&process_id, output_path, display.as_deref()).await
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
                 Ok(json!({
@@ -307,7 +562,8 @@ impl TauriMcpServer {
                     .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?
                     .to_string();
                 
-                let info = self.window_manager.get_window_info(&process_id).await
+                let display = self.process_manager.read().await.display_for(&process_id);
+                let info = self.window_manager.get_window_info(&process_id, display.as_deref()).await
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
                 Ok(info)
@@ -323,7 +579,13 @@ impl TauriMcpServer {
                     .ok_or_else(|| TauriMcpError::Other("Missing keys".to_string()))?
                     .to_string();
                 
-                self.input_simulator.send_keyboard_input(&process_id, &keys).await
+                let display = self.process_manager.read().await.display_for(&process_id);
+                if display.is_none() {
+                    self.window_manager.require_display()
+                        .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                }
+                
+                self.input_simulator.send_keyboard_input(&process_id, &keys, display.as_deref()).await
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
                 Ok(json!({
@@ -349,7 +611,13 @@ impl TauriMcpServer {
                     .unwrap_or("left")
                     .to_string();
                 
-                self.input_simulator.send_mouse_click(&process_id, x, y, &button).await
+                let display = self.process_manager.read().await.display_for(&process_id);
+                if display.is_none() {
+                    self.window_manager.require_display()
+                        .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                }
+                
+                self.input_simulator.send_mouse_click(&process_id, x, y, &button, display.as_deref()).await
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
                 Ok(json!({
@@ -390,13 +658,93 @@ impl TauriMcpServer {
                     .and_then(|v| v.as_str())
                     .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?
                     .to_string();
+                let tree = arguments.get("tree").and_then(|v| v.as_bool()).unwrap_or(false);
                 
                 let manager = self.process_manager.read().await;
-                let resources = manager.monitor_resources(&process_id).await
+                let resources = manager.monitor_resources(&process_id, tree).await
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
                 Ok(resources)
             },
+            "list_crashes" => {
+                let process_id = arguments.get("process_id").and_then(|v| v.as_str());
+                let since_seq = arguments.get("since_seq").and_then(|v| v.as_u64()).unwrap_or(0);
+                let symbolize = arguments.get("symbolize").and_then(|v| v.as_bool()).unwrap_or(true);
+                
+                list_crashes(&self.process_manager, process_id, since_seq, symbolize).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "dump_threads" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing process_id".to_string()))?;
+                
+                let tool = BacktraceTool::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                dump_threads_report(&self.process_manager, process_id, tool).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "list_app_data" => {
+                let dirs = AppDataTarget::from_arguments(&arguments).resolve(&self.process_manager).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                list_app_data(&dirs, &ListAppDataOptions::from_arguments(&arguments))
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "read_app_data_file" => {
+                let path = arguments.get("path")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing path".to_string()))?;
+                let dir = arguments.get("dir").and_then(|v| v.as_str());
+                let max_bytes = arguments.get("max_bytes").and_then(|v| v.as_u64()).map(|n| n as usize);
+                
+                let dirs = AppDataTarget::from_arguments(&arguments).resolve(&self.process_manager).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                read_app_data_file(&dirs, path, dir, max_bytes).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "snapshot_app_data" => {
+                let names = app_dir_names(&arguments);
+                let dirs = AppDataTarget::from_arguments(&arguments).resolve(&self.process_manager).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                snapshot_app_data(&dirs, &names)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "reset_app_data" => {
+                let names = app_dir_names(&arguments);
+                let restore = arguments.get("restore_snapshot").and_then(|v| v.as_str()).map(Path::new);
+                let force = arguments.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
+                let target = AppDataTarget::from_arguments(&arguments);
+                target.ensure_stopped(&self.process_manager, force).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                let dirs = target.resolve(&self.process_manager).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
+                
+                reset_app_data(&dirs, &names, restore)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "fs_snapshot" => {
+                let options = FsSnapshotOptions::from_arguments(&arguments);
+                let app_data = arguments.get("include_app_data")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false)
+                    .then(|| AppDataTarget::from_arguments(&arguments));
+                
+                fs_snapshot_report(&self.process_manager, &self.fs_snapshots, options, app_data).await
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
+            "fs_diff" => {
+                let snapshot_id = arguments.get("snapshot_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| TauriMcpError::Other("Missing snapshot_id".to_string()))?;
+                let to_snapshot_id = arguments.get("to_snapshot_id").and_then(|v| v.as_str());
+                
+                fs_diff(&self.fs_snapshots, snapshot_id, to_snapshot_id)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))
+            },
             "list_ipc_handlers" => {
                 let process_id = arguments.get("process_id")
                     .and_then(|v| v.as_str())
@@ -443,21 +791,253 @@ impl TauriMcpServer {
                 let pid = arguments.get("pid")
                     .and_then(|v| v.as_u64())
                     .ok_or_else(|| TauriMcpError::Other("Missing pid".to_string()))? as u32;
+                let log_source = LogSource::from_arguments(&arguments)
+                    .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
                 let mut manager = self.process_manager.write().await;
-                let process_id = manager.attach_to_app(pid).await
+                let process_id = manager.attach_to_app(pid, log_source.as_ref()).await
                     .map_err(|e| TauriMcpError::Other(e.to_string()))?;
                 
                 Ok(json!({
                     "process_id": process_id,
-                    "status": "attached"
+                    "status": "attached",
+                    "log_source": log_source
                 }))
             },
+            "server_status" => {
+                let protocol_version = self.protocol_version.read().clone();
+                Ok(server_status_report(&self.config, self.started_at, protocol_version, &self.process_manager).await)
+            },
             _ => Err(TauriMcpError::Other(format!("Unknown tool: {}", tool_name)))
         }
     }
 }
 
+/// Next process lifecycle event, or never when event streaming is off.
+async fn next_event(events: &mut Option<broadcast::Receiver<ProcessEvent>>) -> ProcessEvent {
+    let Some(receiver) = events else {
+        return std::future::pending().await;
+    };
+    
+    loop {
+        match receiver.recv().await {
+            Ok(event) => return event,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing::warn!("Dropped {} process events for slow notification stream", skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => {
+                *events = None;
+                return std::future::pending().await;
+            }
+        }
+    }
+}
+
+/// MCP `notifications/message` carrying a process lifecycle event.
+fn process_event_notification(event: &ProcessEvent) -> Value {
+    let level = match event.event {
+        "panicked" => "error",
+        "exited" if event.details["crashed"] == true => "error",
+        "restarting" => "warning",
+        "alert" if event.details["state"] == "triggered" => "warning",
+        _ => "info",
+    };
+    
+    json!({
+        "jsonrpc": "2.0",
+        "method": "notifications/message",
+        "params": {
+            "level": level,
+            "logger": "tauri-mcp.process",
+            "data": event,
+        }
+    })
+}
+
+/// Status snapshot shared by the `server_status` tool in both serve and tool mode.
+async fn server_status_report(
+    config: &ServerConfig,
+    started_at: Instant,
+    protocol_version: Option<String>,
+    process_manager: &RwLock<ProcessManager>,
+) -> Value {
+    let processes = process_manager.read().await.process_summaries();
+    let environment = run_environment_checks().await;
+    
+    json!({
+        "server": {
+            "name": "tauri-mcp",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "protocol_version": protocol_version,
+        "uptime_secs": started_at.elapsed().as_secs(),
+        "config": config,
+        "processes": processes,
+        "environment": environment,
+    })
+}
+
+/// Managed processes with their log files, for `list_processes`.
+fn process_list(config: &ServerConfig, process_manager: &ProcessManager) -> Value {
+    json!({
+        "processes": process_manager.process_summaries(),
+        "log_dir": config.log_directory(),
+    })
+}
+
+/// Build `project_path` as a Tauri project and report diagnostics.
+async fn build_app_report(project_path: &str, build_options: &BuildOptions) -> Result<Value> {
+    let project = TauriProject::detect(Path::new(project_path))
+        .ok_or_else(|| TauriMcpError::ProcessError(format!(
+            "Not a Tauri project (no src-tauri/tauri.conf.json): {}", project_path
+        )))?;
+    
+    let report = build_project(&project, build_options).await?;
+    
+    Ok(json!({
+        "status": if report.success { "built" } else { "build_failed" },
+        "project": project,
+        "build": report,
+    }))
+}
+
+/// What to run for an `app_path`: the path itself, or the executable built
+/// from the Tauri project there.
+struct Built {
+    executable: String,
+    project: Option<TauriProject>,
+    build: Option<BuildReport>,
+}
+
+/// Build `app_path` first when it is a Tauri project. A failed build gives
+/// the `build_failed` result to return as is.
+async fn build_if_project(app_path: &str, build_options: &BuildOptions) -> Result<std::result::Result<Built, Value>> {
+    let Some(project) = TauriProject::detect(Path::new(app_path)) else {
+        return Ok(Ok(Built { executable: app_path.to_string(), project: None, build: None }));
+    };
+    
+    // Builds can take minutes; the process manager stays unlocked meanwhile
+    let report = build_project(&project, build_options).await?;
+    match (&report.executable, report.success) {
+        (Some(executable), true) => Ok(Ok(Built {
+            executable: executable.to_string_lossy().to_string(),
+            project: Some(project),
+            build: Some(report),
+        })),
+        _ => Ok(Err(json!({
+            "status": "build_failed",
+            "project": project,
+            "build": report,
+        }))),
+    }
+}
+
+/// Launch a binary, or build a Tauri project directory first and launch its
+/// binary. A failed build is reported as `build_failed` with its diagnostics,
+/// an app that misses its `wait_for` conditions as `not_ready`.
+async fn launch_app_report(
+    process_manager: &RwLock<ProcessManager>,
+    app_path: &str,
+    args: Vec<String>,
+    options: LaunchOptions,
+    build_options: &BuildOptions,
+    ready_options: Option<&ReadyOptions>,
+) -> Result<Value> {
+    let mut result = json!({});
+    
+    let executable = match build_if_project(app_path, build_options).await? {
+        Ok(Built { executable, build: Some(build), .. }) => {
+            result["executable"] = json!(executable);
+            result["build"] = json!(build);
+            executable
+        }
+        Ok(Built { executable, .. }) => executable,
+        Err(build_failed) => return Ok(build_failed),
+    };
+    
+    let mut manager = process_manager.write().await;
+    let process_id = manager.launch_app(&executable, args, options).await?;
+    result["process_id"] = json!(process_id);
+    result["status"] = json!("launched");
+    if let Some(profile) = manager.isolated_profile(&process_id) {
+        result["isolated"] = json!(profile);
+    }
+    drop(manager);
+    
+    if let Some(ready_options) = ready_options {
+        let ready = wait_until_ready(process_manager, &process_id, ready_options).await?;
+        if ready["ready"] != true {
+            result["status"] = json!("not_ready");
+        }
+        result["ready"] = ready;
+    }
+    
+    Ok(result)
+}
+
+/// Measure an app's startup over several launches; a Tauri project is built
+/// once up front and supplies the identifier for cold runs.
+async fn measure_startup_report(
+    process_manager: &RwLock<ProcessManager>,
+    app_path: &str,
+    args: Vec<String>,
+    options: LaunchOptions,
+    build_options: &BuildOptions,
+    mut startup_options: StartupOptions,
+) -> Result<Value> {
+    let built = match build_if_project(app_path, build_options).await? {
+        Ok(built) => built,
+        Err(build_failed) => return Ok(build_failed),
+    };
+    if startup_options.identifier.is_none() {
+        startup_options.identifier = built.project.as_ref().and_then(TauriProject::identifier);
+    }
+    
+    let mut result = measure_startup(process_manager, &built.executable, args, options, &startup_options).await?;
+    if let Some(build) = built.build {
+        result["build"] = json!(build);
+    }
+    Ok(result)
+}
+
+/// Thread dump of a managed app. The process manager is only locked to look
+/// up the PID; a debugger may take a while to attach.
+async fn dump_threads_report(process_manager: &RwLock<ProcessManager>, process_id: &str, tool: BacktraceTool) -> Result<Value> {
+    let pid = process_manager.read().await.pid_of(process_id)?;
+    let mut result = dump_threads(pid, tool).await?;
+    result["process_id"] = json!(process_id);
+    Ok(result)
+}
+
+/// Snapshot `options.paths`, plus the app's data directories when `app_data`
+/// names an app.
+async fn fs_snapshot_report(
+    process_manager: &RwLock<ProcessManager>,
+    snapshots: &parking_lot::Mutex<FsSnapshots>,
+    mut options: FsSnapshotOptions,
+    app_data: Option<AppDataTarget>,
+) -> Result<Value> {
+    if let Some(target) = app_data {
+        let dirs = target.resolve(process_manager).await?;
+        options.paths.extend(dirs.roots().into_iter().map(|(_, dir)| dir.to_path_buf()));
+    }
+    fs_snapshot(snapshots, &options)
+}
+
+/// The `dirs` argument of the app data tools: names of app directories.
+fn app_dir_names(arguments: &Value) -> Vec<String> {
+    arguments.get("dirs")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+        .unwrap_or_default()
+}
+
+fn stop_report_json(report: StopReport) -> Value {
+    let mut result = json!(report);
+    result["status"] = json!("stopped");
+    result
+}
+
 #[derive(Clone)]
 struct McpServerImpl {
     process_manager: Arc<RwLock<ProcessManager>>,
@@ -465,6 +1045,10 @@ struct McpServerImpl {
     input_simulator: Arc<InputSimulator>,
     debug_tools: Arc<DebugTools>,
     ipc_manager: Arc<IpcManager>,
+    fs_snapshots: Arc<parking_lot::Mutex<FsSnapshots>>,
+    config: ServerConfig,
+    started_at: Instant,
+    protocol_version: Arc<parking_lot::RwLock<Option<String>>>,
 }
 
 impl McpServerImpl {
@@ -492,6 +1076,7 @@ impl McpServerImpl {
         
         // Log the protocol version being used
         tracing::info!("MCP client connected with protocol version: {}", protocol_version);
+        *self.protocol_version.write() = Some(protocol_version.clone());
         
         // Extract client capabilities if provided
         let _client_capabilities = capabilities;
@@ -523,54 +1108,228 @@ impl McpServerImpl {
         }))
     }
     
-    fn launch_app(&self, app_path: String, args: Option<Vec<String>>) -> jsonrpc_core::Result<Value> {
+    fn launch_app(
+        &self,
+        app_path: String,
+        args: Option<Vec<String>>,
+        options: LaunchOptions,
+        build_options: BuildOptions,
+        ready_options: Option<ReadyOptions>,
+    ) -> jsonrpc_core::Result<Value> {
         let process_manager = Arc::clone(&self.process_manager);
         let args = args.unwrap_or_default();
         
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            launch_app_report(&process_manager, &app_path, args, options, &build_options, ready_options.as_ref()).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn build_app(&self, project_path: String, build_options: BuildOptions) -> jsonrpc_core::Result<Value> {
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            build_app_report(&project_path, &build_options).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn stop_app(&self, process_id: String, options: StopOptions) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
         let runtime = tokio::runtime::Handle::current();
         let result = runtime.block_on(async {
             let mut manager = process_manager.write().await;
-            manager.launch_app(&app_path, args).await
+            manager.stop_app(&process_id, options).await
         });
         
         match result {
-            Ok(process_id) => Ok(json!({
-                "process_id": process_id,
-                "status": "launched"
-            })),
+            Ok(report) => Ok(stop_report_json(report)),
             Err(e) => Err(RpcError::invalid_params(e.to_string())),
         }
     }
     
-    fn stop_app(&self, process_id: String) -> jsonrpc_core::Result<Value> {
+    fn get_app_logs(&self, process_id: String, query: LogQuery) -> jsonrpc_core::Result<Value> {
         let process_manager = Arc::clone(&self.process_manager);
         
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            let manager = process_manager.read().await;
+            manager.get_app_logs(&process_id, &query).await
+        });
+        
+        match result {
+            Ok(page) => Ok(json!(page)),
+            Err(e) => Err(RpcError::invalid_params(e.to_string())),
+        }
+    }
+    
+    fn search_app_logs(&self, process_id: String, search: LogSearch) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            let manager = process_manager.read().await;
+            manager.search_app_logs(&process_id, &search)
+        });
+        
+        match result {
+            Ok(result) => Ok(json!(result)),
+            Err(e) => Err(RpcError::invalid_params(e.to_string())),
+        }
+    }
+    
+    fn wait_for_log(&self, process_id: String, options: LogWaitOptions) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            wait_for_log(&process_manager, &process_id, &options).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn measure_startup(
+        &self,
+        app_path: String,
+        args: Option<Vec<String>>,
+        options: LaunchOptions,
+        build_options: BuildOptions,
+        startup_options: StartupOptions,
+    ) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        let args = args.unwrap_or_default();
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            measure_startup_report(&process_manager, &app_path, args, options, &build_options, startup_options).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn sample_resources(&self, process_id: String, options: SampleOptions) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            sample_resources(&process_manager, &process_id, options).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn set_resource_alerts(&self, process_id: String, thresholds: Option<AlertThresholds>) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        let status = if thresholds.is_some() { "monitoring" } else { "cleared" };
+        
         let runtime = tokio::runtime::Handle::current();
         let result = runtime.block_on(async {
             let mut manager = process_manager.write().await;
-            manager.stop_app(&process_id).await
+            manager.set_resource_alerts(&process_id, thresholds)
         });
         
         match result {
             Ok(()) => Ok(json!({
-                "status": "stopped"
+                "process_id": process_id,
+                "status": status
             })),
             Err(e) => Err(RpcError::invalid_params(e.to_string())),
         }
     }
     
-    fn get_app_logs(&self, process_id: String, lines: Option<usize>) -> jsonrpc_core::Result<Value> {
+    fn get_alerts(&self, process_id: Option<String>, since_seq: u64) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            let manager = process_manager.read().await;
+            manager.get_alerts(process_id.as_deref(), since_seq)
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn list_processes(&self) -> jsonrpc_core::Result<Value> {
+        let runtime = tokio::runtime::Handle::current();
+        Ok(runtime.block_on(async {
+            process_list(&self.config, &*self.process_manager.read().await)
+        }))
+    }
+    
+    fn list_resources(&self) -> jsonrpc_core::Result<Value> {
+        let resources: Vec<Value> = log_files_in(&self.config.log_directory())
+            .into_iter()
+            .map(|path| {
+                let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                json!({
+                    "uri": format!("file://{}", path.display()),
+                    "name": name,
+                    "description": "Output of a launched app, written by tauri-mcp",
+                    "mimeType": "text/plain",
+                })
+            })
+            .collect();
+        
+        Ok(json!({ "resources": resources }))
+    }
+    
+    /// Read a log file listed by `resources/list`; nothing outside the log directory.
+    fn read_resource(&self, uri: &str) -> jsonrpc_core::Result<Value> {
+        let path = uri.strip_prefix("file://")
+            .ok_or_else(|| RpcError::invalid_params(format!("Unsupported resource URI: {}", uri)))?;
+        let log_dir = self.config.log_directory().canonicalize()
+            .map_err(|e| RpcError::invalid_params(format!("Log directory unavailable: {}", e)))?;
+        let path = Path::new(path).canonicalize()
+            .map_err(|_| RpcError::invalid_params(format!("Resource not found: {}", uri)))?;
+        if !path.starts_with(&log_dir) || !path.is_file() {
+            return Err(RpcError::invalid_params(format!("Resource not found: {}", uri)));
+        }
+        
+        let bytes = std::fs::read(&path)
+            .map_err(|e| RpcError::invalid_params(format!("Failed to read {}: {}", path.display(), e)))?;
+        Ok(json!({
+            "contents": [{
+                "uri": uri,
+                "mimeType": "text/plain",
+                "text": String::from_utf8_lossy(&bytes),
+            }]
+        }))
+    }
+    
+    fn get_process_status(&self, process_id: Option<String>) -> jsonrpc_core::Result<Value> {
         let process_manager = Arc::clone(&self.process_manager);
         
         let runtime = tokio::runtime::Handle::current();
         let result = runtime.block_on(async {
             let manager = process_manager.read().await;
-            manager.get_app_logs(&process_id, lines).await
+            manager.process_status(process_id.as_deref())
+        });
+        
+        match result {
+            Ok(processes) => Ok(json!({
+                "processes": processes
+            })),
+            Err(e) => Err(RpcError::invalid_params(e.to_string())),
+        }
+    }
+    
+    fn write_stdin(&self, process_id: String, data: String, close: bool) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            let mut manager = process_manager.write().await;
+            manager.write_stdin(&process_id, &data, close).await
         });
         
         match result {
-            Ok(logs) => Ok(json!({
-                "logs": logs
+            Ok(bytes_written) => Ok(json!({
+                "bytes_written": bytes_written,
+                "closed": close
             })),
             Err(e) => Err(RpcError::invalid_params(e.to_string())),
         }
@@ -578,11 +1337,13 @@ impl McpServerImpl {
     
     fn take_screenshot(&self, process_id: String, output_path: Option<String>) -> jsonrpc_core::Result<Value> {
         let window_manager = Arc::clone(&self.window_manager);
+        let process_manager = Arc::clone(&self.process_manager);
         let output_path = output_path.map(PathBuf::from);
         
         let runtime = tokio::runtime::Handle::current();
         let result = runtime.block_on(async {
-            window_manager.take_screenshot(&process_id, output_path).await
+            let display = process_manager.read().await.display_for(&process_id);
+            window_manager.take_screenshot(&process_id, output_path, display.as_deref()).await
         });
         
         match result {
@@ -595,10 +1356,12 @@ impl McpServerImpl {
     
     fn get_window_info(&self, process_id: String) -> jsonrpc_core::Result<Value> {
         let window_manager = Arc::clone(&self.window_manager);
+        let process_manager = Arc::clone(&self.process_manager);
         
         let runtime = tokio::runtime::Handle::current();
         let result = runtime.block_on(async {
-            window_manager.get_window_info(&process_id).await
+            let display = process_manager.read().await.display_for(&process_id);
+            window_manager.get_window_info(&process_id, display.as_deref()).await
         });
         
         match result {
@@ -609,10 +1372,17 @@ impl McpServerImpl {
     
     fn send_keyboard_input(&self, process_id: String, keys: String) -> jsonrpc_core::Result<Value> {
         let input_simulator = Arc::clone(&self.input_simulator);
+        let process_manager = Arc::clone(&self.process_manager);
         
         let runtime = tokio::runtime::Handle::current();
+        let display = runtime.block_on(async { process_manager.read().await.display_for(&process_id) });
+        if display.is_none() {
+            self.window_manager.require_display()
+                .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+        }
+        
         let result = runtime.block_on(async {
-            input_simulator.send_keyboard_input(&process_id, &keys).await
+            input_simulator.send_keyboard_input(&process_id, &keys, display.as_deref()).await
         });
         
         match result {
@@ -625,11 +1395,18 @@ impl McpServerImpl {
     
     fn send_mouse_click(&self, process_id: String, x: i32, y: i32, button: Option<String>) -> jsonrpc_core::Result<Value> {
         let input_simulator = Arc::clone(&self.input_simulator);
+        let process_manager = Arc::clone(&self.process_manager);
         let button = button.unwrap_or_else(|| "left".to_string());
         
         let runtime = tokio::runtime::Handle::current();
+        let display = runtime.block_on(async { process_manager.read().await.display_for(&process_id) });
+        if display.is_none() {
+            self.window_manager.require_display()
+                .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+        }
+        
         let result = runtime.block_on(async {
-            input_simulator.send_mouse_click(&process_id, x, y, &button).await
+            input_simulator.send_mouse_click(&process_id, x, y, &button, display.as_deref()).await
         });
         
         match result {
@@ -670,13 +1447,13 @@ impl McpServerImpl {
         }
     }
     
-    fn monitor_resources(&self, process_id: String) -> jsonrpc_core::Result<Value> {
+    fn monitor_resources(&self, process_id: String, tree: bool) -> jsonrpc_core::Result<Value> {
         let process_manager = Arc::clone(&self.process_manager);
         
         let runtime = tokio::runtime::Handle::current();
         let result = runtime.block_on(async {
             let manager = process_manager.read().await;
-            manager.monitor_resources(&process_id).await
+            manager.monitor_resources(&process_id, tree).await
         });
         
         match result {
@@ -685,6 +1462,93 @@ impl McpServerImpl {
         }
     }
     
+    fn list_crashes(&self, process_id: Option<String>, since_seq: u64, symbolize: bool) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            list_crashes(&process_manager, process_id.as_deref(), since_seq, symbolize).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn dump_threads(&self, process_id: String, tool: BacktraceTool) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            dump_threads_report(&process_manager, &process_id, tool).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn list_app_data(&self, target: AppDataTarget, options: ListAppDataOptions) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            let dirs = target.resolve(&process_manager).await?;
+            list_app_data(&dirs, &options)
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn read_app_data_file(&self, target: AppDataTarget, path: String, dir: Option<String>, max_bytes: Option<usize>) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            let dirs = target.resolve(&process_manager).await?;
+            read_app_data_file(&dirs, &path, dir.as_deref(), max_bytes).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn snapshot_app_data(&self, target: AppDataTarget, names: Vec<String>) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            let dirs = target.resolve(&process_manager).await?;
+            snapshot_app_data(&dirs, &names)
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn reset_app_data(&self, target: AppDataTarget, names: Vec<String>, restore: Option<PathBuf>, force: bool) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            target.ensure_stopped(&process_manager, force).await?;
+            let dirs = target.resolve(&process_manager).await?;
+            reset_app_data(&dirs, &names, restore.as_deref())
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn fs_snapshot(&self, options: FsSnapshotOptions, app_data: Option<AppDataTarget>) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        
+        let runtime = tokio::runtime::Handle::current();
+        let result = runtime.block_on(async {
+            fs_snapshot_report(&process_manager, &self.fs_snapshots, options, app_data).await
+        });
+        
+        result.map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
+    fn fs_diff(&self, snapshot_id: String, to_snapshot_id: Option<String>) -> jsonrpc_core::Result<Value> {
+        fs_diff(&self.fs_snapshots, &snapshot_id, to_snapshot_id.as_deref())
+            .map_err(|e| RpcError::invalid_params(e.to_string()))
+    }
+    
     fn list_ipc_handlers(&self, process_id: String) -> jsonrpc_core::Result<Value> {
         let ipc_manager = Arc::clone(&self.ipc_manager);
         
@@ -733,189 +1597,38 @@ impl McpServerImpl {
         }
     }
     
-    fn attach_to_app(&self, pid: u32) -> jsonrpc_core::Result<Value> {
+    fn attach_to_app(&self, pid: u32, log_source: Option<LogSource>) -> jsonrpc_core::Result<Value> {
         let process_manager = Arc::clone(&self.process_manager);
         
         let runtime = tokio::runtime::Handle::current();
         let result = runtime.block_on(async {
             let mut manager = process_manager.write().await;
-            manager.attach_to_app(pid).await
+            manager.attach_to_app(pid, log_source.as_ref()).await
         });
         
         match result {
             Ok(process_id) => Ok(json!({
                 "process_id": process_id,
-                "status": "attached"
+                "status": "attached",
+                "log_source": log_source
             })),
             Err(e) => Err(RpcError::invalid_params(e.to_string())),
         }
     }
     
+    fn server_status(&self) -> jsonrpc_core::Result<Value> {
+        let process_manager = Arc::clone(&self.process_manager);
+        let protocol_version = self.protocol_version.read().clone();
+        
+        let runtime = tokio::runtime::Handle::current();
+        Ok(runtime.block_on(async {
+            server_status_report(&self.config, self.started_at, protocol_version, &process_manager).await
+        }))
+    }
+    
     fn list_tools(&self) -> jsonrpc_core::Result<Value> {
         Ok(json!({
-            "tools": [
-                {
-                    "name": "launch_app",
-                    "description": "Launch a Tauri application",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "app_path": { "type": "string", "description": "Path to the Tauri application" },
-                            "args": { "type": "array", "items": { "type": "string" }, "description": "Optional launch arguments" }
-                        },
-                        "required": ["app_path"]
-                    }
-                },
-                {
-                    "name": "stop_app",
-                    "description": "Stop a running Tauri application",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app to stop" }
-                        },
-                        "required": ["process_id"]
-                    }
-                },
-                {
-                    "name": "get_app_logs",
-                    "description": "Get stdout/stderr logs from a running app",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" },
-                            "lines": { "type": "number", "description": "Number of recent lines to return" }
-                        },
-                        "required": ["process_id"]
-                    }
-                },
-                {
-                    "name": "take_screenshot",
-                    "description": "Take a screenshot of the app window",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" },
-                            "output_path": { "type": "string", "description": "Optional path to save the screenshot" }
-                        },
-                        "required": ["process_id"]
-                    }
-                },
-                {
-                    "name": "get_window_info",
-                    "description": "Get window dimensions, position, and state",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" }
-                        },
-                        "required": ["process_id"]
-                    }
-                },
-                {
-                    "name": "send_keyboard_input",
-                    "description": "Send keyboard input to the app",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" },
-                            "keys": { "type": "string", "description": "Keys to send" }
-                        },
-                        "required": ["process_id", "keys"]
-                    }
-                },
-                {
-                    "name": "send_mouse_click",
-                    "description": "Send mouse click to specific coordinates",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" },
-                            "x": { "type": "number", "description": "X coordinate" },
-                            "y": { "type": "number", "description": "Y coordinate" },
-                            "button": { "type": "string", "enum": ["left", "right", "middle"], "description": "Mouse button" }
-                        },
-                        "required": ["process_id", "x", "y"]
-                    }
-                },
-                {
-                    "name": "execute_js",
-                    "description": "Execute JavaScript in the app's webview",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" },
-                            "javascript_code": { "type": "string", "description": "JavaScript code to execute" }
-                        },
-                        "required": ["process_id", "javascript_code"]
-                    }
-                },
-                {
-                    "name": "get_devtools_info",
-                    "description": "Get DevTools connection information",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" }
-                        },
-                        "required": ["process_id"]
-                    }
-                },
-                {
-                    "name": "monitor_resources",
-                    "description": "Monitor CPU, memory, and other resource usage",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" }
-                        },
-                        "required": ["process_id"]
-                    }
-                },
-                {
-                    "name": "list_ipc_handlers",
-                    "description": "List all registered Tauri IPC commands",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" }
-                        },
-                        "required": ["process_id"]
-                    }
-                },
-                {
-                    "name": "call_ipc_command",
-                    "description": "Call a Tauri IPC command",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "process_id": { "type": "string", "description": "Process ID of the app" },
-                            "command_name": { "type": "string", "description": "Name of the IPC command" },
-                            "args": { "type": "object", "description": "Arguments to pass to the command" }
-                        },
-                        "required": ["process_id", "command_name"]
-                    }
-                },
-                {
-                    "name": "find_running_apps",
-                    "description": "Find running Tauri applications on the system",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {}
-                    }
-                },
-                {
-                    "name": "attach_to_app",
-                    "description": "Attach to an already running Tauri application by PID",
-                    "inputSchema": {
-                        "type": "object",
-                        "properties": {
-                            "pid": { "type": "number", "description": "Process ID of the running app" }
-                        },
-                        "required": ["pid"]
-                    }
-                }
-            ]
+            "tools": tool_definitions()
         }))
     }
     
@@ -930,6 +1643,8 @@ impl McpServerImpl {
         
         match tool_name {
             "launch_app" => {
+                let arguments = self.config.launch_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
                 let app_path = arguments.get("app_path")
                     .and_then(|v| v.as_str())
                     .ok_or_else(|| RpcError::invalid_params("Missing app_path"))?
@@ -939,7 +1654,22 @@ impl McpServerImpl {
                     .and_then(|v| v.as_array())
                     .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect());
                 
-                self.launch_app(app_path, args)
+                let mut options = LaunchOptions::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                options.log_file = self.config.log_file_options(&arguments);
+                let build_options = BuildOptions::from_arguments(&arguments);
+                let ready_options = ReadyOptions::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                
+                self.launch_app(app_path, args, options, build_options, ready_options)
+            },
+            "build_app" => {
+                let project_path = arguments.get("project_path")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing project_path"))?
+                    .to_string();
+                
+                self.build_app(project_path, BuildOptions::from_arguments(&arguments))
             },
             "stop_app" => {
                 let process_id = arguments.get("process_id")
@@ -947,7 +1677,7 @@ impl McpServerImpl {
                     .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
                     .to_string();
                 
-                self.stop_app(process_id)
+                self.stop_app(process_id, StopOptions::from_arguments(&arguments))
             },
             "get_app_logs" => {
                 let process_id = arguments.get("process_id")
@@ -955,11 +1685,107 @@ impl McpServerImpl {
                     .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
                     .to_string();
                 
-                let lines = arguments.get("lines")
-                    .and_then(|v| v.as_u64())
-                    .map(|n| n as usize);
+                let query = LogQuery::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                
+                self.get_app_logs(process_id, query)
+            },
+            "search_app_logs" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
+                    .to_string();
+                
+                let search = LogSearch::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                
+                self.search_app_logs(process_id, search)
+            },
+            "wait_for_log" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
+                    .to_string();
+                
+                let options = LogWaitOptions::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                
+                self.wait_for_log(process_id, options)
+            },
+            "sample_resources" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
+                    .to_string();
+                
+                let options = SampleOptions::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
                 
-                self.get_app_logs(process_id, lines)
+                self.sample_resources(process_id, options)
+            },
+            "measure_startup" => {
+                let arguments = self.config.launch_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                let app_path = arguments.get("app_path")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing app_path"))?
+                    .to_string();
+                
+                let args = arguments.get("args")
+                    .and_then(|v| v.as_array())
+                    .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect());
+                
+                let mut options = LaunchOptions::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                options.log_file = self.config.log_file_options(&arguments);
+                let startup_options = StartupOptions::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                
+                self.measure_startup(app_path, args, options, BuildOptions::from_arguments(&arguments), startup_options)
+            },
+            "set_resource_alerts" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
+                    .to_string();
+                
+                let thresholds = AlertThresholds::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                
+                self.set_resource_alerts(process_id, thresholds)
+            },
+            "get_alerts" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .map(String::from);
+                let since_seq = arguments.get("since_seq").and_then(|v| v.as_u64()).unwrap_or(0);
+                
+                self.get_alerts(process_id, since_seq)
+            },
+            "list_processes" => self.list_processes(),
+            "get_process_status" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .map(String::from);
+                
+                self.get_process_status(process_id)
+            },
+            "write_stdin" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
+                    .to_string();
+                
+                let data = arguments.get("data")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing data"))?
+                    .to_string();
+                
+                let close = arguments.get("close")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                
+                self.write_stdin(process_id, data, close)
             },
             "take_screenshot" => {
                 let process_id = arguments.get("process_id")
@@ -1036,12 +1862,74 @@ impl McpServerImpl {
                 self.get_devtools_info(process_id)
             },
             "monitor_resources" => {
+                let process_id = arguments.get("process_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
+                    .to_string();
+                let tree = arguments.get("tree").and_then(|v| v.as_bool()).unwrap_or(false);
+                
+                self.monitor_resources(process_id, tree)
+            },
+            "list_crashes" => {
+                let process_id = arguments.get("process_id").and_then(|v| v.as_str()).map(String::from);
+                let since_seq = arguments.get("since_seq").and_then(|v| v.as_u64()).unwrap_or(0);
+                let symbolize = arguments.get("symbolize").and_then(|v| v.as_bool()).unwrap_or(true);
+                
+                self.list_crashes(process_id, since_seq, symbolize)
+            },
+            "dump_threads" => {
                 let process_id = arguments.get("process_id")
                     .and_then(|v| v.as_str())
                     .ok_or_else(|| RpcError::invalid_params("Missing process_id"))?
                     .to_string();
                 
-                self.monitor_resources(process_id)
+                let tool = BacktraceTool::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
+                
+                self.dump_threads(process_id, tool)
+            },
+            "list_app_data" => {
+                let target = AppDataTarget::from_arguments(&arguments);
+                let options = ListAppDataOptions::from_arguments(&arguments);
+                
+                self.list_app_data(target, options)
+            },
+            "read_app_data_file" => {
+                let path = arguments.get("path")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing path"))?
+                    .to_string();
+                let dir = arguments.get("dir").and_then(|v| v.as_str()).map(String::from);
+                let max_bytes = arguments.get("max_bytes").and_then(|v| v.as_u64()).map(|n| n as usize);
+                
+                self.read_app_data_file(AppDataTarget::from_arguments(&arguments), path, dir, max_bytes)
+            },
+            "snapshot_app_data" => {
+                self.snapshot_app_data(AppDataTarget::from_arguments(&arguments), app_dir_names(&arguments))
+            },
+            "reset_app_data" => {
+                let restore = arguments.get("restore_snapshot").and_then(|v| v.as_str()).map(PathBuf::from);
+                let force = arguments.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
+                
+                self.reset_app_data(AppDataTarget::from_arguments(&arguments), app_dir_names(&arguments), restore, force)
+            },
+            "fs_snapshot" => {
+                let options = FsSnapshotOptions::from_arguments(&arguments);
+                let app_data = arguments.get("include_app_data")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false)
+                    .then(|| AppDataTarget::from_arguments(&arguments));
+                
+                self.fs_snapshot(options, app_data)
+            },
+            "fs_diff" => {
+                let snapshot_id = arguments.get("snapshot_id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| RpcError::invalid_params("Missing snapshot_id"))?
+                    .to_string();
+                let to_snapshot_id = arguments.get("to_snapshot_id").and_then(|v| v.as_str()).map(String::from);
+                
+                self.fs_diff(snapshot_id, to_snapshot_id)
             },
             "list_ipc_handlers" => {
                 let process_id = arguments.get("process_id")
@@ -1073,8 +1961,13 @@ impl McpServerImpl {
                 let pid = arguments.get("pid")
                     .and_then(|v| v.as_u64())
                     .ok_or_else(|| RpcError::invalid_params("Missing pid"))? as u32;
+                let log_source = LogSource::from_arguments(&arguments)
+                    .map_err(|e| RpcError::invalid_params(e.to_string()))?;
                 
-                self.attach_to_app(pid)
+                self.attach_to_app(pid, log_source)
+            },
+            "server_status" => {
+                self.server_status()
             },
             _ => Err(RpcError::method_not_found())
         }
@@ -0,0 +1,257 @@
+use crate::tools::sampling::{ResourceSample, ResourceSampler};
+use crate::{Result, TauriMcpError};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+const DEFAULT_CPU_SUSTAINED: Duration = Duration::from_secs(10);
+/// Alerts kept across all processes; older ones are dropped.
+const MAX_ALERTS: usize = 1000;
+const MIB: f64 = 1024.0 * 1024.0;
+
+/// Resource thresholds for one managed app, checked by an `AlertMonitor`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AlertThresholds {
+    pub rss_mb: Option<u64>,
+    pub cpu_percent: Option<f32>,
+    /// How long CPU has to stay above `cpu_percent` before it trips
+    #[serde(serialize_with = "serialize_millis")]
+    pub cpu_sustained: Duration,
+    pub open_fds: Option<usize>,
+    pub threads: Option<usize>,
+    #[serde(serialize_with = "serialize_millis")]
+    pub interval: Duration,
+    /// Measure the whole process tree, WebKit helpers included
+    pub tree: bool,
+}
+
+fn serialize_millis<S: serde::Serializer>(duration: &Duration, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_millis() as u64)
+}
+
+impl AlertThresholds {
+    /// Parse `set_resource_alerts` arguments; `None` when no threshold is
+    /// given, which clears the app's alerts.
+    pub fn from_arguments(arguments: &Value) -> Result<Option<Self>> {
+        let number = |name: &str| -> Result<Option<f64>> {
+            match arguments.get(name) {
+                None | Some(Value::Null) => Ok(None),
+                Some(value) => value.as_f64()
+                    .filter(|n| *n > 0.0)
+                    .map(Some)
+                    .ok_or_else(|| TauriMcpError::ProcessError(format!("{} must be a positive number", name))),
+            }
+        };
+        
+        let thresholds = Self {
+            rss_mb: number("rss_mb")?.map(|n| n as u64),
+            cpu_percent: number("cpu_percent")?.map(|n| n as f32),
+            cpu_sustained: number("cpu_sustained_ms")?.map_or(DEFAULT_CPU_SUSTAINED, |n| Duration::from_millis(n as u64)),
+            open_fds: number("max_fds")?.map(|n| n as usize),
+            threads: number("max_threads")?.map(|n| n as usize),
+            interval: number("interval_ms")?.map_or(DEFAULT_INTERVAL, |n| Duration::from_millis(n as u64)).max(MIN_INTERVAL),
+            tree: arguments.get("tree").and_then(|v| v.as_bool()).unwrap_or(true),
+        };
+        
+        let any = thresholds.rss_mb.is_some()
+            || thresholds.cpu_percent.is_some()
+            || thresholds.open_fds.is_some()
+            || thresholds.threads.is_some();
+        Ok(any.then_some(thresholds))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertState {
+    Triggered,
+    Resolved,
+}
+
+/// A threshold crossing, in either direction.
+#[derive(Debug, Clone, Serialize)]
+pub struct Alert {
+    pub seq: u64,
+    pub process_id: String,
+    pub pid: u32,
+    /// `rss_mb`, `cpu_percent`, `open_fds` or `threads`
+    pub metric: &'static str,
+    pub state: AlertState,
+    pub value: f64,
+    pub threshold: f64,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Alerts of all managed apps, oldest first.
+#[derive(Debug, Default)]
+pub struct AlertHistory {
+    alerts: VecDeque<Alert>,
+    next_seq: u64,
+}
+
+impl AlertHistory {
+    fn push(&mut self, mut alert: Alert) -> Alert {
+        alert.seq = self.next_seq;
+        self.next_seq += 1;
+        if self.alerts.len() == MAX_ALERTS {
+            self.alerts.pop_front();
+        }
+        self.alerts.push_back(alert.clone());
+        alert
+    }
+    
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+    
+    /// Alerts from `since_seq` on, optionally of one app only.
+    pub fn query(&self, process_id: Option<&str>, since_seq: u64) -> Vec<Alert> {
+        self.alerts.iter()
+            .filter(|alert| alert.seq >= since_seq)
+            .filter(|alert| process_id.is_none_or(|id| alert.process_id == id))
+            .cloned()
+            .collect()
+    }
+    
+    /// The latest alert of each app and metric that is still triggered.
+    pub fn active(&self, process_id: Option<&str>) -> Vec<Alert> {
+        let mut latest: HashMap<(&str, &str), &Alert> = HashMap::new();
+        for alert in &self.alerts {
+            latest.insert((alert.process_id.as_str(), alert.metric), alert);
+        }
+        let mut active: Vec<Alert> = latest.into_values()
+            .filter(|alert| alert.state == AlertState::Triggered)
+            .filter(|alert| process_id.is_none_or(|id| alert.process_id == id))
+            .cloned()
+            .collect();
+        active.sort_by_key(|alert| alert.seq);
+        active
+    }
+}
+
+/// Tracks which thresholds are tripped so each crossing is reported once.
+struct Evaluator {
+    thresholds: AlertThresholds,
+    tripped: Vec<&'static str>,
+    cpu_above_since: Option<Instant>,
+}
+
+impl Evaluator {
+    fn evaluate(&mut self, sample: &ResourceSample) -> Vec<(&'static str, AlertState, f64, f64)> {
+        let thresholds = &self.thresholds;
+        let cpu_above = thresholds.cpu_percent.is_some_and(|limit| sample.cpu_usage > limit);
+        self.cpu_above_since = if cpu_above { self.cpu_above_since.or(Some(Instant::now())) } else { None };
+        let cpu_sustained = self.cpu_above_since.is_some_and(|since| since.elapsed() >= thresholds.cpu_sustained);
+        
+        let checks = [
+            ("rss_mb", thresholds.rss_mb.map(|limit| limit as f64), Some(sample.rss_bytes as f64 / MIB), None),
+            ("cpu_percent", thresholds.cpu_percent.map(f64::from), Some(sample.cpu_usage as f64), Some(cpu_sustained)),
+            ("open_fds", thresholds.open_fds.map(|limit| limit as f64), sample.open_fds.map(|n| n as f64), None),
+            ("threads", thresholds.threads.map(|limit| limit as f64), sample.threads.map(|n| n as f64), None),
+        ];
+        
+        let mut crossings = Vec::new();
+        for (metric, threshold, value, tripped_override) in checks {
+            let (Some(threshold), Some(value)) = (threshold, value) else {
+                continue;
+            };
+            let above = tripped_override.unwrap_or(value > threshold);
+            let was_tripped = self.tripped.contains(&metric);
+            if above && !was_tripped {
+                self.tripped.push(metric);
+                crossings.push((metric, AlertState::Triggered, value, threshold));
+            } else if value <= threshold && was_tripped {
+                self.tripped.retain(|m| *m != metric);
+                crossings.push((metric, AlertState::Resolved, value, threshold));
+            }
+        }
+        crossings
+    }
+}
+
+fn describe(metric: &str, state: AlertState, value: f64, threshold: f64, thresholds: &AlertThresholds) -> String {
+    let (name, unit) = match metric {
+        "rss_mb" => ("RSS", " MB"),
+        "cpu_percent" => ("CPU", "%"),
+        "open_fds" => ("Open FDs", ""),
+        _ => ("Threads", ""),
+    };
+    match state {
+        AlertState::Triggered if metric == "cpu_percent" => format!(
+            "{} {:.0}{} above {}{} for {} ms", name, value, unit, threshold, unit, thresholds.cpu_sustained.as_millis()
+        ),
+        AlertState::Triggered => format!("{} {:.0}{} above {}{}", name, value, unit, threshold, unit),
+        AlertState::Resolved => format!("{} back to {:.0}{} (threshold {}{})", name, value, unit, threshold, unit),
+    }
+}
+
+/// Background sampler checking one app's thresholds, owned by its `ProcessInfo`.
+pub struct AlertMonitor {
+    task: JoinHandle<()>,
+    thresholds: AlertThresholds,
+}
+
+impl AlertMonitor {
+    /// Sample every `thresholds.interval`, record crossings in `history`
+    /// and hand each one to `notify`.
+    pub fn start(
+        process_id: String,
+        sampler: ResourceSampler,
+        thresholds: AlertThresholds,
+        history: Arc<Mutex<AlertHistory>>,
+        notify: impl Fn(&Alert) + Send + 'static,
+    ) -> Self {
+        let mut evaluator = Evaluator { thresholds: thresholds.clone(), tripped: Vec::new(), cpu_above_since: None };
+        let interval = thresholds.interval;
+        
+        let task = tokio::spawn(async move {
+            let started = Instant::now();
+            // The first check already reads CPU usage over a full interval
+            sampler.prime().await;
+            let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            
+            loop {
+                ticker.tick().await;
+                let reader = sampler.clone();
+                let Some(sample) = tokio::task::spawn_blocking(move || reader.take(started)).await.ok().flatten() else {
+                    continue;
+                };
+                for (metric, state, value, threshold) in evaluator.evaluate(&sample) {
+                    let alert = history.lock().push(Alert {
+                        seq: 0,
+                        process_id: process_id.clone(),
+                        pid: sample.pid,
+                        metric,
+                        state,
+                        value: (value * 10.0).round() / 10.0,
+                        threshold,
+                        message: describe(metric, state, value, threshold, &evaluator.thresholds),
+                        timestamp: Utc::now(),
+                    });
+                    notify(&alert);
+                }
+            }
+        });
+        
+        Self { task, thresholds }
+    }
+    
+    pub fn thresholds(&self) -> &AlertThresholds {
+        &self.thresholds
+    }
+}
+
+impl Drop for AlertMonitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,452 @@
+use crate::tools::build::TauriProject;
+use crate::tools::process::ProcessManager;
+use crate::tools::supervisor::ProcessState;
+use crate::utils::app_dirs::{AppDirs, BaseDirs};
+use crate::utils::isolation::copy_dir;
+use crate::utils::platform::project_for_executable;
+use crate::{Result, TauriMcpError};
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::ffi::OsString;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::info;
+use uuid::Uuid;
+
+const DEFAULT_MAX_DEPTH: usize = 8;
+const DEFAULT_MAX_ENTRIES: usize = 1000;
+const DEFAULT_MAX_BYTES: usize = 64 * 1024;
+const MAX_BYTES: usize = 1024 * 1024;
+/// Binary files are only shown as far as their magic numbers and headers.
+const BINARY_PREVIEW_BYTES: usize = 256;
+const SQLITE_MAGIC: &[u8] = b"SQLite format 3\0";
+const SQLITE_HEADER_BYTES: usize = 100;
+const SQLITE_PREVIEW_TABLES: usize = 20;
+const SQLITE_PREVIEW_ROWS: usize = 5;
+const SQLITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Which app the app data tools look at, and in which environment.
+#[derive(Debug, Clone, Default)]
+pub struct AppDataTarget {
+    pub identifier: Option<String>,
+    /// A Tauri project, or an executable built from one
+    pub app_path: Option<PathBuf>,
+    /// A managed app: its executable's project supplies the identifier, and
+    /// an `isolated` launch its home directory
+    pub process_id: Option<String>,
+}
+
+impl AppDataTarget {
+    pub fn from_arguments(arguments: &Value) -> Self {
+        Self {
+            identifier: arguments.get("identifier").and_then(|v| v.as_str()).map(String::from),
+            app_path: arguments.get("app_path").and_then(|v| v.as_str()).map(PathBuf::from),
+            process_id: arguments.get("process_id").and_then(|v| v.as_str()).map(String::from),
+        }
+    }
+    
+    /// The app's directories, as its path resolver would find them.
+    pub async fn resolve(&self, process_manager: &RwLock<ProcessManager>) -> Result<AppDirs> {
+        let (executable, profile) = match &self.process_id {
+            Some(process_id) => {
+                let manager = process_manager.read().await;
+                (
+                    manager.executable_of(process_id)?.map(Path::to_path_buf),
+                    manager.isolated_profile(process_id).cloned(),
+                )
+            }
+            None => (None, None),
+        };
+        
+        let identifier = match &self.identifier {
+            Some(identifier) => identifier.clone(),
+            None => self.app_path.as_deref()
+                .and_then(|path| TauriProject::detect(path).or_else(|| project_for_executable(path)))
+                .or_else(|| executable.as_deref().and_then(project_for_executable))
+                .and_then(|project| project.identifier())
+                .ok_or_else(|| TauriMcpError::Other(
+                    "Could not find the app's identifier; pass identifier, or an app_path or process_id of a Tauri project with tauri.conf.json".to_string()
+                ))?,
+        };
+        
+        let base = match &profile {
+            Some(profile) => BaseDirs::with_env(|name| {
+                profile.env().iter()
+                    .find(|(variable, _)| *variable == name)
+                    .map(|(_, dir)| OsString::from(dir))
+                    .or_else(|| std::env::var_os(name))
+            }),
+            None => BaseDirs::from_env(),
+        };
+        let base = base.ok_or_else(|| TauriMcpError::Other("Could not determine the user's home directory".to_string()))?;
+        AppDirs::new(&identifier, &base)
+    }
+    
+    /// Refuse to go on while the targeted managed app is running, unless
+    /// `force`: it holds its SQLite, WAL and WebKit storage files open, and
+    /// deleting them underneath it leaves it in an inconsistent state.
+    pub async fn ensure_stopped(&self, process_manager: &RwLock<ProcessManager>, force: bool) -> Result<()> {
+        let Some(process_id) = self.process_id.as_deref().filter(|_| !force) else {
+            return Ok(());
+        };
+        // Attached processes have no status and are assumed to be running
+        let running = process_manager.read().await.status_of(process_id)?
+            .is_none_or(|status| status.state != ProcessState::Exited);
+        if running {
+            return Err(TauriMcpError::Other(format!(
+                "{} is still running; stop it with stop_app first, or pass force: true", process_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// One file or directory under an app directory.
+#[derive(Debug, Clone, Serialize)]
+pub struct AppDataEntry {
+    /// Name of the app directory it is in, e.g. `data`
+    pub dir: &'static str,
+    /// Relative to that directory
+    pub path: String,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub size: u64,
+    pub modified: Option<DateTime<Utc>>,
+}
+
+/// Options for `list_app_data`.
+#[derive(Debug, Clone)]
+pub struct ListAppDataOptions {
+    /// Only this directory; all of them by default
+    pub dir: Option<String>,
+    pub max_depth: usize,
+    pub max_entries: usize,
+}
+
+impl ListAppDataOptions {
+    pub fn from_arguments(arguments: &Value) -> Self {
+        Self {
+            dir: arguments.get("dir").and_then(|v| v.as_str()).map(String::from),
+            max_depth: arguments.get("max_depth").and_then(|v| v.as_u64()).map_or(DEFAULT_MAX_DEPTH, |n| n as usize),
+            max_entries: arguments.get("max_entries").and_then(|v| v.as_u64()).map_or(DEFAULT_MAX_ENTRIES, |n| n as usize),
+        }
+    }
+}
+
+/// The app's directories and the files in them.
+pub fn list_app_data(dirs: &AppDirs, options: &ListAppDataOptions) -> Result<Value> {
+    let roots = match &options.dir {
+        Some(name) => selected(dirs, std::slice::from_ref(name))?,
+        None => dirs.roots(),
+    };
+    
+    let mut entries = Vec::new();
+    let mut truncated = false;
+    for (name, root) in roots {
+        truncated |= walk(root, root, name, options.max_depth, options.max_entries, &mut entries)?;
+    }
+    let total_bytes: u64 = entries.iter().filter(|entry| entry.kind == "file").map(|entry| entry.size).sum();
+    
+    Ok(json!({
+        "identifier": dirs.identifier,
+        "dirs": dir_summary(dirs),
+        "files": entries,
+        "total_bytes": total_bytes,
+        "truncated": truncated,
+    }))
+}
+
+/// Read a file in one of the app's directories: parsed when it is JSON,
+/// tables and sample rows when it is a SQLite database, else text or the
+/// start of its bytes. `path` is relative to `dir` (default `data`) or
+/// absolute, and may not lead outside the app's directories.
+pub async fn read_app_data_file(dirs: &AppDirs, path: &str, dir: Option<&str>, max_bytes: Option<usize>) -> Result<Value> {
+    let path = resolve_file(dirs, path, dir)?;
+    let metadata = std::fs::metadata(&path)?;
+    if metadata.is_dir() {
+        return Err(TauriMcpError::Other(format!("{} is a directory; use list_app_data", path.display())));
+    }
+    let max_bytes = max_bytes.unwrap_or(DEFAULT_MAX_BYTES).min(MAX_BYTES);
+    
+    let mut bytes = Vec::new();
+    std::fs::File::open(&path)?.take(max_bytes.max(SQLITE_HEADER_BYTES) as u64).read_to_end(&mut bytes)?;
+    let mut result = json!({
+        "path": path,
+        "size": metadata.len(),
+        "modified": metadata.modified().ok().map(DateTime::<Utc>::from),
+    });
+    
+    if bytes.starts_with(SQLITE_MAGIC) && bytes.len() >= SQLITE_HEADER_BYTES {
+        result["format"] = json!("sqlite");
+        result["sqlite"] = sqlite_preview(&path, &bytes[..SQLITE_HEADER_BYTES]).await;
+        return Ok(result);
+    }
+    
+    bytes.truncate(max_bytes);
+    let truncated = (bytes.len() as u64) < metadata.len();
+    result["truncated"] = json!(truncated);
+    
+    let looks_like_json = path.extension().is_some_and(|ext| ext == "json")
+        || bytes.iter().find(|b| !b.is_ascii_whitespace()).is_some_and(|b| *b == b'{' || *b == b'[');
+    if looks_like_json && !truncated {
+        if let Ok(value) = serde_json::from_slice::<Value>(&bytes) {
+            result["format"] = json!("json");
+            result["json"] = value;
+            return Ok(result);
+        }
+    }
+    
+    let text = match std::str::from_utf8(&bytes) {
+        Ok(text) => Some(text),
+        // Cut off mid-character by max_bytes
+        Err(e) if truncated && e.error_len().is_none() => std::str::from_utf8(&bytes[..e.valid_up_to()]).ok(),
+        Err(_) => None,
+    };
+    match text {
+        Some(text) => {
+            result["format"] = json!("text");
+            result["text"] = json!(text);
+        }
+        None => {
+            bytes.truncate(BINARY_PREVIEW_BYTES);
+            result["format"] = json!("binary");
+            result["truncated"] = json!((bytes.len() as u64) < metadata.len());
+            result["base64"] = json!(base64::engine::general_purpose::STANDARD.encode(&bytes));
+        }
+    }
+    Ok(result)
+}
+
+/// Copy the app's directories (`names`, or all of them) to a new temporary
+/// directory, one subdirectory per name, to hand to `reset_app_data` later.
+pub fn snapshot_app_data(dirs: &AppDirs, names: &[String]) -> Result<Value> {
+    let selection = if names.is_empty() { dirs.roots() } else { selected(dirs, names)? };
+    let snapshot = std::env::temp_dir().join(format!("tauri-mcp-app-data-{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&snapshot)?;
+    
+    let mut copied = serde_json::Map::new();
+    for (name, dir) in selection {
+        let summary = if dir.is_dir() {
+            copy_dir(dir, &snapshot.join(name))?;
+            let (files, bytes) = tree_size(dir)?;
+            json!({ "path": dir, "exists": true, "files": files, "bytes": bytes })
+        } else {
+            json!({ "path": dir, "exists": false })
+        };
+        copied.insert(name.to_string(), summary);
+    }
+    
+    info!("Snapshot of {} app data at {}", dirs.identifier, snapshot.display());
+    Ok(json!({
+        "identifier": dirs.identifier,
+        "snapshot": snapshot,
+        "dirs": copied,
+    }))
+}
+
+/// Delete the app's directories (`names`, or all of them) so it starts as
+/// on first launch, then optionally put back what a snapshot holds for them.
+pub fn reset_app_data(dirs: &AppDirs, names: &[String], restore: Option<&Path>) -> Result<Value> {
+    let selection = if names.is_empty() { dirs.roots() } else { selected(dirs, names)? };
+    if let Some(restore) = restore {
+        if !restore.is_dir() {
+            return Err(TauriMcpError::Other(format!("Snapshot does not exist: {}", restore.display())));
+        }
+    }
+    
+    let mut removed = Vec::new();
+    for (name, dir) in &selection {
+        if dir.is_dir() {
+            let (files, bytes) = tree_size(dir)?;
+            std::fs::remove_dir_all(dir)?;
+            removed.push(json!({ "dir": name, "path": dir, "files": files, "bytes": bytes }));
+        }
+    }
+    
+    let mut restored = Vec::new();
+    if let Some(restore) = restore {
+        for (name, dir) in &selection {
+            let source = restore.join(name);
+            if source.is_dir() {
+                copy_dir(&source, dir)?;
+                let (files, bytes) = tree_size(dir)?;
+                restored.push(json!({ "dir": name, "path": dir, "files": files, "bytes": bytes }));
+            }
+        }
+    }
+    
+    info!("Reset {} app data: {} directories removed, {} restored", dirs.identifier, removed.len(), restored.len());
+    Ok(json!({
+        "identifier": dirs.identifier,
+        "removed": removed,
+        "restored": restored,
+    }))
+}
+
+/// Every directory with whether it exists yet.
+fn dir_summary(dirs: &AppDirs) -> Value {
+    let summary: serde_json::Map<String, Value> = dirs.all()
+        .into_iter()
+        .map(|(name, dir)| (name.to_string(), json!({ "path": dir, "exists": dir.is_dir() })))
+        .collect();
+    Value::Object(summary)
+}
+
+/// The directories called `names`.
+fn selected<'a>(dirs: &'a AppDirs, names: &[String]) -> Result<Vec<(&'static str, &'a Path)>> {
+    names.iter().map(|name| dirs.named(name)).collect()
+}
+
+fn resolve_file(dirs: &AppDirs, path: &str, dir: Option<&str>) -> Result<PathBuf> {
+    let path = Path::new(path);
+    let candidate = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        dirs.named(dir.unwrap_or("data"))?.1.join(path)
+    };
+    let canonical = candidate.canonicalize()
+        .map_err(|e| TauriMcpError::Other(format!("Cannot read {}: {}", candidate.display(), e)))?;
+    
+    let inside = dirs.all().iter().any(|(_, root)| root.canonicalize().is_ok_and(|root| canonical.starts_with(root)));
+    if !inside {
+        return Err(TauriMcpError::Other(format!("{} is outside the app's directories", canonical.display())));
+    }
+    Ok(canonical)
+}
+
+/// Append the entries under `dir` in name order; true when `max_entries`
+/// cut the listing short.
+fn walk(root: &Path, dir: &Path, name: &'static str, depth: usize, max_entries: usize, entries: &mut Vec<AppDataEntry>) -> Result<bool> {
+    if !dir.is_dir() {
+        return Ok(false);
+    }
+    
+    let mut children: Vec<_> = std::fs::read_dir(dir)?.collect::<std::io::Result<_>>()?;
+    children.sort_by_key(|entry| entry.file_name());
+    for child in children {
+        if entries.len() >= max_entries {
+            return Ok(true);
+        }
+        let path = child.path();
+        let metadata = std::fs::symlink_metadata(&path)?;
+        let kind = if metadata.is_symlink() {
+            "symlink"
+        } else if metadata.is_dir() {
+            "dir"
+        } else {
+            "file"
+        };
+        entries.push(AppDataEntry {
+            dir: name,
+            path: path.strip_prefix(root).unwrap_or(&path).to_string_lossy().to_string(),
+            kind,
+            size: metadata.len(),
+            modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+        });
+        if kind == "dir" && depth > 1 && walk(root, &path, name, depth - 1, max_entries, entries)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Number of files under `dir` and their total size.
+fn tree_size(dir: &Path) -> Result<(u64, u64)> {
+    let mut totals = (0, 0);
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            let (files, bytes) = tree_size(&entry.path())?;
+            totals = (totals.0 + files, totals.1 + bytes);
+        } else if file_type.is_file() {
+            totals = (totals.0 + 1, totals.1 + entry.metadata()?.len());
+        }
+    }
+    Ok(totals)
+}
+
+/// Header fields of a SQLite database, with its tables, row counts and
+/// first rows when the `sqlite3` shell is installed.
+async fn sqlite_preview(path: &Path, header: &[u8]) -> Value {
+    let be_u32 = |offset: usize| u32::from_be_bytes([header[offset], header[offset + 1], header[offset + 2], header[offset + 3]]);
+    let page_size = match u16::from_be_bytes([header[16], header[17]]) {
+        1 => 65536,
+        size => size as u32,
+    };
+    let mut preview = json!({
+        "page_size": page_size,
+        "page_count": be_u32(28),
+        "journal_mode": if header[18] == 2 { "wal" } else { "rollback" },
+        "text_encoding": match be_u32(56) {
+            1 => "UTF-8",
+            2 => "UTF-16le",
+            3 => "UTF-16be",
+            _ => "unknown",
+        },
+        "user_version": be_u32(60) as i32,
+    });
+    
+    match sqlite_tables(path).await {
+        Ok(tables) => preview["tables"] = json!(tables),
+        Err(e) => preview["tables_error"] = json!(e.to_string()),
+    }
+    preview
+}
+
+async fn sqlite_tables(path: &Path) -> Result<Vec<Value>> {
+    let schema = sqlite_query(
+        path,
+        "SELECT type, name FROM sqlite_master WHERE type IN ('table', 'view') AND name NOT LIKE 'sqlite_%' ORDER BY name",
+    ).await?;
+    
+    let mut tables = Vec::new();
+    for table in schema.into_iter().take(SQLITE_PREVIEW_TABLES) {
+        let name = table["name"].as_str().unwrap_or_default();
+        let quoted = format!("\"{}\"", name.replace('"', "\"\""));
+        let count = sqlite_query(path, &format!("SELECT count(*) AS count FROM {}", quoted)).await?;
+        let rows = sqlite_query(path, &format!("SELECT * FROM {} LIMIT {}", quoted, SQLITE_PREVIEW_ROWS)).await?;
+        tables.push(json!({
+            "name": name,
+            "type": table["type"],
+            "rows": count.first().and_then(|row| row["count"].as_u64()),
+            "sample": rows,
+        }));
+    }
+    Ok(tables)
+}
+
+/// Run a read-only query with the `sqlite3` shell, rows as JSON objects.
+async fn sqlite_query(path: &Path, sql: &str) -> Result<Vec<Value>> {
+    let output = tokio::process::Command::new("sqlite3")
+        .args(["-readonly", "-json"])
+        .arg(path)
+        .arg(sql)
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true)
+        .output();
+    let output = match tokio::time::timeout(SQLITE_TIMEOUT, output).await {
+        Ok(Ok(output)) if output.status.success() => output,
+        Ok(Ok(output)) => return Err(TauriMcpError::Other(format!(
+            "sqlite3 failed: {}", String::from_utf8_lossy(&output.stderr).trim()
+        ))),
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::NotFound => return Err(TauriMcpError::Other(
+            "sqlite3 is not installed; only the header was read".to_string()
+        )),
+        Ok(Err(e)) => return Err(TauriMcpError::Other(format!("Failed to run sqlite3: {}", e))),
+        Err(_) => return Err(TauriMcpError::Other(format!(
+            "sqlite3 did not finish within {} s", SQLITE_TIMEOUT.as_secs()
+        ))),
+    };
+    
+    // No rows print nothing rather than []
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_str(&stdout).map_err(|e| TauriMcpError::Other(format!("Unexpected sqlite3 output: {}", e)))
+}
@@ -0,0 +1,403 @@
+use crate::{Result, TauriMcpError};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tracing::{debug, info};
+
+/// Cargo progress lines kept in a build report; diagnostics are kept in full.
+const MAX_OUTPUT_LINES: usize = 200;
+
+const CONFIG_FILES: &[&str] = &["tauri.conf.json", "tauri.conf.json5", "Tauri.toml"];
+
+/// A Tauri project on disk, found from its root or its `src-tauri` directory.
+#[derive(Debug, Clone, Serialize)]
+pub struct TauriProject {
+    pub root: PathBuf,
+    pub src_tauri: PathBuf,
+    pub config_path: PathBuf,
+}
+
+impl TauriProject {
+    /// Recognise `path` as a project root containing `src-tauri/tauri.conf.json`,
+    /// or as the `src-tauri` directory itself.
+    pub fn detect(path: &Path) -> Option<Self> {
+        if !path.is_dir() {
+            return None;
+        }
+        
+        let candidates = [(path.join("src-tauri"), path), (path.to_path_buf(), path.parent().unwrap_or(path))];
+        for (src_tauri, root) in candidates {
+            if !src_tauri.join("Cargo.toml").is_file() {
+                continue;
+            }
+            if let Some(config) = CONFIG_FILES.iter().map(|f| src_tauri.join(f)).find(|p| p.is_file()) {
+                return Some(Self {
+                    root: root.to_path_buf(),
+                    src_tauri,
+                    config_path: config,
+                });
+            }
+        }
+        
+        None
+    }
+    
+    pub fn manifest_path(&self) -> PathBuf {
+        self.src_tauri.join("Cargo.toml")
+    }
+    
+    /// The parsed `tauri.conf.json`, when the project uses the JSON format.
+    pub fn config(&self) -> Option<Value> {
+        if self.config_path.extension().and_then(|e| e.to_str()) != Some("json") {
+            return None;
+        }
+        let contents = std::fs::read_to_string(&self.config_path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+    
+    /// The bundle identifier: top-level in Tauri 2, under `tauri.bundle` in Tauri 1.
+    pub fn identifier(&self) -> Option<String> {
+        let config = self.config()?;
+        config.get("identifier")
+            .or_else(|| config.pointer("/tauri/bundle/identifier"))
+            .and_then(|v| v.as_str())
+            .map(String::from)
+    }
+    
+    /// `build.beforeBuildCommand` and the directory it runs in: a string, or
+    /// `{ script, cwd }` with `cwd` relative to the project root.
+    fn before_build_command(&self) -> Option<(String, PathBuf)> {
+        let command = self.config()?.pointer("/build/beforeBuildCommand")?.clone();
+        match command {
+            Value::String(script) => Some((script, self.root.clone())),
+            Value::Object(command) => {
+                let script = command.get("script")?.as_str()?.to_string();
+                let cwd = command.get("cwd").and_then(|v| v.as_str()).map_or_else(|| self.root.clone(), |cwd| self.root.join(cwd));
+                Some((script, cwd))
+            }
+            _ => None,
+        }
+        .filter(|(script, _)| !script.trim().is_empty())
+    }
+    
+    fn manifest(&self) -> Option<toml::Value> {
+        let contents = std::fs::read_to_string(self.manifest_path()).ok()?;
+        toml::from_str(&contents).ok()
+    }
+    
+    /// Whether `src-tauri/Cargo.toml` has `tauri` among its dependencies.
+    fn depends_on_tauri(&self) -> bool {
+        self.manifest()
+            .and_then(|manifest| manifest.get("dependencies").and_then(|deps| deps.get("tauri")).map(|_| ()))
+            .is_some()
+    }
+    
+    /// Cargo package name from `src-tauri/Cargo.toml`.
+    fn package_name(&self) -> Option<String> {
+        let manifest = self.manifest()?;
+        let package = manifest.get("package")?;
+        package.get("default-run")
+            .or_else(|| package.get("name"))
+            .and_then(|v| v.as_str())
+            .map(String::from)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+    pub release: bool,
+    pub features: Vec<String>,
+    /// Binary target to launch when the package has several
+    pub bin: Option<String>,
+    /// Load `build.devUrl` from a dev server the caller runs, instead of
+    /// building the frontend and embedding it
+    pub dev_server: bool,
+}
+
+impl BuildOptions {
+    /// Parse build options from `launch_app` / `build_app` tool arguments.
+    pub fn from_arguments(arguments: &Value) -> Self {
+        Self {
+            release: arguments.get("release").and_then(|v| v.as_bool()).unwrap_or(false),
+            features: arguments.get("features")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect())
+                .unwrap_or_default(),
+            bin: arguments.get("bin").and_then(|v| v.as_str()).map(String::from),
+            dev_server: arguments.get("dev_server").and_then(|v| v.as_bool()).unwrap_or(false),
+        }
+    }
+}
+
+/// A compiler error or warning from cargo's JSON message stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    pub level: String,
+    pub message: String,
+    pub code: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<u64>,
+    pub column: Option<u64>,
+    pub rendered: Option<String>,
+}
+
+impl Diagnostic {
+    fn from_compiler_message(message: &Value) -> Option<Self> {
+        let level = message.get("level")?.as_str()?.to_string();
+        let text = message.get("message")?.as_str()?.to_string();
+        
+        let spans = message.get("spans").and_then(|v| v.as_array());
+        let primary = spans.and_then(|spans| {
+            spans.iter().find(|s| s.get("is_primary").and_then(|v| v.as_bool()).unwrap_or(false))
+        });
+        
+        // rustc's closing summaries ("aborting due to ...", "2 warnings emitted",
+        // "try `rustc --explain`") repeat what the individual diagnostics say
+        if level == "failure-note"
+            || (primary.is_none() && (text.starts_with("aborting due to") || text.ends_with("emitted")))
+        {
+            return None;
+        }
+        
+        Some(Self {
+            level,
+            message: text,
+            code: message.get("code")
+                .and_then(|c| c.get("code"))
+                .and_then(|v| v.as_str())
+                .map(String::from),
+            file: primary.and_then(|s| s.get("file_name")).and_then(|v| v.as_str()).map(String::from),
+            line: primary.and_then(|s| s.get("line_start")).and_then(|v| v.as_u64()),
+            column: primary.and_then(|s| s.get("column_start")).and_then(|v| v.as_u64()),
+            rendered: message.get("rendered").and_then(|v| v.as_str()).map(String::from),
+        })
+    }
+}
+
+/// The project's `beforeBuildCommand`, run ahead of cargo.
+#[derive(Debug, Clone, Serialize)]
+pub struct FrontendBuild {
+    pub command: String,
+    pub success: bool,
+    pub exit_code: Option<i32>,
+    /// Tail of its stdout and stderr
+    pub output: Vec<String>,
+    pub duration_ms: u128,
+}
+
+/// Result of building a project, kept apart from the app's runtime logs.
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildReport {
+    pub success: bool,
+    /// Absent with `dev_server` or when the project has no `beforeBuildCommand`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frontend: Option<FrontendBuild>,
+    pub executable: Option<PathBuf>,
+    pub errors: usize,
+    pub warnings: usize,
+    pub diagnostics: Vec<Diagnostic>,
+    /// Tail of cargo's own output (progress, build script failures)
+    pub output: Vec<String>,
+    pub duration_ms: u128,
+}
+
+/// Run the project's `beforeBuildCommand` through the shell, as the Tauri
+/// CLI would before `tauri build`.
+async fn build_frontend(command: &str, cwd: &Path) -> Result<FrontendBuild> {
+    #[cfg(unix)]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    };
+    cmd.current_dir(cwd)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    
+    info!("Building frontend with `{}` in {}", command, cwd.display());
+    let started = Instant::now();
+    
+    let output = cmd.output().await
+        .map_err(|e| TauriMcpError::ProcessError(format!("Failed to run beforeBuildCommand `{}`: {}", command, e)))?;
+    let mut lines = VecDeque::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines().chain(String::from_utf8_lossy(&output.stderr).lines()) {
+        debug!("[frontend] {}", line);
+        if lines.len() == MAX_OUTPUT_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line.to_string());
+    }
+    
+    Ok(FrontendBuild {
+        command: command.to_string(),
+        success: output.status.success(),
+        exit_code: output.status.code(),
+        output: Vec::from(lines),
+        duration_ms: started.elapsed().as_millis(),
+    })
+}
+
+/// Build a Tauri project with `cargo build --message-format=json`.
+///
+/// Unless `dev_server` is set, the result is a standalone app like `tauri
+/// build` makes: the `beforeBuildCommand` builds the frontend first and
+/// `tauri/custom-protocol` embeds it, where a plain cargo build would load
+/// `build.devUrl` and show a blank window without a dev server. Only a
+/// `tauri.conf.json` config is read for the command.
+pub async fn build_project(project: &TauriProject, options: &BuildOptions) -> Result<BuildReport> {
+    let started = Instant::now();
+    
+    let frontend = match project.before_build_command().filter(|_| !options.dev_server) {
+        Some((command, cwd)) => Some(build_frontend(&command, &cwd).await?),
+        None => None,
+    };
+    if frontend.as_ref().is_some_and(|frontend| !frontend.success) {
+        info!("Frontend build of {} failed", project.root.display());
+        return Ok(BuildReport {
+            success: false,
+            frontend,
+            executable: None,
+            errors: 0,
+            warnings: 0,
+            diagnostics: Vec::new(),
+            output: Vec::new(),
+            duration_ms: started.elapsed().as_millis(),
+        });
+    }
+    
+    let manifest_path = project.manifest_path();
+    
+    let mut cmd = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    cmd.arg("build")
+        .arg("--manifest-path")
+        .arg(&manifest_path)
+        .arg("--message-format=json")
+        .current_dir(&project.src_tauri)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    
+    if options.release {
+        cmd.arg("--release");
+    }
+    let mut features = options.features.clone();
+    if !options.dev_server && project.depends_on_tauri() {
+        features.push("tauri/custom-protocol".to_string());
+    }
+    if !features.is_empty() {
+        cmd.arg("--features").arg(features.join(","));
+    }
+    if let Some(bin) = &options.bin {
+        cmd.arg("--bin").arg(bin);
+    }
+    
+    info!("Building Tauri project {}", project.src_tauri.display());
+    
+    let mut child = cmd.spawn()
+        .map_err(|e| TauriMcpError::ProcessError(format!("Failed to run cargo: {}", e)))?;
+    
+    let stdout = child.stdout.take()
+        .ok_or_else(|| TauriMcpError::ProcessError("Failed to capture cargo stdout".to_string()))?;
+    let stderr = child.stderr.take()
+        .ok_or_else(|| TauriMcpError::ProcessError("Failed to capture cargo stderr".to_string()))?;
+    
+    let output_task = tokio::spawn(async move {
+        let mut output = VecDeque::new();
+        let mut lines = BufReader::new(stderr).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            debug!("[cargo] {}", line);
+            if output.len() == MAX_OUTPUT_LINES {
+                output.pop_front();
+            }
+            output.push_back(line);
+        }
+        Vec::from(output)
+    });
+    
+    let canonical_manifest = manifest_path.canonicalize().unwrap_or(manifest_path);
+    let wanted_bin = options.bin.clone().or_else(|| project.package_name());
+    
+    let mut diagnostics = Vec::new();
+    let mut executables: Vec<(String, PathBuf)> = Vec::new();
+    let mut finished_ok = None;
+    
+    let mut lines = BufReader::new(stdout).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let Ok(message) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        
+        match message.get("reason").and_then(|v| v.as_str()) {
+            Some("compiler-message") => {
+                if let Some(diagnostic) = message.get("message").and_then(Diagnostic::from_compiler_message) {
+                    diagnostics.push(diagnostic);
+                }
+            }
+            Some("compiler-artifact") => {
+                let is_bin = message.pointer("/target/kind")
+                    .and_then(|v| v.as_array())
+                    .map(|kinds| kinds.iter().any(|k| k == "bin"))
+                    .unwrap_or(false);
+                let from_project = message.get("manifest_path")
+                    .and_then(|v| v.as_str())
+                    .map(|p| Path::new(p) == canonical_manifest)
+                    .unwrap_or(false);
+                let executable = message.get("executable").and_then(|v| v.as_str());
+                
+                if let (true, true, Some(executable)) = (is_bin, from_project, executable) {
+                    let name = message.pointer("/target/name").and_then(|v| v.as_str()).unwrap_or_default();
+                    executables.push((name.to_string(), PathBuf::from(executable)));
+                }
+            }
+            Some("build-finished") => {
+                finished_ok = message.get("success").and_then(|v| v.as_bool());
+            }
+            _ => {}
+        }
+    }
+    
+    let status = child.wait().await
+        .map_err(|e| TauriMcpError::ProcessError(format!("Failed to wait for cargo: {}", e)))?;
+    let output = output_task.await.unwrap_or_default();
+    
+    let executable = executables.iter()
+        .find(|(name, _)| Some(name) == wanted_bin.as_ref())
+        .or_else(|| executables.first())
+        .map(|(_, path)| path.clone());
+    
+    let success = finished_ok.unwrap_or_else(|| status.success()) && executable.is_some();
+    let errors = diagnostics.iter().filter(|d| d.level == "error").count();
+    let warnings = diagnostics.iter().filter(|d| d.level == "warning").count();
+    
+    info!(
+        "Build of {} {} in {:?} ({} errors, {} warnings)",
+        project.src_tauri.display(),
+        if success { "succeeded" } else { "failed" },
+        started.elapsed(),
+        errors,
+        warnings
+    );
+    
+    Ok(BuildReport {
+        success,
+        frontend,
+        executable,
+        errors,
+        warnings,
+        diagnostics,
+        output,
+        duration_ms: started.elapsed().as_millis(),
+    })
+}
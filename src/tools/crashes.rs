@@ -0,0 +1,334 @@
+use crate::tools::process::ProcessManager;
+use crate::tools::supervisor::PanicReport;
+use crate::tools::threads::StackFrame;
+use crate::{Result, TauriMcpError};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use regex::Regex;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+/// Crash reports kept across all process aliases; older ones are dropped.
+const MAX_CRASHES: usize = 200;
+/// Symbolizers tried in order; both take binutils arguments.
+const ADDR2LINE: &[&str] = &["addr2line", "llvm-addr2line"];
+/// What the standard library prints for a frame it could not symbolize.
+const UNKNOWN_FUNCTION: &str = "<unknown>";
+
+/// Parse the frames of a Rust backtrace as printed with `RUST_BACKTRACE=1`
+/// (`  3: app::main` then `at src/main.rs:2:5`) or `full`
+/// (`  3:     0x55d1c2a1b2c3 - app::main::h0123456789abcdef`).
+pub fn parse_rust_backtrace(lines: &[String]) -> Vec<StackFrame> {
+    let mut frames: Vec<StackFrame> = Vec::new();
+    
+    for line in lines {
+        let line = line.trim();
+        if let Some(location) = line.strip_prefix("at ") {
+            if let Some(frame) = frames.last_mut() {
+                frame.location.get_or_insert_with(|| location.to_string());
+            }
+            continue;
+        }
+        let Some((index, rest)) = line.split_once(": ") else {
+            continue;
+        };
+        if index.parse::<usize>().is_err() {
+            continue;
+        }
+        
+        let rest = rest.trim();
+        let (address, function) = match rest.split_once(" - ") {
+            Some((address, function)) if address.starts_with("0x") => (Some(address.to_string()), function),
+            _ => (None, rest),
+        };
+        frames.push(StackFrame { address, function: strip_symbol_hash(function), location: None });
+    }
+    frames
+}
+
+/// Drop the hashes that make symbol names unique: `app::main::h0123456789abcdef`
+/// (legacy mangling) and `std[e28293b1aa0f68bd]::rt` (v0) become `app::main`
+/// and `std::rt`.
+fn strip_symbol_hash(function: &str) -> String {
+    static DISAMBIGUATOR: OnceLock<Regex> = OnceLock::new();
+    let disambiguator = DISAMBIGUATOR.get_or_init(|| Regex::new(r"\[[0-9a-f]{16}\]").expect("valid regex"));
+    let function = match function.rsplit_once("::h") {
+        Some((name, hash)) if hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()) => name,
+        _ => function,
+    };
+    disambiguator.replace_all(function, "").into_owned()
+}
+
+/// Where a run's executable was mapped, to turn runtime addresses into
+/// addresses in the file.
+#[derive(Debug, Clone)]
+pub struct ExecutableImage {
+    pub path: PathBuf,
+    start: u64,
+    end: u64,
+    /// Subtracted from runtime addresses; 0 for non-PIE executables
+    bias: u64,
+}
+
+impl ExecutableImage {
+    /// Read the executable's mapping of a running process from `/proc`.
+    #[cfg(target_os = "linux")]
+    pub fn of_process(pid: u32) -> Option<Self> {
+        let exe = std::fs::read_link(format!("/proc/{}/exe", pid)).ok()?;
+        let exe_name = exe.to_str()?;
+        // Rebuilt since launch: the file on disk no longer matches
+        if exe_name.ends_with(" (deleted)") {
+            return None;
+        }
+        
+        let maps = std::fs::read_to_string(format!("/proc/{}/maps", pid)).ok()?;
+        let mut image: Option<Self> = None;
+        for line in maps.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 6 || fields[5..].join(" ") != exe_name {
+                continue;
+            }
+            let (start, end) = fields[0].split_once('-')?;
+            let start = u64::from_str_radix(start, 16).ok()?;
+            let end = u64::from_str_radix(end, 16).ok()?;
+            let offset = u64::from_str_radix(fields[2], 16).ok()?;
+            
+            let image = image.get_or_insert_with(|| Self { path: exe.clone(), start, end, bias: 0 });
+            image.start = image.start.min(start);
+            image.end = image.end.max(end);
+            // The segment at file offset 0 is linked at address 0 in a PIE
+            if offset == 0 {
+                image.bias = start;
+            }
+        }
+        
+        let mut image = image?;
+        if !is_position_independent(&image.path) {
+            image.bias = 0;
+        }
+        Some(image)
+    }
+    
+    #[cfg(not(target_os = "linux"))]
+    pub fn of_process(_pid: u32) -> Option<Self> {
+        None
+    }
+    
+    fn file_address(&self, address: u64) -> Option<u64> {
+        (self.start..self.end).contains(&address).then(|| address - self.bias)
+    }
+}
+
+/// ELF `e_type` is `ET_DYN` (3) for PIE executables.
+#[cfg(target_os = "linux")]
+fn is_position_independent(path: &std::path::Path) -> bool {
+    use std::io::Read;
+    let mut header = [0u8; 18];
+    let read = std::fs::File::open(path).and_then(|mut file| file.read_exact(&mut header));
+    read.is_ok() && &header[..4] == b"\x7fELF" && u16::from_le_bytes([header[16], header[17]]) == 3
+}
+
+/// Resolve the executable's unsymbolized frames with addr2line against its
+/// debug info. Returns how many frames were resolved.
+pub async fn symbolize(frames: &mut [StackFrame], image: &ExecutableImage) -> Result<usize> {
+    let pending: Vec<(usize, u64)> = frames.iter()
+        .enumerate()
+        .filter(|(_, frame)| frame.function == UNKNOWN_FUNCTION)
+        .filter_map(|(index, frame)| {
+            let address = u64::from_str_radix(frame.address.as_deref()?.trim_start_matches("0x"), 16).ok()?;
+            // Callers' addresses are return addresses, just past the call
+            let address = if index == 0 { address } else { address.checked_sub(1)? };
+            Some((index, image.file_address(address)?))
+        })
+        .collect();
+    if pending.is_empty() {
+        return Ok(0);
+    }
+    
+    let addresses: Vec<String> = pending.iter().map(|(_, address)| format!("{:#x}", address)).collect();
+    for program in ADDR2LINE {
+        let output = tokio::process::Command::new(program)
+            .args(["-C", "-f", "-e"])
+            .arg(&image.path)
+            .args(&addresses)
+            .stdin(std::process::Stdio::null())
+            .output()
+            .await;
+        let output = match output {
+            Ok(output) if output.status.success() => output,
+            Ok(output) => return Err(TauriMcpError::ProcessError(format!(
+                "{} failed: {}", program, String::from_utf8_lossy(&output.stderr).trim()
+            ))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(TauriMcpError::ProcessError(format!("Failed to run {}: {}", program, e))),
+        };
+        
+        // Two lines per address: function, then file:line
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let lines: Vec<&str> = stdout.lines().collect();
+        let mut resolved = 0;
+        for ((index, _), answer) in pending.iter().zip(lines.chunks(2)) {
+            let [function, location] = answer else {
+                break;
+            };
+            if *function == "??" {
+                continue;
+            }
+            frames[*index].function = function.to_string();
+            if !location.starts_with("??") {
+                frames[*index].location = Some(location.to_string());
+            }
+            resolved += 1;
+        }
+        return Ok(resolved);
+    }
+    
+    Err(TauriMcpError::ProcessError(
+        "No symbolizer found; install binutils (addr2line) or LLVM (llvm-addr2line)".to_string()
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrashKind {
+    Panic,
+    /// Killed by a signal without a panic, e.g. SIGSEGV or SIGABRT
+    Signal,
+    /// Failing exit code without a panic
+    Exit,
+}
+
+/// One crash of a managed app.
+#[derive(Debug, Clone, Serialize)]
+pub struct CrashReport {
+    pub seq: u64,
+    pub process_id: String,
+    /// Run of the app, counting restarts from 1
+    pub run: u32,
+    pub pid: u32,
+    pub kind: CrashKind,
+    /// How the run ended, once it has
+    pub exit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub panic: Option<PanicReport>,
+    /// Frames resolved from the executable's debug info
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbolized_frames: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbolize_error: Option<String>,
+    pub timestamp: String,
+    #[serde(skip)]
+    image: Option<ExecutableImage>,
+}
+
+/// Crash reports of all launched apps by process alias, kept across
+/// restarts and after the app is stopped.
+#[derive(Debug, Default)]
+pub struct CrashHistory {
+    crashes: VecDeque<CrashReport>,
+    next_seq: u64,
+}
+
+impl CrashHistory {
+    fn push(&mut self, process_id: &str, run: u32, pid: u32, kind: CrashKind, exit: Option<String>) -> &mut CrashReport {
+        if self.crashes.len() == MAX_CRASHES {
+            self.crashes.pop_front();
+        }
+        self.crashes.push_back(CrashReport {
+            seq: self.next_seq,
+            process_id: process_id.to_string(),
+            run,
+            pid,
+            kind,
+            exit,
+            panic: None,
+            symbolized_frames: None,
+            symbolize_error: None,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            image: None,
+        });
+        self.next_seq += 1;
+        self.crashes.back_mut().expect("just pushed")
+    }
+    
+    pub fn record_panic(&mut self, process_id: &str, run: u32, pid: u32, panic: PanicReport, image: Option<ExecutableImage>) {
+        let report = self.push(process_id, run, pid, CrashKind::Panic, None);
+        report.timestamp = panic.detected_at.clone();
+        report.panic = Some(panic);
+        report.image = image;
+    }
+    
+    /// Record how a crashed run ended: on its panics, or as a crash of its own.
+    pub fn record_exit(&mut self, process_id: &str, run: u32, pid: u32, exit: String, signaled: bool) {
+        let mut panicked = false;
+        for report in self.crashes.iter_mut().filter(|report| report.process_id == process_id && report.run == run && report.pid == pid) {
+            report.exit = Some(exit.clone());
+            panicked = true;
+        }
+        if !panicked {
+            let kind = if signaled { CrashKind::Signal } else { CrashKind::Exit };
+            self.push(process_id, run, pid, kind, Some(exit));
+        }
+    }
+    
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+    
+    /// Crashes from `since_seq` on, optionally of one alias only.
+    pub fn query(&self, process_id: Option<&str>, since_seq: u64) -> Vec<CrashReport> {
+        self.crashes.iter()
+            .filter(|report| report.seq >= since_seq)
+            .filter(|report| process_id.is_none_or(|id| report.process_id == id))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Crash history of `process_id` (or of all apps), with unsymbolized panic
+/// frames resolved on first read when `symbolize` is set.
+pub async fn list_crashes(
+    process_manager: &RwLock<ProcessManager>,
+    process_id: Option<&str>,
+    since_seq: u64,
+    symbolize_frames: bool,
+) -> Result<Value> {
+    let history = process_manager.read().await.crash_history();
+    
+    if symbolize_frames {
+        let pending: Vec<CrashReport> = history.lock().query(process_id, since_seq)
+            .into_iter()
+            .filter(|report| report.symbolized_frames.is_none() && report.symbolize_error.is_none())
+            .filter(|report| report.panic.as_ref().is_some_and(|panic| panic.frames.iter().any(|f| f.function == UNKNOWN_FUNCTION)))
+            .collect();
+        
+        // addr2line runs without the history locked
+        for mut report in pending {
+            let (Some(image), Some(panic)) = (report.image.clone(), report.panic.as_mut()) else {
+                continue;
+            };
+            let result = symbolize(&mut panic.frames, &image).await;
+            debug!("Symbolized crash {} of {}: {:?}", report.seq, report.process_id, result);
+            
+            let mut history = history.lock();
+            if let Some(stored) = history.crashes.iter_mut().find(|stored| stored.seq == report.seq) {
+                match result {
+                    Ok(resolved) => {
+                        stored.symbolized_frames = Some(resolved);
+                        stored.panic = report.panic;
+                    }
+                    Err(e) => stored.symbolize_error = Some(e.to_string()),
+                }
+            }
+        }
+    }
+    
+    let history = history.lock();
+    Ok(json!({
+        "crashes": history.query(process_id, since_seq),
+        "next_seq": history.next_seq(),
+    }))
+}
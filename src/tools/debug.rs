@@ -1,10 +1,21 @@
+use crate::utils::websocket::WebSocket;
 use crate::{Result, TauriMcpError};
 use reqwest::Client;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::time::Duration;
 use tracing::{debug, error, info};
 
+/// Default WebDriver endpoint (tauri-driver / chromedriver).
+pub const WEBDRIVER_URL: &str = "http://localhost:9515";
+
+/// Ports scanned for a Chromium DevTools endpoint.
+pub const DEVTOOLS_PORTS: Range<u16> = 9222..9250;
+
+/// Largest DevTools message accepted; replies to evaluations are small.
+const MAX_DEVTOOLS_MESSAGE: usize = 16 * 1024 * 1024;
+
 pub struct DebugTools {
     client: Client,
     webdriver_sessions: HashMap<String, WebDriverSession>,
@@ -77,7 +88,7 @@ impl DebugTools {
             }
         });
         
-        let url = format!("http://localhost:9515/session");
+        let url = format!("{}/session", WEBDRIVER_URL);
         let response = self.client.post(&url)
             .json(&capabilities)
             .send()
@@ -107,7 +118,7 @@ impl DebugTools {
         info!("Getting page source for process: {}", process_id);
         
         if let Some(session) = self.webdriver_sessions.get(process_id) {
-            let url = format!("http://localhost:9515/session/{}/source", session.session_id);
+            let url = format!("{}/session/{}/source", WEBDRIVER_URL, session.session_id);
             let response = self.client.get(&url)
                 .send()
                 .await
@@ -130,7 +141,7 @@ impl DebugTools {
         info!("Getting console logs for process: {}", process_id);
         
         if let Some(session) = self.webdriver_sessions.get(process_id) {
-            let url = format!("http://localhost:9515/session/{}/se/log", session.session_id);
+            let url = format!("{}/session/{}/se/log", WEBDRIVER_URL, session.session_id);
             let body = serde_json::json!({
                 "type": "browser"
             });
@@ -158,7 +169,7 @@ impl DebugTools {
         info!("Taking element screenshot for process {}, selector: {}", process_id, selector);
         
         if let Some(session) = self.webdriver_sessions.get(process_id) {
-            let find_url = format!("http://localhost:9515/session/{}/element", session.session_id);
+            let find_url = format!("{}/session/{}/element", WEBDRIVER_URL, session.session_id);
             let find_body = serde_json::json!({
                 "using": "css selector",
                 "value": selector
@@ -181,8 +192,8 @@ impl DebugTools {
                 .or_else(|| element_data["value"]["ELEMENT"].as_str())
                 .ok_or_else(|| TauriMcpError::WebDriverError("No element ID in response".to_string()))?;
             
-            let screenshot_url = format!("http://localhost:9515/session/{}/element/{}/screenshot", 
-                                       session.session_id, element_id);
+            let screenshot_url = format!("{}/session/{}/element/{}/screenshot", 
+                                       WEBDRIVER_URL, session.session_id, element_id);
             
             let screenshot_response = self.client.get(&screenshot_url)
                 .send()
@@ -203,7 +214,7 @@ impl DebugTools {
     }
     
     async fn execute_via_webdriver(&self, session: &WebDriverSession, javascript_code: &str) -> Result<Value> {
-        let url = format!("http://localhost:9515/session/{}/execute/sync", session.session_id);
+        let url = format!("{}/session/{}/execute/sync", WEBDRIVER_URL, session.session_id);
         let body = serde_json::json!({
             "script": javascript_code,
             "args": []
@@ -256,7 +267,7 @@ impl DebugTools {
     }
     
     async fn find_debug_port(&self, _process_id: &str) -> Result<u16> {
-        for port in 9222..9250 {
+        for port in DEVTOOLS_PORTS {
             let url = format!("http://localhost:{}/json/version", port);
             if let Ok(response) = self.client.get(&url).send().await {
                 if response.status().is_success() {
@@ -267,4 +278,51 @@ impl DebugTools {
         
         Err(TauriMcpError::WebDriverError("No debug port found".to_string()))
     }
-}
\ No newline at end of file
+}
+
+/// Evaluate `expression` in the first page of the DevTools endpoint on
+/// `port` with `Runtime.evaluate`, returning the result by value.
+pub async fn devtools_evaluate(client: &Client, port: u16, expression: &str) -> Result<Value> {
+    let list_url = format!("http://localhost:{}/json/list", port);
+    let pages: Vec<Value> = client.get(&list_url)
+        .send()
+        .await
+        .map_err(|e| TauriMcpError::WebDriverError(format!("Failed to list pages: {}", e)))?
+        .json()
+        .await
+        .map_err(|e| TauriMcpError::WebDriverError(format!("Failed to parse pages: {}", e)))?;
+    
+    let socket_url = pages.iter()
+        .filter(|page| page["type"] == "page")
+        .find_map(|page| page["webSocketDebuggerUrl"].as_str())
+        .ok_or_else(|| TauriMcpError::WebDriverError("No page with a DevTools websocket found".to_string()))?;
+    
+    let request = serde_json::json!({
+        "id": 1,
+        "method": "Runtime.evaluate",
+        "params": { "expression": expression, "returnByValue": true },
+    });
+    let response = websocket_request(socket_url, &request.to_string()).await?;
+    
+    if let Some(error) = response.get("error") {
+        return Err(TauriMcpError::WebDriverError(format!("Runtime.evaluate failed: {}", error)));
+    }
+    if let Some(exception) = response["result"].get("exceptionDetails") {
+        return Err(TauriMcpError::WebDriverError(format!("Expression threw: {}", exception["text"])));
+    }
+    Ok(response["result"]["result"]["value"].clone())
+}
+
+/// Send one DevTools command over a new websocket and return the reply
+/// with the same `id`.
+async fn websocket_request(url: &str, message: &str) -> Result<Value> {
+    let mut socket = WebSocket::connect(url, MAX_DEVTOOLS_MESSAGE).await?;
+    socket.send_text(message).await?;
+    loop {
+        let reply: Value = serde_json::from_slice(&socket.read_message().await?)?;
+        // Events may arrive before the reply
+        if reply["id"] == 1 {
+            return Ok(reply);
+        }
+    }
+}
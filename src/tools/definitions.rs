@@ -0,0 +1,514 @@
+use serde_json::{json, Value};
+
+/// Tool definitions exposed over MCP.
+///
+/// This is the single source of truth for the tool list: `tools/list`, the
+/// `tauri-mcp manifest` subcommand and the Node.js wrapper all read from here.
+pub fn tool_definitions() -> Vec<Value> {
+    vec![
+        json!({
+            "name": "launch_app",
+            "description": "Launch a Tauri application binary, or build a Tauri project directory with cargo and launch the result",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "app_path": { "type": "string", "description": "Path to the Tauri application binary, or to a project directory containing src-tauri/tauri.conf.json" },
+                    "args": { "type": "array", "items": { "type": "string" }, "description": "Optional launch arguments" },
+                    "env": { "type": "object", "additionalProperties": { "type": ["string", "null"] }, "description": "Environment variables to set, e.g. {\"RUST_LOG\": \"debug\"}; null unsets a variable" },
+                    "clear_env": { "type": "boolean", "description": "Start from an empty environment instead of inheriting the server's" },
+                    "cwd": { "type": "string", "description": "Working directory for the app" },
+                    "stdin": { "type": "boolean", "description": "Keep a pipe to the app's stdin for write_stdin (default: stdin is closed)" },
+                    "headless": { "type": "boolean", "description": "Run the app on a private Xvfb display (Linux); screenshots and input for this process use that display" },
+                    "resolution": { "type": "string", "description": "Headless display resolution as WIDTHxHEIGHT (default 1280x800)" },
+                    "depth": { "type": "number", "description": "Headless display color depth (default 24)" },
+                    "display_server": { "type": "string", "description": "Xvfb-compatible server binary for headless mode (default Xvfb)" },
+                    "alias": { "type": "string", "description": "Process ID to use instead of a generated UUID; must be unique and is kept across restarts" },
+                    "profile": { "type": "string", "description": "Name of a [profiles.NAME] table in the server config whose settings fill in arguments not given here" },
+                    "restart": { "type": "string", "enum": ["never", "on-failure", "always"], "description": "Relaunch with the same arguments and environment when the app exits: on-failure after a crash (failing exit code, signal or panic), always after any exit not requested by stop_app (default never)" },
+                    "max_restarts": { "type": "number", "description": "Consecutive restarts before giving up; a run lasting 30s resets the count (default 5)" },
+                    "restart_backoff_ms": { "type": "number", "description": "Delay before the first restart, doubled for each consecutive one up to 30000 (default 1000)" },
+                    "log_buffer_lines": { "type": "number", "description": "Lines of output retained for get_app_logs; the oldest are evicted (default 10000)" },
+                    "rust_backtrace": { "type": "boolean", "description": "Run with RUST_BACKTRACE=full (unless env sets it) so panics carry full backtraces with addresses for list_crashes" },
+                    "persist_logs": { "type": "boolean", "description": "Also write stdout/stderr to <log_dir>/<process_id>.log with size-based rotation (default from the persist_logs config setting)" },
+                    "isolated": { "type": "boolean", "description": "Run with a temporary HOME, XDG_CONFIG_HOME, XDG_DATA_HOME and XDG_CACHE_HOME so the app data dir, localStorage and IndexedDB start empty; env still overrides them" },
+                    "fixture_dir": { "type": "string", "description": "With isolated: directory copied into the temporary HOME first, laid out like a home directory (.config/..., .local/share/<identifier>/...)" },
+                    "keep_isolated": { "type": "boolean", "description": "With isolated: keep the temporary directories for inspection (default: deleted by stop_app, or when the server exits if the app has already exited)" },
+                    "wait_for": {
+                        "type": "object",
+                        "description": "Wait until the app is ready before returning; all given conditions must hold. On timeout or exit the result has status not_ready with the last log lines and process status",
+                        "properties": {
+                            "window": { "type": "boolean", "description": "A top-level window of the process is mapped" },
+                            "log": { "type": "string", "description": "Regex matched against each stdout/stderr line" },
+                            "endpoint": { "type": "string", "description": "\"devtools\", \"webdriver\", or a URL that must answer HTTP" },
+                            "port": { "type": "number", "description": "TCP port on localhost that must accept connections" },
+                            "timeout_ms": { "type": "number", "description": "How long to wait (default 30000)" }
+                        }
+                    },
+                    "release": { "type": "boolean", "description": "Project builds only: build with --release" },
+                    "features": { "type": "array", "items": { "type": "string" }, "description": "Project builds only: cargo features to enable" },
+                    "dev_server": { "type": "boolean", "description": "Project builds only: load build.devUrl from a dev server you run instead of running beforeBuildCommand and embedding the frontend", "default": false },
+                    "bin": { "type": "string", "description": "Project builds only: binary target to launch when the package has several" }
+                },
+                "required": ["app_path"]
+            }
+        }),
+        json!({
+            "name": "build_app",
+            "description": "Build a Tauri project with cargo and return compiler errors and warnings as structured diagnostics, without launching it. Like tauri build, the frontend is built with beforeBuildCommand (from tauri.conf.json) and embedded unless dev_server is set",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "project_path": { "type": "string", "description": "Project directory containing src-tauri/tauri.conf.json, or the src-tauri directory" },
+                    "release": { "type": "boolean", "description": "Build with --release" },
+                    "features": { "type": "array", "items": { "type": "string" }, "description": "Cargo features to enable" },
+                    "dev_server": { "type": "boolean", "description": "Load build.devUrl from a dev server you run instead of running beforeBuildCommand and embedding the frontend", "default": false },
+                    "bin": { "type": "string", "description": "Binary target to build when the package has several" }
+                },
+                "required": ["project_path"]
+            }
+        }),
+        json!({
+            "name": "stop_app",
+            "description": "Stop a running Tauri application and its child processes: SIGTERM, then SIGKILL after a grace period. Returns exit code or signal, runtime and the final log lines",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app to stop" },
+                    "grace_period_ms": { "type": "number", "description": "Time to wait after SIGTERM before SIGKILL (default 5000)" },
+                    "force": { "type": "boolean", "description": "Kill immediately without SIGTERM" }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "get_app_logs",
+            "description": "Read an app's retained stdout/stderr without consuming it. Entries have a sequence number, timestamp and stream; pass next_cursor back as since_cursor to read only new lines",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "since_cursor": { "type": "number", "description": "Only entries at or after this sequence number, e.g. the next_cursor of a previous call" },
+                    "tail": { "type": "number", "description": "Only the last N entries" },
+                    "head": { "type": "number", "description": "Only the first N entries; has_more and next_cursor page through the rest" },
+                    "stream": { "type": "string", "enum": ["stdout", "stderr"], "description": "Only entries from this stream" },
+                    "lines": { "type": "number", "description": "Same as tail" }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "search_app_logs",
+            "description": "Search an app's retained logs instead of reading them all: regex include/exclude, time window, minimum level parsed from env_logger, tracing and tauri-plugin-log output (ANSI colors stripped), grep -C style context. Returns capped groups of matches with context plus match counts",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "pattern": { "type": "string", "description": "Regex a line must match" },
+                    "exclude": { "type": "string", "description": "Regex of lines to leave out" },
+                    "level": { "type": "string", "enum": ["trace", "debug", "info", "warn", "error"], "description": "Minimum log level; lines without a recognised level are left out" },
+                    "since": { "type": "string", "description": "RFC 3339 timestamp of the earliest line" },
+                    "until": { "type": "string", "description": "RFC 3339 timestamp of the latest line" },
+                    "within_ms": { "type": "number", "description": "Only lines from the last N milliseconds" },
+                    "stream": { "type": "string", "enum": ["stdout", "stderr"], "description": "Only lines from this stream" },
+                    "context": { "type": "number", "description": "Lines of context before and after each match (max 20)" },
+                    "max_matches": { "type": "number", "description": "Matches returned; the rest are only counted (default 100)" }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "wait_for_log",
+            "description": "Wait until an app logs a line matching a regex (e.g. \"listening on\", \"migrations applied\"), or until the timeout or the app exits. Returns the matched line with surrounding context, or matched: false with the reason and last lines",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "pattern": { "type": "string", "description": "Regex matched against each line, ANSI colors stripped" },
+                    "timeout_ms": { "type": "number", "description": "How long to wait (default 30000)" },
+                    "new_only": { "type": "boolean", "description": "Only count lines printed after the call started (default: retained lines count too)" },
+                    "context": { "type": "number", "description": "Lines returned before and after the match (default 2, max 20)" },
+                    "stream": { "type": "string", "enum": ["stdout", "stderr"], "description": "Only watch this stream" }
+                },
+                "required": ["process_id", "pattern"]
+            }
+        }),
+        json!({
+            "name": "list_processes",
+            "description": "Managed apps with liveness, exit and crash state, display and the paths of their persisted log files (also listed as MCP resources)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {}
+            }
+        }),
+        json!({
+            "name": "get_process_status",
+            "description": "Lifecycle of launched apps: running or exited, exit code or signal, exit time, runtime, whether it crashed, and any Rust panics with their backtraces",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app; omit for all managed processes" }
+                }
+            }
+        }),
+        json!({
+            "name": "write_stdin",
+            "description": "Write text to the stdin of an app launched with stdin: true",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "data": { "type": "string", "description": "Text to write; include a trailing newline for line-based input" },
+                    "close": { "type": "boolean", "description": "Close stdin after writing, sending EOF" }
+                },
+                "required": ["process_id", "data"]
+            }
+        }),
+        json!({
+            "name": "take_screenshot",
+            "description": "Take a screenshot of the app window (requires display)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "output_path": { "type": "string", "description": "Optional path to save the screenshot" }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "get_window_info",
+            "description": "Get window dimensions, position, and state (requires display)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "send_keyboard_input",
+            "description": "Send keyboard input to the app (requires display)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "keys": { "type": "string", "description": "Keys to send" }
+                },
+                "required": ["process_id", "keys"]
+            }
+        }),
+        json!({
+            "name": "send_mouse_click",
+            "description": "Send mouse click to specific coordinates (requires display)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "x": { "type": "number", "description": "X coordinate" },
+                    "y": { "type": "number", "description": "Y coordinate" },
+                    "button": { "type": "string", "enum": ["left", "right", "middle"], "description": "Mouse button" }
+                },
+                "required": ["process_id", "x", "y"]
+            }
+        }),
+        json!({
+            "name": "execute_js",
+            "description": "Execute JavaScript in the app's webview",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "javascript_code": { "type": "string", "description": "JavaScript code to execute" }
+                },
+                "required": ["process_id", "javascript_code"]
+            }
+        }),
+        json!({
+            "name": "get_devtools_info",
+            "description": "Get DevTools connection information",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "monitor_resources",
+            "description": "Monitor CPU, memory, and other resource usage",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "tree": { "type": "boolean", "description": "Also report each descendant process (WebKitWebProcess, WebKitNetworkProcess, ...) labelled by role, with CPU, RSS, PSS, threads and open FDs, plus totals", "default": false }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "list_crashes",
+            "description": "Crash history of launched apps, kept per process alias across restarts and after stop_app: Rust panics parsed into thread, message, location and backtrace frames, and runs killed by a signal or failing exit code. Unsymbolized frames of the app's executable are resolved against its debug info with addr2line",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Only crashes of this process ID or alias" },
+                    "since_seq": { "type": "number", "description": "Only crashes from this sequence number on; pass the previous next_seq to get new ones" },
+                    "symbolize": { "type": "boolean", "description": "Resolve <unknown> frames with addr2line or llvm-addr2line", "default": true }
+                }
+            }
+        }),
+        json!({
+            "name": "dump_threads",
+            "description": "Thread dump of a managed app for diagnosing hangs (Linux): state, kernel wait channel and CPU time of every thread from /proc, plus symbolized backtraces when eu-stack or gdb is installed. Summarizes each thread and assesses the main (GTK) thread: idle in the main loop, busy, or blocked",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "backtrace": {
+                        "type": "string",
+                        "enum": ["auto", "eu-stack", "gdb", "none"],
+                        "description": "Debugger for backtraces; both briefly stop the app and need ptrace permission. none reads /proc only",
+                        "default": "auto"
+                    }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "list_app_data",
+            "description": "Resolve a Tauri app's config, data, local data, cache and log directories the way its path resolver does (XDG directories + identifier on Linux) and list the files in them with size and mtime",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "identifier": { "type": "string", "description": "Bundle identifier, e.g. com.example.app; read from tauri.conf.json when omitted" },
+                    "app_path": { "type": "string", "description": "Tauri project (or an executable built from one) whose tauri.conf.json gives the identifier" },
+                    "process_id": { "type": "string", "description": "Managed app: its project gives the identifier, and an isolated launch's throwaway home is used" },
+                    "dir": { "type": "string", "enum": ["config", "data", "local_data", "cache", "log"], "description": "Only list this directory" },
+                    "max_depth": { "type": "number", "description": "Directory levels to descend", "default": 8 },
+                    "max_entries": { "type": "number", "description": "Stop listing after this many entries", "default": 1000 }
+                }
+            }
+        }),
+        json!({
+            "name": "read_app_data_file",
+            "description": "Read a file from a Tauri app's directories: JSON is returned parsed, SQLite databases as header info, tables, row counts and sample rows (via the sqlite3 shell), other files as text or the start of their bytes in base64",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "identifier": { "type": "string", "description": "Bundle identifier, e.g. com.example.app; read from tauri.conf.json when omitted" },
+                    "app_path": { "type": "string", "description": "Tauri project (or an executable built from one) whose tauri.conf.json gives the identifier" },
+                    "process_id": { "type": "string", "description": "Managed app: its project gives the identifier, and an isolated launch's throwaway home is used" },
+                    "path": { "type": "string", "description": "Relative to dir, or absolute inside the app's directories" },
+                    "dir": { "type": "string", "enum": ["config", "data", "local_data", "cache", "log"], "default": "data" },
+                    "max_bytes": { "type": "number", "description": "Read at most this many bytes (up to 1 MiB)", "default": 65536 }
+                },
+                "required": ["path"]
+            }
+        }),
+        json!({
+            "name": "snapshot_app_data",
+            "description": "Copy a Tauri app's directories to a temporary snapshot directory, to restore later with reset_app_data",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "identifier": { "type": "string", "description": "Bundle identifier, e.g. com.example.app; read from tauri.conf.json when omitted" },
+                    "app_path": { "type": "string", "description": "Tauri project (or an executable built from one) whose tauri.conf.json gives the identifier" },
+                    "process_id": { "type": "string", "description": "Managed app: its project gives the identifier, and an isolated launch's throwaway home is used" },
+                    "dirs": { "type": "array", "items": { "type": "string", "enum": ["config", "data", "local_data", "cache", "log"] }, "description": "Directories to copy; all by default" }
+                }
+            }
+        }),
+        json!({
+            "name": "reset_app_data",
+            "description": "Delete a Tauri app's directories so its next launch starts from a clean state, optionally restoring them from a snapshot_app_data snapshot. Stop the app first: with a process_id that is still running it refuses unless force is set, since the app holds its databases and webview storage open",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "identifier": { "type": "string", "description": "Bundle identifier, e.g. com.example.app; read from tauri.conf.json when omitted" },
+                    "app_path": { "type": "string", "description": "Tauri project (or an executable built from one) whose tauri.conf.json gives the identifier" },
+                    "process_id": { "type": "string", "description": "Managed app: its project gives the identifier, and an isolated launch's throwaway home is used" },
+                    "dirs": { "type": "array", "items": { "type": "string", "enum": ["config", "data", "local_data", "cache", "log"] }, "description": "Directories to delete; all by default" },
+                    "restore_snapshot": { "type": "string", "description": "Snapshot directory returned by snapshot_app_data to copy back afterwards" },
+                    "force": { "type": "boolean", "description": "Reset even though the process_id app is still running", "default": false }
+                }
+            }
+        }),
+        json!({
+            "name": "fs_snapshot",
+            "description": "Record the tree, sizes, modification times and CRC-32 checksums of chosen directories (e.g. the app's data directories or a project), to compare with fs_diff after an action. Small text files are kept for line diffs",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "paths": { "type": "array", "items": { "type": "string" }, "description": "Directories or files to record; they need not exist yet" },
+                    "include_app_data": { "type": "boolean", "description": "Also record the app's config, data, cache and log directories, found from identifier, app_path or process_id as in list_app_data", "default": false },
+                    "identifier": { "type": "string", "description": "Bundle identifier for include_app_data" },
+                    "app_path": { "type": "string", "description": "Tauri project whose tauri.conf.json gives the identifier for include_app_data" },
+                    "process_id": { "type": "string", "description": "Managed app whose project and isolated home are used for include_app_data" },
+                    "exclude": { "type": "array", "items": { "type": "string" }, "description": "File and directory names to skip anywhere in the tree", "default": [".git", "node_modules", "target"] },
+                    "max_files": { "type": "number", "description": "Stop recording after this many paths", "default": 20000 }
+                }
+            }
+        }),
+        json!({
+            "name": "fs_diff",
+            "description": "Created, modified and deleted files between an fs_snapshot and the current state of the same paths (kept as a new snapshot) or another snapshot, with unified diffs for small text files. Files whose contents are unchanged but were rewritten are listed as touched",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "snapshot_id": { "type": "string", "description": "Snapshot to compare from" },
+                    "to_snapshot_id": { "type": "string", "description": "Snapshot to compare to; the current state by default" }
+                },
+                "required": ["snapshot_id"]
+            }
+        }),
+        json!({
+            "name": "sample_resources",
+            "description": "Sample CPU and memory of an app over time (CPU readings are accurate from the first sample). Returns min/max/mean/p95, a sparkline or CSV series, and a linear-regression memory growth rate that flags likely leaks. Runs for duration_ms, or in the background between action start and stop",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "action": {
+                        "type": "string",
+                        "enum": ["run", "start", "read", "stop"],
+                        "description": "run: sample for duration_ms and report; start: sample in the background; read: report on the background sampler so far; stop: stop it and report",
+                        "default": "run"
+                    },
+                    "interval_ms": { "type": "number", "description": "Time between samples (minimum 100)", "default": 1000 },
+                    "duration_ms": { "type": "number", "description": "How long to sample for action run (maximum 60000, as the server handles nothing else meanwhile; sample longer with action start)", "default": 10000 },
+                    "tree": { "type": "boolean", "description": "Sum the whole process tree, WebKit helpers included, instead of the main process only", "default": true },
+                    "format": { "type": "string", "enum": ["sparkline", "csv"], "description": "Series format", "default": "sparkline" }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "measure_startup",
+            "description": "Launch an app several times in a row and time its startup: spawn to first mapped window, first log line and DOMContentLoaded (when a new DevTools endpoint appears), plus peak RSS of the process tree early on. Reports each run and min/max/mean/p95 per metric. A Tauri project directory is built once first",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "app_path": { "type": "string", "description": "Path to the Tauri application binary, or to a project directory containing src-tauri/tauri.conf.json" },
+                    "args": { "type": "array", "items": { "type": "string" }, "description": "Optional launch arguments" },
+                    "env": { "type": "object", "additionalProperties": { "type": ["string", "null"] }, "description": "Environment variables to set; null unsets a variable" },
+                    "cwd": { "type": "string", "description": "Working directory for the app" },
+                    "headless": { "type": "boolean", "description": "Run each launch on a private Xvfb display (Linux)" },
+                    "profile": { "type": "string", "description": "Name of a [profiles.NAME] table in the server config whose settings fill in arguments not given here" },
+                    "runs": { "type": "number", "description": "Number of launches (maximum 10)", "default": 5 },
+                    "cold": { "type": "boolean", "description": "Delete the app's cache directory (<cache dir>/<identifier>) and cache_dirs before each launch. The OS page cache is not dropped", "default": false },
+                    "identifier": { "type": "string", "description": "Bundle identifier for cold runs (default: read from the project's tauri.conf.json)" },
+                    "cache_dirs": { "type": "array", "items": { "type": "string" }, "description": "Further directories to delete before cold runs; each must be inside the user's cache directory" },
+                    "timeout_ms": { "type": "number", "description": "Give up on a run's window and DOMContentLoaded after this long. runs × timeout_ms may be at most 60000, as the server handles nothing else meanwhile", "default": 10000 },
+                    "peak_rss_ms": { "type": "number", "description": "How long after spawn peak RSS is tracked; each run lasts at least this long", "default": 5000 },
+                    "release": { "type": "boolean", "description": "Build a project directory in release mode" }
+                },
+                "required": ["app_path"]
+            }
+        }),
+        json!({
+            "name": "set_resource_alerts",
+            "description": "Register resource thresholds for an app, checked by a background sampler. Each crossing (and recovery) is recorded for get_alerts, marked in the app's logs with an [alert] line and sent as a notification. Call without thresholds to stop checking",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "rss_mb": { "type": "number", "description": "Alert when RSS exceeds this many MB" },
+                    "cpu_percent": { "type": "number", "description": "Alert when CPU usage stays above this percentage for cpu_sustained_ms" },
+                    "cpu_sustained_ms": { "type": "number", "description": "How long CPU must stay above cpu_percent", "default": 10000 },
+                    "max_fds": { "type": "number", "description": "Alert when open file descriptors exceed this count (Linux)" },
+                    "max_threads": { "type": "number", "description": "Alert when threads exceed this count (Linux)" },
+                    "interval_ms": { "type": "number", "description": "Time between checks (minimum 100)", "default": 1000 },
+                    "tree": { "type": "boolean", "description": "Measure the whole process tree, WebKit helpers included", "default": true }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "get_alerts",
+            "description": "Resource alert history with the alerts still active and the thresholds being checked",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Only alerts of this app" },
+                    "since_seq": { "type": "number", "description": "Only alerts from this sequence number on; pass the previous next_seq to get new ones" }
+                }
+            }
+        }),
+        json!({
+            "name": "list_ipc_handlers",
+            "description": "List all registered Tauri IPC commands",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" }
+                },
+                "required": ["process_id"]
+            }
+        }),
+        json!({
+            "name": "call_ipc_command",
+            "description": "Call a Tauri IPC command",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "process_id": { "type": "string", "description": "Process ID of the app" },
+                    "command_name": { "type": "string", "description": "Name of the IPC command" },
+                    "args": { "type": "object", "description": "Arguments to pass to the command" }
+                },
+                "required": ["process_id", "command_name"]
+            }
+        }),
+        json!({
+            "name": "find_running_apps",
+            "description": "Find running Tauri applications on the system. On Linux, detects apps by WebKitGTK in their memory maps, WebKit helper child processes and Tauri strings in the executable; returns confidence, evidence, exe path, window count and whether the app is already managed",
+            "inputSchema": {
+                "type": "object",
+                "properties": {}
+            }
+        }),
+        json!({
+            "name": "attach_to_app",
+            "description": "Attach to an already running Tauri application by PID, optionally following its logs (Linux) so get_app_logs, search_app_logs and wait_for_log work as for launched apps",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "pid": { "type": "number", "description": "Process ID of the running app" },
+                    "log_source": {
+                        "type": "object",
+                        "description": "Where to read the app's output from (Linux only)",
+                        "properties": {
+                            "type": {
+                                "type": "string",
+                                "enum": ["file", "journald", "proc_fds"],
+                                "description": "file: tail a log file, following rotation; journald: follow journal entries via journalctl; proc_fds: read /proc/<pid>/fd/1 and 2 when they are files or pipes. Reading a pipe takes its output away from the app's real reader (a terminal, a parent process), which then misses those lines"
+                            },
+                            "path": { "type": "string", "description": "Log file to follow (file)" },
+                            "unit": { "type": "string", "description": "systemd unit to follow (journald)" },
+                            "identifier": { "type": "string", "description": "Syslog identifier to follow (journald); without unit or identifier, entries of the PID itself" }
+                        },
+                        "required": ["type"]
+                    }
+                },
+                "required": ["pid"]
+            }
+        }),
+        json!({
+            "name": "server_status",
+            "description": "Report server version, protocol, uptime, config, managed processes and environment readiness checks",
+            "inputSchema": {
+                "type": "object",
+                "properties": {}
+            }
+        }),
+    ]
+}
+
+/// Names of all tools, in definition order.
+pub fn tool_names() -> Vec<String> {
+    tool_definitions()
+        .iter()
+        .filter_map(|tool| tool["name"].as_str().map(String::from))
+        .collect()
+}
@@ -0,0 +1,122 @@
+use serde::Serialize;
+
+/// Processes below this confidence are not reported as Tauri apps. Above a
+/// WebKitGTK mapping alone, which any GTK app embedding a web view has.
+pub const MIN_CONFIDENCE: f32 = 0.5;
+
+/// WebKitGTK builds a Tauri app can link: 4.1 for Tauri 2, 4.0 for Tauri 1.
+#[cfg(target_os = "linux")]
+const WEBKIT_LIBRARIES: [(&str, &str); 2] = [("libwebkit2gtk-4.1", "4.1"), ("libwebkit2gtk-4.0", "4.0")];
+/// Strings the Tauri runtime embeds in every app binary.
+#[cfg(target_os = "linux")]
+const TAURI_MARKERS: [&str; 4] = ["__TAURI_INTERNALS__", "__TAURI_IPC__", "tauri://localhost", "ipc://localhost"];
+/// Executables are scanned up to this size for `TAURI_MARKERS`.
+#[cfg(target_os = "linux")]
+const MAX_EXE_SCAN_BYTES: u64 = 512 * 1024 * 1024;
+
+#[cfg(target_os = "linux")]
+const WEBKIT_MAPPED_SCORE: f32 = 0.4;
+#[cfg(target_os = "linux")]
+const WEBKIT_CHILDREN_SCORE: f32 = 0.2;
+#[cfg(target_os = "linux")]
+const TAURI_MARKERS_SCORE: f32 = 0.4;
+
+/// Why a process looks like a Tauri app, and how sure we are.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Detection {
+    /// 0.0 to 1.0
+    pub confidence: f32,
+    pub evidence: Vec<String>,
+    /// WebKitGTK API version mapped into the process
+    pub webkit: Option<String>,
+}
+
+impl Detection {
+    fn add(&mut self, score: f32, evidence: String) {
+        self.confidence = (self.confidence + score).min(1.0);
+        self.evidence.push(evidence);
+    }
+}
+
+/// Whether a process is one of WebKit's helpers (`WebKitWebProcess`,
+/// `WebKitNetworkProcess`) rather than an app; names may be cut to 15 bytes.
+pub fn is_webkit_helper(name: &str) -> bool {
+    name.starts_with("WebKitWebProces") || name.starts_with("WebKitNetworkPr")
+}
+
+/// Inspect `pid` for signs of a Tauri/WRY app: WebKitGTK in its memory
+/// maps, WebKit helper children and Tauri strings in its executable.
+#[cfg(target_os = "linux")]
+pub fn detect(pid: u32, webkit_children: usize, _cmd: &[String]) -> Detection {
+    let mut detection = Detection::default();
+    
+    if let Ok(maps) = std::fs::read_to_string(format!("/proc/{}/maps", pid)) {
+        if let Some((library, version)) = WEBKIT_LIBRARIES.iter().find(|(library, _)| maps.contains(library)) {
+            detection.webkit = Some(version.to_string());
+            detection.add(WEBKIT_MAPPED_SCORE, format!("{} is mapped", library));
+        }
+    }
+    if webkit_children > 0 {
+        detection.add(WEBKIT_CHILDREN_SCORE, format!("{} WebKit helper process(es)", webkit_children));
+    }
+    // Reading the binary is the expensive check; only do it for WebKit users
+    if detection.confidence > 0.0 {
+        let markers = exe_markers(pid);
+        if !markers.is_empty() {
+            detection.add(TAURI_MARKERS_SCORE, format!("executable contains {}", markers.join(", ")));
+        }
+    }
+    
+    detection
+}
+
+/// Without `/proc` the command line is all there is to go on.
+#[cfg(not(target_os = "linux"))]
+pub fn detect(_pid: u32, _webkit_children: usize, cmd: &[String]) -> Detection {
+    let mut detection = Detection::default();
+    if cmd.iter().any(|arg| arg.to_lowercase().contains("tauri")) {
+        detection.add(MIN_CONFIDENCE, "command line mentions tauri".to_string());
+    }
+    detection
+}
+
+#[cfg(target_os = "linux")]
+fn exe_markers(pid: u32) -> Vec<&'static str> {
+    use std::io::Read;
+    
+    const CHUNK: usize = 4 * 1024 * 1024;
+    let overlap = TAURI_MARKERS.iter().map(|marker| marker.len()).max().unwrap_or(0);
+    
+    let Ok(file) = std::fs::File::open(format!("/proc/{}/exe", pid)) else {
+        return Vec::new();
+    };
+    let mut file = file.take(MAX_EXE_SCAN_BYTES);
+    let pattern = TAURI_MARKERS.iter().map(|marker| regex::escape(marker)).collect::<Vec<_>>().join("|");
+    let pattern = regex::bytes::Regex::new(&pattern).expect("markers are literals");
+    let mut found = Vec::new();
+    let mut window = Vec::with_capacity(CHUNK + overlap);
+    let mut chunk = vec![0; CHUNK];
+    
+    loop {
+        let read = match file.read(&mut chunk) {
+            Ok(0) | Err(_) => break,
+            Ok(read) => read,
+        };
+        window.extend_from_slice(&chunk[..read]);
+        for matched in pattern.find_iter(&window) {
+            if let Some(marker) = TAURI_MARKERS.iter().find(|marker| marker.as_bytes() == matched.as_bytes()) {
+                if !found.contains(marker) {
+                    found.push(*marker);
+                }
+            }
+        }
+        if found.len() == TAURI_MARKERS.len() {
+            break;
+        }
+        // Keep the tail so markers spanning two chunks are still seen
+        let keep = window.len().min(overlap);
+        window.drain(..window.len() - keep);
+    }
+    
+    found
+}
@@ -0,0 +1,152 @@
+use crate::tools::debug::{DEVTOOLS_PORTS, WEBDRIVER_URL};
+use enigo::{Enigo, Settings};
+use reqwest::Client;
+use serde::Serialize;
+use std::time::Duration;
+use tracing::debug;
+
+/// Outcome of a single environment readiness check.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    pub name: String,
+    pub ok: bool,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<String>,
+}
+
+impl CheckResult {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            ok: true,
+            detail: detail.into(),
+            remediation: None,
+        }
+    }
+    
+    fn fail(name: &str, detail: impl Into<String>, remediation: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ok: false,
+            detail: detail.into(),
+            remediation: Some(remediation.to_string()),
+        }
+    }
+}
+
+/// Run all environment checks: display, input simulation, WebDriver and DevTools.
+pub async fn run_environment_checks() -> Vec<CheckResult> {
+    let client = Client::builder()
+        .timeout(Duration::from_secs(2))
+        .build()
+        .expect("Failed to create HTTP client");
+    
+    let display = check_display();
+    let input = check_input().await;
+    let (webdriver, devtools) = tokio::join!(check_webdriver(&client), check_devtools(&client));
+    
+    vec![display, input, webdriver, devtools]
+}
+
+#[cfg(target_os = "linux")]
+pub fn check_display() -> CheckResult {
+    use crate::utils::x11::XConnection;
+    
+    match XConnection::open(None) {
+        Ok(connection) => CheckResult::pass("display", format!("Opened X11 display {}", connection.name())),
+        Err(_) if std::env::var("DISPLAY").unwrap_or_default().is_empty() => CheckResult::fail(
+            "display",
+            "DISPLAY is not set",
+            "Set DISPLAY to a running X server (e.g. `export DISPLAY=:0`), or start a virtual one with `Xvfb :99 &` and `export DISPLAY=:99`",
+        ),
+        Err(e) => CheckResult::fail(
+            "display",
+            e.to_string(),
+            "Check that the X server is running and that this user may connect to it (`xhost`, XAUTHORITY)",
+        ),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn check_display() -> CheckResult {
+    CheckResult::pass("display", "Native windowing system, no X11 display required")
+}
+
+async fn check_input() -> CheckResult {
+    let result = tokio::task::spawn_blocking(|| {
+        Enigo::new(&Settings::default()).map(|_| ()).map_err(|e| format!("{:?}", e))
+    })
+    .await
+    .unwrap_or_else(|e| Err(e.to_string()));
+    
+    match result {
+        Ok(()) => CheckResult::pass("input", "Input simulation initialised"),
+        Err(e) => CheckResult::fail(
+            "input",
+            format!("Failed to initialise input simulation: {}", e),
+            if cfg!(target_os = "macos") {
+                "Grant Accessibility permission to the terminal or client running tauri-mcp"
+            } else {
+                "Input simulation needs a working display and libxdo (install `libxdo3`)"
+            },
+        ),
+    }
+}
+
+async fn check_webdriver(client: &Client) -> CheckResult {
+    let url = format!("{}/status", WEBDRIVER_URL);
+    match client.get(&url).send().await {
+        Ok(response) if response.status().is_success() => {
+            CheckResult::pass("webdriver", format!("WebDriver answered at {}", WEBDRIVER_URL))
+        }
+        Ok(response) => CheckResult::fail(
+            "webdriver",
+            format!("WebDriver at {} returned {}", WEBDRIVER_URL, response.status()),
+            "Restart the WebDriver server; `tauri-driver` should answer GET /status",
+        ),
+        Err(e) => CheckResult::fail(
+            "webdriver",
+            format!("No WebDriver at {}: {}", WEBDRIVER_URL, e),
+            "Install and start tauri-driver (`cargo install tauri-driver && tauri-driver`) to enable WebDriver tools",
+        ),
+    }
+}
+
+/// DevTools ports in the scanned range that answer `/json/version`.
+pub async fn responding_devtools_ports(client: &Client) -> Vec<u16> {
+    let mut probes = tokio::task::JoinSet::new();
+    for port in DEVTOOLS_PORTS {
+        let client = client.clone();
+        probes.spawn(async move {
+            let url = format!("http://localhost:{}/json/version", port);
+            match client.get(&url).send().await {
+                Ok(response) if response.status().is_success() => Some(port),
+                _ => None,
+            }
+        });
+    }
+    
+    let mut ports = Vec::new();
+    while let Some(result) = probes.join_next().await {
+        if let Ok(Some(port)) = result {
+            ports.push(port);
+        }
+    }
+    ports.sort_unstable();
+    debug!("Responding DevTools ports: {:?}", ports);
+    ports
+}
+
+async fn check_devtools(client: &Client) -> CheckResult {
+    let ports = responding_devtools_ports(client).await;
+    if ports.is_empty() {
+        CheckResult::fail(
+            "devtools",
+            format!("No DevTools endpoint on ports {}-{}", DEVTOOLS_PORTS.start, DEVTOOLS_PORTS.end - 1),
+            "Start the app with remote debugging enabled, e.g. WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS=--remote-debugging-port=9222 on Windows",
+        )
+    } else {
+        CheckResult::pass("devtools", format!("DevTools answering on ports {:?}", ports))
+    }
+}
@@ -0,0 +1,412 @@
+use crate::{Result, TauriMcpError};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, VecDeque};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tracing::info;
+use uuid::Uuid;
+
+const DEFAULT_MAX_FILES: usize = 20_000;
+const DEFAULT_EXCLUDE: &[&str] = &[".git", "node_modules", "target"];
+/// Snapshots kept for `fs_diff`; the oldest are dropped first.
+const MAX_SNAPSHOTS: usize = 32;
+/// Files up to this size are kept as text for diffs.
+const MAX_TEXT_BYTES: u64 = 32 * 1024;
+/// Text kept per snapshot; larger trees only get hashes.
+const TEXT_BUDGET: usize = 16 * 1024 * 1024;
+/// Line diffs compare every line with every other.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+const DIFF_CONTEXT: usize = 3;
+
+/// Options for `fs_snapshot`.
+#[derive(Debug, Clone)]
+pub struct FsSnapshotOptions {
+    /// Directories (or files) to record; they need not exist yet
+    pub paths: Vec<PathBuf>,
+    /// File and directory names skipped anywhere in the tree
+    pub exclude: Vec<String>,
+    pub max_files: usize,
+}
+
+impl FsSnapshotOptions {
+    pub fn from_arguments(arguments: &Value) -> Self {
+        let strings = |key: &str| arguments.get(key)
+            .and_then(|v| v.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(String::from)).collect::<Vec<_>>());
+        
+        Self {
+            paths: strings("paths").unwrap_or_default().into_iter().map(PathBuf::from).collect(),
+            exclude: strings("exclude").unwrap_or_else(|| DEFAULT_EXCLUDE.iter().map(|s| s.to_string()).collect()),
+            max_files: arguments.get("max_files").and_then(|v| v.as_u64()).map_or(DEFAULT_MAX_FILES, |n| n as usize),
+        }
+    }
+}
+
+/// What was recorded about one path.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    File { size: u64, crc32: u32, text: Option<String> },
+    Dir,
+    Symlink { target: PathBuf },
+}
+
+impl Node {
+    fn kind(&self) -> &'static str {
+        match self {
+            Node::File { .. } => "file",
+            Node::Dir => "dir",
+            Node::Symlink { .. } => "symlink",
+        }
+    }
+    
+    fn size(&self) -> u64 {
+        match self {
+            Node::File { size, .. } => *size,
+            _ => 0,
+        }
+    }
+    
+    fn text(&self) -> Option<&str> {
+        match self {
+            Node::File { text, .. } => text.as_deref(),
+            _ => None,
+        }
+    }
+    
+    /// Same kind and contents; modification times are compared separately.
+    fn same_contents(&self, other: &Node) -> bool {
+        match (self, other) {
+            (Node::File { size, crc32, .. }, Node::File { size: other_size, crc32: other_crc32, .. }) => {
+                size == other_size && crc32 == other_crc32
+            }
+            _ => self == other,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    node: Node,
+    modified: Option<SystemTime>,
+}
+
+/// The state of a set of directories at one point in time.
+#[derive(Debug, Clone)]
+pub struct FsSnapshot {
+    pub id: String,
+    pub taken_at: DateTime<Utc>,
+    options: FsSnapshotOptions,
+    entries: BTreeMap<PathBuf, Entry>,
+    /// `max_files` was reached; later files are missing
+    truncated: bool,
+}
+
+impl FsSnapshot {
+    /// Walk `options.paths`, hashing every file.
+    pub fn take(options: &FsSnapshotOptions) -> Result<Self> {
+        if options.paths.is_empty() {
+            return Err(TauriMcpError::Other("fs_snapshot needs at least one path".to_string()));
+        }
+        
+        let mut snapshot = Self {
+            id: Uuid::new_v4().to_string(),
+            taken_at: Utc::now(),
+            options: options.clone(),
+            entries: BTreeMap::new(),
+            truncated: false,
+        };
+        let mut text_budget = TEXT_BUDGET;
+        for path in &options.paths {
+            snapshot.record(path, &mut text_budget)?;
+        }
+        Ok(snapshot)
+    }
+    
+    fn record(&mut self, root: &Path, text_budget: &mut usize) -> Result<()> {
+        let mut pending = VecDeque::from([root.to_path_buf()]);
+        while let Some(path) = pending.pop_front() {
+            let metadata = match std::fs::symlink_metadata(&path) {
+                Ok(metadata) => metadata,
+                // Deleted while walking, or a root that does not exist (yet)
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            if self.entries.len() >= self.options.max_files {
+                self.truncated = true;
+                return Ok(());
+            }
+            
+            // Unreadable paths are left out rather than failing the snapshot
+            let node = if metadata.is_symlink() {
+                Node::Symlink { target: std::fs::read_link(&path)? }
+            } else if metadata.is_dir() {
+                let mut children: Vec<PathBuf> = match std::fs::read_dir(&path) {
+                    Ok(children) => children.filter_map(|entry| entry.ok())
+                        .filter(|entry| !self.options.exclude.iter().any(|name| entry.file_name() == name.as_str()))
+                        .map(|entry| entry.path())
+                        .collect(),
+                    Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => Vec::new(),
+                    Err(e) => return Err(e.into()),
+                };
+                children.sort();
+                pending.extend(children);
+                Node::Dir
+            } else if metadata.is_file() {
+                match read_file(&path, metadata.len(), text_budget) {
+                    Ok((crc32, text)) => Node::File { size: metadata.len(), crc32, text },
+                    Err(e) if matches!(e.kind(), std::io::ErrorKind::PermissionDenied | std::io::ErrorKind::NotFound) => continue,
+                    Err(e) => return Err(e.into()),
+                }
+            } else {
+                // Sockets, FIFOs and devices have no contents to compare
+                continue;
+            };
+            self.entries.insert(path, Entry { node, modified: metadata.modified().ok() });
+        }
+        Ok(())
+    }
+    
+    /// Counts for the tool result.
+    pub fn summary(&self) -> Value {
+        let count = |kind: &str| self.entries.values().filter(|entry| entry.node.kind() == kind).count();
+        json!({
+            "snapshot_id": self.id,
+            "taken_at": self.taken_at,
+            "paths": self.options.paths,
+            "files": count("file"),
+            "dirs": count("dir"),
+            "symlinks": count("symlink"),
+            "bytes": self.entries.values().map(|entry| entry.node.size()).sum::<u64>(),
+            "truncated": self.truncated,
+        })
+    }
+}
+
+/// CRC-32 of a file, and its contents when it is small UTF-8 text.
+fn read_file(path: &Path, size: u64, text_budget: &mut usize) -> std::io::Result<(u32, Option<String>)> {
+    let mut file = std::fs::File::open(path)?;
+    if size <= MAX_TEXT_BYTES && (size as usize) <= *text_budget {
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        let crc32 = crc32fast::hash(&bytes);
+        let text = String::from_utf8(bytes).ok();
+        if let Some(text) = &text {
+            *text_budget -= text.len().min(*text_budget);
+        }
+        return Ok((crc32, text));
+    }
+    
+    let mut hasher = crc32fast::Hasher::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok((hasher.finalize(), None))
+}
+
+/// Snapshots taken by `fs_snapshot`, by ID.
+#[derive(Debug, Default)]
+pub struct FsSnapshots {
+    snapshots: VecDeque<FsSnapshot>,
+}
+
+impl FsSnapshots {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn insert(&mut self, snapshot: FsSnapshot) {
+        if self.snapshots.len() >= MAX_SNAPSHOTS {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+    
+    pub fn get(&self, id: &str) -> Result<&FsSnapshot> {
+        self.snapshots.iter()
+            .find(|snapshot| snapshot.id == id)
+            .ok_or_else(|| TauriMcpError::Other(format!("Snapshot not found: {}", id)))
+    }
+}
+
+/// A path that differs between two snapshots.
+#[derive(Debug, Clone, Serialize)]
+pub struct FsChange {
+    pub path: PathBuf,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_before: Option<u64>,
+    /// Unified diff, for text files small enough to keep
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+/// Created, modified and deleted paths from `before` to `after`. Paths only
+/// `touched` kept their contents but got a new modification time.
+pub fn diff_snapshots(before: &FsSnapshot, after: &FsSnapshot) -> Value {
+    let mut created = Vec::new();
+    let mut modified = Vec::new();
+    let mut deleted = Vec::new();
+    let mut touched = Vec::new();
+    
+    for (path, entry) in &after.entries {
+        match before.entries.get(path) {
+            None => created.push(FsChange {
+                path: path.clone(),
+                kind: entry.node.kind(),
+                size: entry.node.size(),
+                size_before: None,
+                diff: entry.node.text().and_then(|text| unified_diff("", text)),
+            }),
+            Some(old) if !old.node.same_contents(&entry.node) => modified.push(FsChange {
+                path: path.clone(),
+                kind: entry.node.kind(),
+                size: entry.node.size(),
+                size_before: Some(old.node.size()),
+                diff: old.node.text().zip(entry.node.text()).and_then(|(old, new)| unified_diff(old, new)),
+            }),
+            // Directory mtimes change with every file created or deleted in them
+            Some(old) if old.modified != entry.modified && entry.node != Node::Dir => touched.push(path.clone()),
+            Some(_) => {}
+        }
+    }
+    for (path, entry) in &before.entries {
+        if !after.entries.contains_key(path) {
+            deleted.push(FsChange {
+                path: path.clone(),
+                kind: entry.node.kind(),
+                size: entry.node.size(),
+                size_before: None,
+                diff: entry.node.text().and_then(|text| unified_diff(text, "")),
+            });
+        }
+    }
+    
+    json!({
+        "from": before.id,
+        "to": after.id,
+        "summary": {
+            "created": created.len(),
+            "modified": modified.len(),
+            "deleted": deleted.len(),
+            "touched": touched.len(),
+        },
+        "created": created,
+        "modified": modified,
+        "deleted": deleted,
+        "touched": touched,
+        "truncated": before.truncated || after.truncated,
+    })
+}
+
+/// Record the tree under `options.paths` and keep it for `fs_diff`.
+pub fn fs_snapshot(snapshots: &parking_lot::Mutex<FsSnapshots>, options: &FsSnapshotOptions) -> Result<Value> {
+    let snapshot = FsSnapshot::take(options)?;
+    let summary = snapshot.summary();
+    info!("Filesystem snapshot {} of {:?}", snapshot.id, options.paths);
+    snapshots.lock().insert(snapshot);
+    Ok(summary)
+}
+
+/// Compare snapshot `from` with snapshot `to`, or with the current state of
+/// the same paths, which is kept as a new snapshot to diff against next.
+pub fn fs_diff(snapshots: &parking_lot::Mutex<FsSnapshots>, from: &str, to: Option<&str>) -> Result<Value> {
+    let before = snapshots.lock().get(from)?.clone();
+    let after = match to {
+        Some(to) => snapshots.lock().get(to)?.clone(),
+        None => {
+            let after = FsSnapshot::take(&before.options)?;
+            snapshots.lock().insert(after.clone());
+            after
+        }
+    };
+    Ok(diff_snapshots(&before, &after))
+}
+
+/// Unified diff of two texts with `DIFF_CONTEXT` lines of context, or `None`
+/// when they have the same lines or are too long to compare line by line.
+pub fn unified_diff(old: &str, new: &str) -> Option<String> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    
+    // Only the lines between the common prefix and suffix need comparing
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..].iter().rev().zip(new[prefix..].iter().rev()).take_while(|(a, b)| a == b).count();
+    let (old_middle, new_middle) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+    if (old_middle.len() + 1) * (new_middle.len() + 1) > MAX_DIFF_CELLS {
+        return None;
+    }
+    
+    // Longest common subsequence lengths of the suffixes of both middles
+    let columns = new_middle.len() + 1;
+    let mut lcs = vec![0u32; (old_middle.len() + 1) * columns];
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            lcs[i * columns + j] = if old_middle[i] == new_middle[j] {
+                lcs[(i + 1) * columns + j + 1] + 1
+            } else {
+                lcs[(i + 1) * columns + j].max(lcs[i * columns + j + 1])
+            };
+        }
+    }
+    
+    let mut ops: Vec<(char, &str)> = old[..prefix].iter().map(|line| (' ', *line)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < old_middle.len() || j < new_middle.len() {
+        if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+            ops.push((' ', old_middle[i]));
+            i += 1;
+            j += 1;
+        } else if i < old_middle.len() && (j == new_middle.len() || lcs[(i + 1) * columns + j] >= lcs[i * columns + j + 1]) {
+            // Removed lines come first, as in diff -u
+            ops.push(('-', old_middle[i]));
+            i += 1;
+        } else {
+            ops.push(('+', new_middle[j]));
+            j += 1;
+        }
+    }
+    ops.extend(old[old.len() - suffix..].iter().map(|line| (' ', *line)));
+    
+    // Hunks: runs of changes with their context, merged where contexts overlap
+    let changes: Vec<usize> = ops.iter().enumerate().filter(|(_, (op, _))| *op != ' ').map(|(k, _)| k).collect();
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &k in &changes {
+        let start = k.saturating_sub(DIFF_CONTEXT);
+        let end = (k + DIFF_CONTEXT + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+    
+    let mut output = String::new();
+    for (start, end) in hunks {
+        let old_start = ops[..start].iter().filter(|(op, _)| *op != '+').count();
+        let new_start = ops[..start].iter().filter(|(op, _)| *op != '-').count();
+        let old_len = ops[start..end].iter().filter(|(op, _)| *op != '+').count();
+        let new_len = ops[start..end].iter().filter(|(op, _)| *op != '-').count();
+        // Empty ranges are numbered by the line before them
+        let line_number = |start: usize, len: usize| if len == 0 { start } else { start + 1 };
+        output.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            line_number(old_start, old_len), old_len, line_number(new_start, new_len), new_len
+        ));
+        for (op, line) in &ops[start..end] {
+            output.push(*op);
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    // Contents that differ only in line endings have no line changes
+    (!output.is_empty()).then_some(output)
+}
@@ -11,11 +11,19 @@ impl InputSimulator {
         Self {}
     }
     
-    pub async fn send_keyboard_input(&self, process_id: &str, keys: &str) -> Result<()> {
+    /// Enigo settings targeting `display`, or the default display when `None`.
+    fn settings_for(display: Option<&str>) -> Settings {
+        Settings {
+            x11_display: display.map(String::from),
+            ..Settings::default()
+        }
+    }
+    
+    pub async fn send_keyboard_input(&self, process_id: &str, keys: &str, display: Option<&str>) -> Result<()> {
         info!("Sending keyboard input to process {}: {}", process_id, keys);
         
         let keys_to_send = keys.to_string();
-        let settings = Settings::default();
+        let settings = Self::settings_for(display);
         
         tokio::task::spawn_blocking(move || {
             let mut enigo = Enigo::new(&settings)
@@ -39,7 +47,7 @@ impl InputSimulator {
         Ok(())
     }
     
-    pub async fn send_mouse_click(&self, process_id: &str, x: i32, y: i32, button: &str) -> Result<()> {
+    pub async fn send_mouse_click(&self, process_id: &str, x: i32, y: i32, button: &str, display: Option<&str>) -> Result<()> {
         info!("Sending mouse click to process {} at ({}, {}), button: {}", process_id, x, y, button);
         
         let button_to_click = match button.to_lowercase().as_str() {
@@ -49,7 +57,7 @@ impl InputSimulator {
             _ => return Err(TauriMcpError::InputError(format!("Invalid mouse button: {}", button))),
         };
         
-        let settings = Settings::default();
+        let settings = Self::settings_for(display);
         
         tokio::task::spawn_blocking(move || {
             let mut enigo = Enigo::new(&settings)
@@ -70,10 +78,10 @@ impl InputSimulator {
         Ok(())
     }
     
-    pub async fn send_mouse_move(&self, process_id: &str, x: i32, y: i32) -> Result<()> {
+    pub async fn send_mouse_move(&self, process_id: &str, x: i32, y: i32, display: Option<&str>) -> Result<()> {
         info!("Moving mouse for process {} to ({}, {})", process_id, x, y);
         
-        let settings = Settings::default();
+        let settings = Self::settings_for(display);
         
         tokio::task::spawn_blocking(move || {
             let mut enigo = Enigo::new(&settings)
@@ -88,11 +96,11 @@ impl InputSimulator {
         Ok(())
     }
     
-    pub async fn send_mouse_drag(&self, process_id: &str, start_x: i32, start_y: i32, end_x: i32, end_y: i32) -> Result<()> {
+    pub async fn send_mouse_drag(&self, process_id: &str, start_x: i32, start_y: i32, end_x: i32, end_y: i32, display: Option<&str>) -> Result<()> {
         info!("Dragging mouse for process {} from ({}, {}) to ({}, {})", 
               process_id, start_x, start_y, end_x, end_y);
         
-        let settings = Settings::default();
+        let settings = Self::settings_for(display);
         
         tokio::task::spawn_blocking(move || {
             let mut enigo = Enigo::new(&settings)
@@ -129,10 +137,10 @@ impl InputSimulator {
         Ok(())
     }
     
-    pub async fn send_mouse_scroll(&self, process_id: &str, x: i32, y: i32, delta: i32) -> Result<()> {
+    pub async fn send_mouse_scroll(&self, process_id: &str, x: i32, y: i32, delta: i32, display: Option<&str>) -> Result<()> {
         info!("Scrolling mouse for process {} at ({}, {}), delta: {}", process_id, x, y, delta);
         
-        let settings = Settings::default();
+        let settings = Self::settings_for(display);
         
         tokio::task::spawn_blocking(move || {
             let mut enigo = Enigo::new(&settings)
@@ -0,0 +1,281 @@
+use crate::tools::logs::LogStream;
+use crate::tools::process::LogSink;
+use crate::{Result, TauriMcpError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::PathBuf;
+use tokio::task::JoinHandle;
+
+/// Where the output of an attached process can be read from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LogSource {
+    /// Follow a file like `tail -F`, reopening it when rotated or truncated
+    File { path: PathBuf },
+    /// Follow the systemd journal; without a unit or identifier, the
+    /// entries logged by the process itself
+    Journald {
+        #[serde(default)]
+        unit: Option<String>,
+        #[serde(default)]
+        identifier: Option<String>,
+    },
+    /// Read the process's stdout and stderr through `/proc/<pid>/fd`
+    ProcFds,
+}
+
+impl LogSource {
+    /// Parse the `log_source` argument of `attach_to_app`.
+    pub fn from_arguments(arguments: &Value) -> Result<Option<Self>> {
+        let Some(source) = arguments.get("log_source") else {
+            return Ok(None);
+        };
+        if !cfg!(target_os = "linux") {
+            return Err(TauriMcpError::ProcessError("Log sources for attached processes require Linux".to_string()));
+        }
+        
+        serde_json::from_value(source.clone())
+            .map(Some)
+            .map_err(|e| TauriMcpError::ProcessError(format!(
+                "Invalid log_source: {}. Expected {{type: file, path}}, {{type: journald, unit?, identifier?}} or {{type: proc_fds}}", e
+            )))
+    }
+    
+    /// Start forwarding lines from this source into `sink`.
+    #[cfg(target_os = "linux")]
+    pub fn start(&self, pid: u32, sink: LogSink) -> Result<Vec<JoinHandle<()>>> {
+        match self {
+            LogSource::File { path } => {
+                let file = linux::FollowedFile::open_at_end(path)?;
+                Ok(vec![tokio::spawn(linux::follow_file(file, LogStream::Stdout, sink))])
+            }
+            LogSource::Journald { unit, identifier } => {
+                Ok(vec![linux::follow_journal(pid, unit.as_deref(), identifier.as_deref(), sink)?])
+            }
+            LogSource::ProcFds => linux::follow_fds(pid, sink),
+        }
+    }
+    
+    #[cfg(not(target_os = "linux"))]
+    pub fn start(&self, _pid: u32, _sink: LogSink) -> Result<Vec<JoinHandle<()>>> {
+        Err(TauriMcpError::ProcessError("Log sources for attached processes require Linux".to_string()))
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::*;
+    use std::io::Seek;
+    use std::os::unix::fs::MetadataExt;
+    use std::path::Path;
+    use std::process::Stdio;
+    use std::time::Duration;
+    use tokio::fs::File;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, BufReader, SeekFrom};
+    use tokio::net::unix::pipe;
+    use tokio::process::Command;
+    use tracing::{debug, info, warn};
+    
+    const POLL_INTERVAL: Duration = Duration::from_millis(250);
+    /// journald priorities up to `warning` are reported as stderr.
+    const MAX_STDERR_PRIORITY: u64 = 4;
+    
+    /// An open file being followed, with the inode it was opened at.
+    pub struct FollowedFile {
+        path: PathBuf,
+        file: File,
+        inode: u64,
+        position: u64,
+    }
+    
+    impl FollowedFile {
+        /// Open `path` positioned at its end, so only new lines are read.
+        pub fn open_at_end(path: &Path) -> Result<Self> {
+            let mut file = std::fs::File::open(path)
+                .map_err(|e| TauriMcpError::ProcessError(format!("Cannot follow {}: {}", path.display(), e)))?;
+            let metadata = file.metadata()?;
+            if !metadata.is_file() {
+                return Err(TauriMcpError::ProcessError(format!("Not a regular file: {}", path.display())));
+            }
+            
+            let position = file.seek(SeekFrom::End(0))?;
+            Ok(Self { path: path.to_path_buf(), file: File::from_std(file), inode: metadata.ino(), position })
+        }
+    }
+    
+    /// Forward lines appended to a file, like `tail -F`: a rotated file is
+    /// drained before the new one is opened, and a truncated one is re-read
+    /// from the start.
+    pub async fn follow_file(mut followed: FollowedFile, stream: LogStream, sink: LogSink) {
+        let mut partial = Vec::new();
+        
+        loop {
+            let mut chunk = Vec::new();
+            match followed.file.read_to_end(&mut chunk).await {
+                Ok(read) => {
+                    followed.position += read as u64;
+                    push_lines(&mut partial, &chunk, stream, &sink);
+                }
+                Err(e) => debug!("Failed to read {}: {}", followed.path.display(), e),
+            }
+            
+            // Between rotation and the new file appearing, keep draining the old one
+            if let Ok(metadata) = std::fs::metadata(&followed.path) {
+                if metadata.ino() != followed.inode {
+                    info!("{} was rotated, following the new file", followed.path.display());
+                    match File::open(&followed.path).await {
+                        Ok(file) => {
+                            flush_partial(&mut partial, stream, &sink);
+                            followed.file = file;
+                            followed.inode = metadata.ino();
+                            followed.position = 0;
+                        }
+                        Err(e) => debug!("Failed to reopen {}: {}", followed.path.display(), e),
+                    }
+                } else if metadata.len() < followed.position {
+                    info!("{} was truncated, reading from the start", followed.path.display());
+                    if followed.file.seek(SeekFrom::Start(0)).await.is_ok() {
+                        partial.clear();
+                        followed.position = 0;
+                    }
+                }
+            }
+            
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+    
+    fn push_lines(partial: &mut Vec<u8>, chunk: &[u8], stream: LogStream, sink: &LogSink) {
+        partial.extend_from_slice(chunk);
+        while let Some(newline) = partial.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = partial.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line);
+            sink.push(stream, line.trim_end_matches(['\n', '\r']).to_string());
+        }
+    }
+    
+    fn flush_partial(partial: &mut Vec<u8>, stream: LogStream, sink: &LogSink) {
+        if !partial.is_empty() {
+            sink.push(stream, String::from_utf8_lossy(partial).to_string());
+            partial.clear();
+        }
+    }
+    
+    /// Forward new journal entries via `journalctl --follow`.
+    pub fn follow_journal(pid: u32, unit: Option<&str>, identifier: Option<&str>, sink: LogSink) -> Result<JoinHandle<()>> {
+        let mut cmd = Command::new("journalctl");
+        cmd.args(["--follow", "--lines=0", "--output=json"]);
+        match (unit, identifier) {
+            (None, None) => {
+                cmd.arg(format!("_PID={}", pid));
+            }
+            (unit, identifier) => {
+                if let Some(unit) = unit {
+                    cmd.arg(format!("--unit={}", unit));
+                }
+                if let Some(identifier) = identifier {
+                    cmd.arg(format!("--identifier={}", identifier));
+                }
+            }
+        }
+        
+        let mut child = cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| TauriMcpError::ProcessError(format!("Failed to run journalctl: {}", e)))?;
+        let stdout = child.stdout.take()
+            .ok_or_else(|| TauriMcpError::ProcessError("Failed to capture journalctl output".to_string()))?;
+        
+        Ok(tokio::spawn(async move {
+            // Held here so aborting the task also stops journalctl
+            let _child = child;
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let Ok(entry) = serde_json::from_str::<Value>(&line) else {
+                    continue;
+                };
+                let message = match &entry["MESSAGE"] {
+                    Value::String(message) => message.clone(),
+                    // Non-UTF-8 messages come as byte arrays
+                    Value::Array(bytes) => String::from_utf8_lossy(
+                        &bytes.iter().filter_map(|b| b.as_u64().map(|b| b as u8)).collect::<Vec<_>>()
+                    ).to_string(),
+                    _ => continue,
+                };
+                let priority = entry["PRIORITY"].as_str().and_then(|p| p.parse::<u64>().ok());
+                let stream = match priority {
+                    Some(priority) if priority <= MAX_STDERR_PRIORITY => LogStream::Stderr,
+                    _ => LogStream::Stdout,
+                };
+                sink.push(stream, message);
+            }
+            debug!("journalctl for PID {} ended", pid);
+        }))
+    }
+    
+    /// Follow `/proc/<pid>/fd/1` and `2`: files are tailed, pipes are read
+    /// directly (competing with the process's real reader, if any).
+    pub fn follow_fds(pid: u32, sink: LogSink) -> Result<Vec<JoinHandle<()>>> {
+        let mut tasks = Vec::new();
+        let mut unusable = Vec::new();
+        let mut followed_files = Vec::new();
+        
+        for (fd, stream) in [(1, LogStream::Stdout), (2, LogStream::Stderr)] {
+            let fd_path = PathBuf::from(format!("/proc/{}/fd/{}", pid, fd));
+            let target = match std::fs::read_link(&fd_path) {
+                Ok(target) => target,
+                Err(e) => {
+                    unusable.push(format!("fd {}: {}", fd, e));
+                    continue;
+                }
+            };
+            let description = target.to_string_lossy().to_string();
+            
+            if description.starts_with("pipe:") {
+                // Non-blocking, so aborting the task on detach leaves no
+                // thread blocked in read() until the writer closes
+                let pipe = std::fs::OpenOptions::new().read(true).open(&fd_path)
+                    .and_then(pipe::Receiver::from_file);
+                match pipe {
+                    Ok(pipe) => {
+                        warn!("Reading fd {} of PID {} directly; its original reader will miss these lines", fd, pid);
+                        let sink = sink.clone();
+                        tasks.push(tokio::spawn(async move {
+                            let mut lines = BufReader::new(pipe).lines();
+                            while let Ok(Some(line)) = lines.next_line().await {
+                                sink.push(stream, line);
+                            }
+                        }));
+                    }
+                    Err(e) => unusable.push(format!("fd {} ({}): {}", fd, description, e)),
+                }
+            } else if target.is_file() {
+                // `2>&1` into one file: follow it once
+                if followed_files.contains(&target) {
+                    continue;
+                }
+                match FollowedFile::open_at_end(&target) {
+                    Ok(file) => {
+                        tasks.push(tokio::spawn(follow_file(file, stream, sink.clone())));
+                        followed_files.push(target);
+                    }
+                    Err(e) => unusable.push(format!("fd {}: {}", fd, e)),
+                }
+            } else {
+                unusable.push(format!("fd {} is {}, which cannot be read", fd, description));
+            }
+        }
+        
+        if tasks.is_empty() {
+            return Err(TauriMcpError::ProcessError(format!(
+                "No readable output for PID {}: {}", pid, unusable.join("; ")
+            )));
+        }
+        for reason in unusable {
+            debug!("Skipping output of PID {}: {}", pid, reason);
+        }
+        Ok(tasks)
+    }
+}
@@ -0,0 +1,502 @@
+use crate::{Result, TauriMcpError};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{LineWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tracing::warn;
+
+/// Lines kept per process unless `log_buffer_lines` says otherwise.
+pub const DEFAULT_LOG_BUFFER_LINES: usize = 10_000;
+const DEFAULT_MAX_MATCHES: usize = 100;
+/// Context lines are capped so a large `context` cannot return the whole buffer.
+const MAX_CONTEXT_LINES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+    /// Lines written by tauri-mcp itself, such as restart markers
+    System,
+}
+
+impl fmt::Display for LogStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LogStream::Stdout => "stdout",
+            LogStream::Stderr => "stderr",
+            LogStream::System => "system",
+        })
+    }
+}
+
+/// One line of a process's output.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEntry {
+    /// Position in the process's output, counting from 0 across restarts
+    pub seq: u64,
+    pub timestamp: DateTime<Utc>,
+    pub stream: LogStream,
+    pub line: String,
+}
+
+impl LogEntry {
+    /// The line with its stream prefix, e.g. `[stderr] error: ...`.
+    pub fn formatted(&self) -> String {
+        match self.stream {
+            // System lines carry their own tag, e.g. `[restart]`
+            LogStream::System => self.line.clone(),
+            stream => format!("[{}] {}", stream, self.line),
+        }
+    }
+}
+
+/// Which part of a log buffer `get_app_logs` returns.
+#[derive(Debug, Clone, Default)]
+pub struct LogQuery {
+    /// Only entries at or after this sequence number, e.g. a previous `next_cursor`
+    pub since_cursor: Option<u64>,
+    /// Only the last N matching entries
+    pub tail: Option<usize>,
+    /// Only the first N matching entries; `next_cursor` then continues after them
+    pub head: Option<usize>,
+    pub stream: Option<LogStream>,
+}
+
+impl LogQuery {
+    /// Parse `get_app_logs` tool arguments; `lines` is the older name for `tail`.
+    pub fn from_arguments(arguments: &Value) -> Result<Self> {
+        let count = |name: &str| arguments.get(name).and_then(|v| v.as_u64()).map(|n| n as usize);
+        
+        let query = Self {
+            since_cursor: arguments.get("since_cursor").and_then(|v| v.as_u64()),
+            tail: count("tail").or_else(|| count("lines")),
+            head: count("head"),
+            stream: stream_argument(arguments)?,
+        };
+        if query.head.is_some() && query.tail.is_some() {
+            return Err(TauriMcpError::ProcessError("Use either head or tail, not both".to_string()));
+        }
+        Ok(query)
+    }
+}
+
+/// The optional `stream` filter of the log tools.
+pub(crate) fn stream_argument(arguments: &Value) -> Result<Option<LogStream>> {
+    arguments.get("stream")
+        .map(|stream| serde_json::from_value(stream.clone())
+            .map_err(|_| TauriMcpError::ProcessError(format!("Invalid stream {}: expected stdout or stderr", stream))))
+        .transpose()
+}
+
+/// Result of a `LogQuery`.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogPage {
+    pub logs: Vec<LogEntry>,
+    /// Pass as `since_cursor` to read only what comes after this page
+    pub next_cursor: u64,
+    /// Matching entries beyond `head` that are still to be read
+    pub has_more: bool,
+    /// Entries after `since_cursor` that were evicted before they could be read
+    pub missed: u64,
+}
+
+/// Fixed-size ring buffer of a process's output. Reading never consumes
+/// entries; once full, the oldest are evicted.
+#[derive(Debug)]
+pub struct LogBuffer {
+    entries: VecDeque<LogEntry>,
+    capacity: usize,
+    next_seq: u64,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            entries: VecDeque::with_capacity(capacity.min(DEFAULT_LOG_BUFFER_LINES)),
+            capacity,
+            next_seq: 0,
+        }
+    }
+    
+    pub fn push(&mut self, stream: LogStream, line: String) -> LogEntry {
+        let entry = LogEntry {
+            seq: self.next_seq,
+            timestamp: Utc::now(),
+            stream,
+            line,
+        };
+        self.next_seq += 1;
+        
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry.clone());
+        entry
+    }
+    
+    /// Sequence number the next entry will get.
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+    
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &LogEntry> {
+        self.entries.iter()
+    }
+    
+    pub fn query(&self, query: &LogQuery) -> LogPage {
+        let since = query.since_cursor.unwrap_or(0);
+        let oldest = self.entries.front().map(|e| e.seq).unwrap_or(self.next_seq);
+        let missed = oldest.saturating_sub(since);
+        
+        // Sequence numbers are contiguous, so the start is an offset
+        let start = since.saturating_sub(oldest).min(self.entries.len() as u64) as usize;
+        let matching = self.entries.range(start..)
+            .filter(|entry| query.stream.is_none_or(|stream| entry.stream == stream));
+        
+        let (logs, has_more, next_cursor) = match (query.head, query.tail) {
+            (Some(head), _) => {
+                let mut matching = matching.peekable();
+                let logs: Vec<LogEntry> = matching.by_ref().take(head).cloned().collect();
+                let has_more = matching.peek().is_some();
+                let next_cursor = match (has_more, logs.last()) {
+                    (true, Some(last)) => last.seq + 1,
+                    (true, None) => since,
+                    (false, _) => self.next_seq,
+                };
+                (logs, has_more, next_cursor)
+            }
+            (None, Some(tail)) => {
+                let mut logs: Vec<LogEntry> = matching.rev().take(tail).cloned().collect();
+                logs.reverse();
+                (logs, false, self.next_seq)
+            }
+            (None, None) => (matching.cloned().collect(), false, self.next_seq),
+        };
+        
+        LogPage { logs, next_cursor, has_more, missed }
+    }
+    
+    /// The last `count` entries, formatted.
+    pub fn last_lines(&self, count: usize) -> Vec<String> {
+        let skip = self.entries.len().saturating_sub(count);
+        self.entries.iter().skip(skip).map(LogEntry::formatted).collect()
+    }
+}
+
+/// Where and how much of a process's output is written to disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogFileOptions {
+    pub dir: PathBuf,
+    /// Size at which the file is rotated to `<name>.log.1`
+    pub max_bytes: u64,
+    /// Files kept per process, the current one included
+    pub max_files: usize,
+}
+
+/// A process's output on disk, one line per entry, rotated by size.
+#[derive(Debug)]
+pub struct LogFile {
+    path: PathBuf,
+    writer: LineWriter<File>,
+    written: u64,
+    options: LogFileOptions,
+}
+
+impl LogFile {
+    /// Open `<dir>/<name>.log` for appending; a relaunch under the same
+    /// alias continues the same file.
+    pub fn open(name: &str, options: &LogFileOptions) -> Result<Self> {
+        std::fs::create_dir_all(&options.dir)?;
+        // Aliases are user-chosen; keep them inside the log directory
+        let name: String = name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '_' })
+            .collect();
+        let path = options.dir.join(format!("{}.log", name.trim_start_matches('.')));
+        
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            path,
+            writer: LineWriter::new(file),
+            written,
+            options: options.clone(),
+        })
+    }
+    
+    pub fn append(&mut self, entry: &LogEntry) {
+        let line = format!("{} [{}] {}\n", entry.timestamp.to_rfc3339(), entry.stream, entry.line);
+        if self.written > 0 && self.written + line.len() as u64 > self.options.max_bytes {
+            if let Err(e) = self.rotate() {
+                warn!("Failed to rotate {}: {}", self.path.display(), e);
+            }
+        }
+        match self.writer.write_all(line.as_bytes()) {
+            Ok(()) => self.written += line.len() as u64,
+            Err(e) => warn!("Failed to write {}: {}", self.path.display(), e),
+        }
+    }
+    
+    /// Shift `<name>.log.N` up by one, dropping those past `max_files`.
+    fn rotate(&mut self) -> std::io::Result<()> {
+        self.writer.flush()?;
+        let rotated = |n: usize| PathBuf::from(format!("{}.{}", self.path.display(), n));
+        
+        let keep = self.options.max_files.saturating_sub(1);
+        let _ = std::fs::remove_file(rotated(keep.max(1)));
+        for n in (1..keep).rev() {
+            let _ = std::fs::rename(rotated(n), rotated(n + 1));
+        }
+        if keep > 0 {
+            std::fs::rename(&self.path, rotated(1))?;
+        }
+        
+        let file = OpenOptions::new().create(true).write(true).truncate(true).open(&self.path)?;
+        self.writer = LineWriter::new(file);
+        self.written = 0;
+        Ok(())
+    }
+    
+    /// The current file followed by its rotated predecessors, newest first.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        std::iter::once(self.path.clone())
+            .chain((1..self.options.max_files).map(|n| PathBuf::from(format!("{}.{}", self.path.display(), n))))
+            .filter(|path| path.is_file())
+            .collect()
+    }
+}
+
+/// Log files (current and rotated) under `dir`, for listing as MCP resources.
+pub fn log_files_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.to_string_lossy().contains(".log"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "TRACE" => Some(LogLevel::Trace),
+            "DEBUG" => Some(LogLevel::Debug),
+            "INFO" => Some(LogLevel::Info),
+            "WARN" | "WARNING" => Some(LogLevel::Warn),
+            "ERROR" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+    
+    /// Level of a log line in `env_logger`, `tracing-subscriber` or
+    /// `tauri-plugin-log` format; the line must already be free of ANSI codes.
+    pub fn of_line(line: &str) -> Option<Self> {
+        static PREFIXED: OnceLock<Regex> = OnceLock::new();
+        static BRACKETED: OnceLock<Regex> = OnceLock::new();
+        
+        // `[2024-05-01T10:00:00Z INFO  app] ...`, `INFO app > ...`, `2024-05-01T10:00:00.123Z  WARN app: ...`
+        let prefixed = PREFIXED.get_or_init(|| Regex::new(
+            r"^\[?(?:\d{4}-\d{2}-\d{2}[T ][^\s\]]*\s+)?(TRACE|DEBUG|INFO|WARN|ERROR)\b"
+        ).expect("valid regex"));
+        // `[2024-05-01][10:00:00][INFO][app] ...`
+        let bracketed = BRACKETED.get_or_init(|| Regex::new(
+            r"^(?:\[[^\]]*\])*?\[(TRACE|DEBUG|INFO|WARN|ERROR)\]"
+        ).expect("valid regex"));
+        
+        if let Some(captures) = prefixed.captures(line).or_else(|| bracketed.captures(line)) {
+            return Self::parse(&captures[1]);
+        }
+        // Panics have no level of their own but are always worth an error
+        if line.starts_with("thread '") && line.contains(" panicked at ") {
+            return Some(LogLevel::Error);
+        }
+        None
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LogLevel::Trace => "trace",
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        })
+    }
+}
+
+/// Remove ANSI escape sequences such as colors from a line.
+pub fn strip_ansi(line: &str) -> std::borrow::Cow<'_, str> {
+    static ANSI: OnceLock<Regex> = OnceLock::new();
+    ANSI.get_or_init(|| Regex::new(r"\x1b\[[0-9;?]*[A-Za-z]").expect("valid regex"))
+        .replace_all(line, "")
+}
+
+/// Options of `search_app_logs`.
+#[derive(Debug, Clone)]
+pub struct LogSearch {
+    pub include: Option<Regex>,
+    pub exclude: Option<Regex>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    /// Minimum parsed level; lines without a level never match
+    pub level: Option<LogLevel>,
+    pub stream: Option<LogStream>,
+    /// Lines shown before and after each match, like `grep -C`
+    pub context: usize,
+    pub max_matches: usize,
+}
+
+impl LogSearch {
+    /// Parse `search_app_logs` tool arguments.
+    pub fn from_arguments(arguments: &Value) -> Result<Self> {
+        let regex = |name: &str| arguments.get(name)
+            .and_then(|v| v.as_str())
+            .map(|pattern| Regex::new(pattern)
+                .map_err(|e| TauriMcpError::ProcessError(format!("Invalid {} regex: {}", name, e))))
+            .transpose();
+        let time = |name: &str| arguments.get(name)
+            .and_then(|v| v.as_str())
+            .map(|time| DateTime::parse_from_rfc3339(time)
+                .map(|time| time.with_timezone(&Utc))
+                .map_err(|e| TauriMcpError::ProcessError(format!("Invalid {} timestamp '{}': {}", name, time, e))))
+            .transpose();
+        
+        let mut since = time("since")?;
+        if let Some(ms) = arguments.get("within_ms").and_then(|v| v.as_u64()) {
+            let window_start = Utc::now() - chrono::Duration::milliseconds(ms as i64);
+            since = Some(since.map_or(window_start, |since| since.max(window_start)));
+        }
+        
+        let level = arguments.get("level")
+            .and_then(|v| v.as_str())
+            .map(|level| LogLevel::parse(level).ok_or_else(|| TauriMcpError::ProcessError(format!(
+                "Invalid level '{}': expected trace, debug, info, warn or error", level
+            ))))
+            .transpose()?;
+        
+        Ok(Self {
+            include: regex("pattern")?,
+            exclude: regex("exclude")?,
+            since,
+            until: time("until")?,
+            level,
+            stream: stream_argument(arguments)?,
+            context: arguments.get("context").and_then(|v| v.as_u64()).map_or(0, |n| n as usize).min(MAX_CONTEXT_LINES),
+            max_matches: arguments.get("max_matches").and_then(|v| v.as_u64()).map_or(DEFAULT_MAX_MATCHES, |n| n as usize),
+        })
+    }
+    
+    fn matches(&self, line: &str) -> bool {
+        self.include.as_ref().is_none_or(|re| re.is_match(line))
+            && !self.exclude.as_ref().is_some_and(|re| re.is_match(line))
+            && self.level.is_none_or(|min| LogLevel::of_line(line).is_some_and(|level| level >= min))
+    }
+}
+
+/// A line of a search result, with ANSI codes removed.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchLine {
+    pub seq: u64,
+    pub timestamp: DateTime<Utc>,
+    pub stream: LogStream,
+    pub level: Option<LogLevel>,
+    pub line: String,
+    /// False for context lines
+    #[serde(rename = "match")]
+    pub is_match: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchResult {
+    /// Runs of adjacent lines: matches with their context
+    pub groups: Vec<Vec<SearchLine>>,
+    pub total_matches: usize,
+    pub returned_matches: usize,
+    /// More lines matched than `max_matches`
+    pub truncated: bool,
+    pub matches_by_level: BTreeMap<String, usize>,
+    /// Lines inside the stream and time window that were searched
+    pub scanned: usize,
+}
+
+impl LogBuffer {
+    pub fn search(&self, search: &LogSearch) -> SearchResult {
+        let candidates: Vec<(&LogEntry, String)> = self.entries.iter()
+            .filter(|entry| search.stream.is_none_or(|stream| entry.stream == stream))
+            .filter(|entry| search.since.is_none_or(|since| entry.timestamp >= since))
+            .filter(|entry| search.until.is_none_or(|until| entry.timestamp <= until))
+            .map(|entry| (entry, strip_ansi(&entry.line).into_owned()))
+            .collect();
+        let matched: Vec<bool> = candidates.iter().map(|(_, line)| search.matches(line)).collect();
+        
+        let mut result = SearchResult {
+            groups: Vec::new(),
+            total_matches: 0,
+            returned_matches: 0,
+            truncated: false,
+            matches_by_level: BTreeMap::new(),
+            scanned: candidates.len(),
+        };
+        
+        let mut group: Vec<SearchLine> = Vec::new();
+        // One past the last index already in `group`
+        let mut group_end = 0;
+        for (index, _) in matched.iter().enumerate().filter(|(_, matched)| **matched) {
+            let level = LogLevel::of_line(&candidates[index].1);
+            result.total_matches += 1;
+            let level_name = level.map_or("none".to_string(), |level| level.to_string());
+            *result.matches_by_level.entry(level_name).or_default() += 1;
+            
+            if result.returned_matches == search.max_matches {
+                result.truncated = true;
+                continue;
+            }
+            result.returned_matches += 1;
+            
+            let start = index.saturating_sub(search.context);
+            let end = (index + search.context + 1).min(candidates.len());
+            if start > group_end && !group.is_empty() {
+                result.groups.push(std::mem::take(&mut group));
+            }
+            for (offset, (entry, line)) in candidates[start.max(group_end)..end].iter().enumerate() {
+                let position = start.max(group_end) + offset;
+                group.push(SearchLine {
+                    seq: entry.seq,
+                    timestamp: entry.timestamp,
+                    stream: entry.stream,
+                    level: LogLevel::of_line(line),
+                    line: line.clone(),
+                    is_match: matched[position],
+                });
+            }
+            group_end = group_end.max(end);
+        }
+        if !group.is_empty() {
+            result.groups.push(group);
+        }
+        
+        result
+    }
+}
@@ -1,5 +1,21 @@
 pub mod process;
+pub mod supervisor;
+pub mod logs;
+pub mod log_sources;
+pub mod build;
+pub mod ready;
 pub mod window;
 pub mod input;
 pub mod debug;
-pub mod ipc;
\ No newline at end of file
+pub mod ipc;
+pub mod definitions;
+pub mod diagnostics;
+pub mod detect;
+pub mod resources;
+pub mod sampling;
+pub mod alerts;
+pub mod startup;
+pub mod threads;
+pub mod crashes;
+pub mod app_data;
+pub mod fs_snapshot;
\ No newline at end of file
@@ -1,30 +1,333 @@
+use crate::tools::logs::{
+    LogBuffer, LogEntry, LogFile, LogFileOptions, LogPage, LogQuery, LogSearch, LogStream, SearchResult,
+    DEFAULT_LOG_BUFFER_LINES,
+};
+use crate::tools::alerts::{Alert, AlertHistory, AlertMonitor, AlertThresholds};
+use crate::tools::crashes::CrashHistory;
+use crate::tools::detect;
+use crate::tools::log_sources::LogSource;
+use crate::tools::resources::ResourceTree;
+use crate::tools::sampling::{BackgroundSampler, ResourceSampler, SampleOptions, SampleReport};
+use crate::tools::supervisor::{ProcessEvent, ProcessState, ProcessStatus, RestartOptions, Supervisor};
 use crate::{Result, TauriMcpError};
-use crossbeam_channel::{bounded, Receiver, Sender};
-use parking_lot::RwLock;
+use crate::utils::isolation::{IsolatedProfile, IsolationOptions};
+#[cfg(target_os = "linux")]
+use crate::utils::xvfb::{HeadlessOptions, VirtualDisplay};
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
 use serde_json::Value;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use sysinfo::{System, Pid};
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
 use tokio::process::{Child, Command};
+use tokio::sync::broadcast;
 use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+/// Log lines a slow subscriber may fall behind before it starts missing lines.
+const LOG_EVENT_CAPACITY: usize = 1024;
+const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(5);
+/// Log lines returned by `stop_app`.
+const FINAL_LOG_LINES: usize = 20;
+
 pub struct ProcessManager {
     processes: HashMap<String, ProcessInfo>,
     system: Arc<RwLock<System>>,
+    events: broadcast::Sender<ProcessEvent>,
+    alerts: Arc<Mutex<AlertHistory>>,
+    crashes: Arc<Mutex<CrashHistory>>,
 }
 
 struct ProcessInfo {
     id: String,
-    child: Option<Child>,
-    pid: u32,
-    log_receiver: Receiver<String>,
-    log_handle: JoinHandle<()>,
+    /// Owns the `Child` of launched apps; `None` for attached ones
+    supervisor: Option<Supervisor>,
+    /// PID of an attached process; launched ones change PID on restart
+    attached_pid: u32,
+    started_at: Instant,
+    logs: Arc<Mutex<LogBuffer>>,
+    log_file: Option<Arc<Mutex<LogFile>>>,
+    /// Live copy of every log line for waiters; see `subscribe_logs`
+    log_events: broadcast::Sender<LogEntry>,
+    startup_logs: Option<broadcast::Receiver<LogEntry>>,
+    /// Followers of an attached process's log source
+    log_tasks: Vec<JoinHandle<()>>,
+    sampler: Option<BackgroundSampler>,
+    alert_monitor: Option<AlertMonitor>,
+    /// Throwaway home of an `isolated` launch
+    isolated_profile: Option<IsolatedProfile>,
+    /// What was launched, or the attached process's executable when known
+    executable: Option<PathBuf>,
     is_attached: bool,
+    /// Name of the private Xvfb display, e.g. `:99`
+    #[cfg(target_os = "linux")]
+    virtual_display: Option<String>,
+    /// Stops that display once supervision ends, by `stop_app` or on its own
+    #[cfg(target_os = "linux")]
+    display_teardown: Option<JoinHandle<()>>,
+}
+
+impl ProcessInfo {
+    fn pid(&self) -> u32 {
+        self.supervisor.as_ref().map(Supervisor::pid).unwrap_or(self.attached_pid)
+    }
+    
+    fn pid_handle(&self) -> Arc<AtomicU32> {
+        self.supervisor.as_ref()
+            .map(Supervisor::pid_handle)
+            .unwrap_or_else(|| Arc::new(AtomicU32::new(self.attached_pid)))
+    }
+}
+
+impl Drop for ProcessInfo {
+    fn drop(&mut self) {
+        for task in &self.log_tasks {
+            task.abort();
+        }
+    }
+}
+
+/// Optional settings for `ProcessManager::launch_app`.
+#[derive(Debug, Clone, Default)]
+pub struct LaunchOptions {
+    /// Variables to set; `None` removes the variable from the app's environment
+    pub env: HashMap<String, Option<String>>,
+    /// Start from an empty environment instead of inheriting the server's
+    pub clear_env: bool,
+    pub cwd: Option<PathBuf>,
+    /// Keep a pipe to the app's stdin for `write_stdin`
+    pub stdin: bool,
+    /// Run the app on a private Xvfb display instead of the current `DISPLAY`
+    #[cfg(target_os = "linux")]
+    pub headless: Option<HeadlessOptions>,
+    pub restart: RestartOptions,
+    /// Process ID to use instead of a generated one, kept across restarts
+    pub alias: Option<String>,
+    /// Lines of output kept for `get_app_logs` (default `DEFAULT_LOG_BUFFER_LINES`)
+    pub log_buffer_lines: Option<usize>,
+    /// Also write the output to a rotated file; set from the server config
+    pub log_file: Option<LogFileOptions>,
+    /// Set `RUST_BACKTRACE=full` unless `env` sets it
+    pub rust_backtrace: bool,
+    /// Run with a temporary `HOME` and XDG directories
+    pub isolated: Option<IsolationOptions>,
+}
+
+impl LaunchOptions {
+    /// Parse launch options from `launch_app` tool arguments.
+    pub fn from_arguments(arguments: &Value) -> Result<Self> {
+        let mut options = LaunchOptions::default();
+        
+        if let Some(env) = arguments.get("env") {
+            let env = env.as_object()
+                .ok_or_else(|| TauriMcpError::ProcessError("env must be an object of NAME: value".to_string()))?;
+            for (name, value) in env {
+                let value = match value {
+                    Value::Null => None,
+                    Value::String(s) => Some(s.clone()),
+                    Value::Number(_) | Value::Bool(_) => Some(value.to_string()),
+                    _ => return Err(TauriMcpError::ProcessError(format!(
+                        "Invalid value for env var {}: expected a string, or null to unset", name
+                    ))),
+                };
+                options.env.insert(name.clone(), value);
+            }
+        }
+        
+        options.clear_env = arguments.get("clear_env").and_then(|v| v.as_bool()).unwrap_or(false);
+        options.cwd = arguments.get("cwd").and_then(|v| v.as_str()).map(PathBuf::from);
+        options.stdin = arguments.get("stdin").and_then(|v| v.as_bool()).unwrap_or(false);
+        options.restart = RestartOptions::from_arguments(arguments)?;
+        options.log_buffer_lines = arguments.get("log_buffer_lines").and_then(|v| v.as_u64()).map(|n| n as usize);
+        options.rust_backtrace = arguments.get("rust_backtrace").and_then(|v| v.as_bool()).unwrap_or(false);
+        options.isolated = IsolationOptions::from_arguments(arguments)?;
+        
+        if let Some(alias) = arguments.get("alias").and_then(|v| v.as_str()) {
+            if alias.trim().is_empty() {
+                return Err(TauriMcpError::ProcessError("alias must not be empty".to_string()));
+            }
+            options.alias = Some(alias.to_string());
+        }
+        
+        if arguments.get("headless").and_then(|v| v.as_bool()).unwrap_or(false) {
+            #[cfg(target_os = "linux")]
+            {
+                let mut headless = HeadlessOptions::default();
+                
+                if let Some(resolution) = arguments.get("resolution").and_then(|v| v.as_str()) {
+                    let (width, height) = resolution.split_once('x')
+                        .and_then(|(w, h)| Some((w.trim().parse().ok()?, h.trim().parse().ok()?)))
+                        .ok_or_else(|| TauriMcpError::ProcessError(format!("Invalid resolution '{}', expected WIDTHxHEIGHT", resolution)))?;
+                    headless.width = width;
+                    headless.height = height;
+                }
+                
+                if let Some(depth) = arguments.get("depth").and_then(|v| v.as_u64()) {
+                    headless.depth = depth as u8;
+                }
+                
+                if let Some(server) = arguments.get("display_server").and_then(|v| v.as_str()) {
+                    headless.server_binary = server.to_string();
+                }
+                
+                options.headless = Some(headless);
+            }
+            
+            #[cfg(not(target_os = "linux"))]
+            return Err(TauriMcpError::ProcessError("Headless mode requires Linux with Xvfb".to_string()));
+        }
+        
+        Ok(options)
+    }
+}
+
+/// Everything needed to spawn an app, again on each restart.
+#[derive(Debug, Clone)]
+pub struct LaunchSpec {
+    pub app_path: String,
+    pub args: Vec<String>,
+    pub options: LaunchOptions,
+    /// Private X display to run on, e.g. `:99`
+    pub display: Option<String>,
+}
+
+impl LaunchSpec {
+    pub fn spawn(&self) -> Result<Child> {
+        let mut cmd = Command::new(&self.app_path);
+        // Own process group, so stopping reaches helpers like WebKitWebProcess
+        #[cfg(unix)]
+        cmd.process_group(0);
+        cmd.args(&self.args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(if self.options.stdin { Stdio::piped() } else { Stdio::null() });
+        
+        if let Some(cwd) = &self.options.cwd {
+            cmd.current_dir(cwd);
+        }
+        
+        // Clearing must come before anything else touches the environment
+        if self.options.clear_env {
+            cmd.env_clear();
+        }
+        
+        if let Some(display) = &self.display {
+            // Keep GTK off any Wayland compositor the server itself may see
+            cmd.env("DISPLAY", display)
+                .env("GDK_BACKEND", "x11")
+                .env_remove("WAYLAND_DISPLAY");
+        }
+        
+        // Full backtraces carry addresses, so unsymbolized frames can be resolved later
+        if self.options.rust_backtrace {
+            cmd.env("RUST_BACKTRACE", "full");
+        }
+        
+        for (name, value) in &self.options.env {
+            match value {
+                Some(value) => cmd.env(name, value),
+                None => cmd.env_remove(name),
+            };
+        }
+        
+        cmd.spawn().map_err(|e| TauriMcpError::ProcessError(format!("Failed to launch app: {}", e)))
+    }
+}
+
+/// Where an app's output goes; shared by all runs of a launched app.
+#[derive(Clone)]
+pub struct LogSink {
+    buffer: Arc<Mutex<LogBuffer>>,
+    file: Option<Arc<Mutex<LogFile>>>,
+    events: broadcast::Sender<LogEntry>,
+}
+
+impl LogSink {
+    pub fn push(&self, stream: LogStream, line: String) {
+        // Broadcast and write under the lock so both see entries in sequence order
+        let mut buffer = self.buffer.lock();
+        let entry = buffer.push(stream, line);
+        if let Some(file) = &self.file {
+            file.lock().append(&entry);
+        }
+        let _ = self.events.send(entry);
+    }
+    
+    pub fn subscribe(&self) -> broadcast::Receiver<LogEntry> {
+        self.events.subscribe()
+    }
+    
+    /// Forward the child's stdout and stderr until both are closed.
+    pub fn read(&self, child: &mut Child) -> Result<JoinHandle<()>> {
+        let stdout = child.stdout.take()
+            .ok_or_else(|| TauriMcpError::ProcessError("Failed to capture stdout".to_string()))?;
+        let stderr = child.stderr.take()
+            .ok_or_else(|| TauriMcpError::ProcessError("Failed to capture stderr".to_string()))?;
+        
+        let stdout_handle = tokio::spawn(forward_lines(stdout, LogStream::Stdout, self.clone()));
+        let stderr_handle = tokio::spawn(forward_lines(stderr, LogStream::Stderr, self.clone()));
+        Ok(tokio::spawn(async move {
+            let _ = tokio::join!(stdout_handle, stderr_handle);
+        }))
+    }
+}
+
+async fn forward_lines(output: impl AsyncRead + Unpin, stream: LogStream, sink: LogSink) {
+    let mut lines = BufReader::new(output).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        sink.push(stream, line);
+    }
+}
+
+/// How `stop_app` ends a process.
+#[derive(Debug, Clone)]
+pub struct StopOptions {
+    /// Time between SIGTERM and SIGKILL
+    pub grace_period: Duration,
+    /// Skip SIGTERM and kill immediately
+    pub force: bool,
+}
+
+impl Default for StopOptions {
+    fn default() -> Self {
+        Self {
+            grace_period: DEFAULT_GRACE_PERIOD,
+            force: false,
+        }
+    }
+}
+
+impl StopOptions {
+    /// Parse stop options from `stop_app` tool arguments.
+    pub fn from_arguments(arguments: &Value) -> Self {
+        let mut options = StopOptions::default();
+        if let Some(ms) = arguments.get("grace_period_ms").and_then(|v| v.as_u64()) {
+            options.grace_period = Duration::from_millis(ms);
+        }
+        options.force = arguments.get("force").and_then(|v| v.as_bool()).unwrap_or(false);
+        options
+    }
+}
+
+/// How a stopped process ended.
+#[derive(Debug, Clone, Serialize)]
+pub struct StopReport {
+    pub exit_code: Option<i32>,
+    pub signal: Option<i32>,
+    pub signal_name: Option<String>,
+    /// SIGTERM was not enough and the process tree was killed
+    pub escalated: bool,
+    pub runtime_ms: u128,
+    pub final_logs: Vec<String>,
+    /// The isolated profile the app ran with; deleted unless `keep` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isolated_profile: Option<IsolatedProfile>,
 }
 
 impl ProcessManager {
@@ -32,47 +335,117 @@ impl ProcessManager {
         Self {
             processes: HashMap::new(),
             system: Arc::new(RwLock::new(System::new_all())),
+            events: broadcast::channel(LOG_EVENT_CAPACITY).0,
+            alerts: Arc::new(Mutex::new(AlertHistory::default())),
+            crashes: Arc::new(Mutex::new(CrashHistory::default())),
         }
     }
     
-    pub async fn launch_app(&mut self, app_path: &str, args: Vec<String>) -> Result<String> {
+    pub async fn launch_app(&mut self, app_path: &str, args: Vec<String>, options: LaunchOptions) -> Result<String> {
         let path = Path::new(app_path);
         if !path.exists() {
             return Err(TauriMcpError::ProcessError(format!("App path does not exist: {}", app_path)));
         }
         
-        info!("Launching Tauri app: {} with args: {:?}", app_path, args);
+        if let Some(cwd) = &options.cwd {
+            if !cwd.is_dir() {
+                return Err(TauriMcpError::ProcessError(format!("Working directory does not exist: {}", cwd.display())));
+            }
+        }
         
-        let mut cmd = Command::new(app_path);
-        cmd.args(args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .stdin(Stdio::null());
+        let process_id = match &options.alias {
+            Some(alias) if self.processes.contains_key(alias) => {
+                return Err(TauriMcpError::ProcessError(format!("Process alias already in use: {}", alias)));
+            }
+            Some(alias) => alias.clone(),
+            None => Uuid::new_v4().to_string(),
+        };
         
-        let mut child = cmd.spawn()
-            .map_err(|e| TauriMcpError::ProcessError(format!("Failed to launch app: {}", e)))?;
+        let log_file = options.log_file.as_ref()
+            .map(|log_file| LogFile::open(&process_id, log_file).map(|file| Arc::new(Mutex::new(file))))
+            .transpose()?;
         
-        let pid = child.id()
-            .ok_or_else(|| TauriMcpError::ProcessError("Failed to get process ID".to_string()))?;
+        info!("Launching Tauri app: {} with args: {:?}", app_path, args);
         
-        let process_id = Uuid::new_v4().to_string();
+        let mut options = options;
+        let isolated_profile = options.isolated.as_ref()
+            .map(|isolated| IsolatedProfile::create(&process_id, isolated))
+            .transpose()?;
+        if let Some(profile) = &isolated_profile {
+            // Variables given explicitly still win
+            for (name, dir) in profile.env() {
+                options.env.entry(name.to_string()).or_insert_with(|| Some(dir.to_string_lossy().to_string()));
+            }
+        }
         
-        let (log_sender, log_receiver) = bounded(1000);
+        #[cfg(target_os = "linux")]
+        let virtual_display = match &options.headless {
+            Some(headless) => Some(VirtualDisplay::start(headless).await?),
+            None => None,
+        };
         
-        let stdout = child.stdout.take()
-            .ok_or_else(|| TauriMcpError::ProcessError("Failed to capture stdout".to_string()))?;
-        let stderr = child.stderr.take()
-            .ok_or_else(|| TauriMcpError::ProcessError("Failed to capture stderr".to_string()))?;
+        let spec = LaunchSpec {
+            app_path: app_path.to_string(),
+            args,
+            options,
+            #[cfg(target_os = "linux")]
+            display: virtual_display.as_ref().map(VirtualDisplay::name),
+            #[cfg(not(target_os = "linux"))]
+            display: None,
+        };
+        
+        let logs = Arc::new(Mutex::new(LogBuffer::new(
+            spec.options.log_buffer_lines.unwrap_or(DEFAULT_LOG_BUFFER_LINES)
+        )));
+        let (log_events, startup_logs) = broadcast::channel(LOG_EVENT_CAPACITY);
+        let sink = LogSink { buffer: Arc::clone(&logs), file: log_file.clone(), events: log_events.clone() };
+        
+        let supervisor = match Supervisor::spawn(spec, process_id.clone(), sink, self.events.clone(), Arc::clone(&self.crashes)) {
+            Ok(supervisor) => supervisor,
+            Err(e) => {
+                #[cfg(target_os = "linux")]
+                if let Some(display) = virtual_display {
+                    display.stop().await;
+                }
+                if let Some(profile) = isolated_profile {
+                    profile.cleanup();
+                }
+                return Err(e);
+            }
+        };
+        let pid = supervisor.pid();
         
-        let log_handle = tokio::spawn(Self::log_reader(stdout, stderr, log_sender));
+        // Restarts reuse the display; it goes when the last run has exited
+        #[cfg(target_os = "linux")]
+        let display_name = virtual_display.as_ref().map(VirtualDisplay::name);
+        #[cfg(target_os = "linux")]
+        let display_teardown = virtual_display.map(|display| {
+            let exited = supervisor.exited();
+            tokio::spawn(async move {
+                exited.await;
+                display.stop().await;
+            })
+        });
         
         let process_info = ProcessInfo {
             id: process_id.clone(),
-            child: Some(child),
-            pid,
-            log_receiver,
-            log_handle,
+            supervisor: Some(supervisor),
+            attached_pid: 0,
+            started_at: Instant::now(),
+            logs,
+            log_file,
+            log_events,
+            startup_logs: Some(startup_logs),
+            log_tasks: Vec::new(),
+            sampler: None,
+            alert_monitor: None,
+            isolated_profile,
+            executable: Some(PathBuf::from(app_path)),
             is_attached: false,
+            #[cfg(target_os = "linux")]
+            virtual_display: display_name,
+            #[cfg(target_os = "linux")]
+            display_teardown,
         };
         
         self.processes.insert(process_id.clone(), process_info);
@@ -82,53 +455,261 @@ impl ProcessManager {
         Ok(process_id)
     }
     
-    pub async fn stop_app(&mut self, process_id: &str) -> Result<()> {
-        let mut process_info = self.processes.remove(process_id)
+    /// Stop a launched app and its process tree: SIGTERM, then SIGKILL once
+    /// the grace period runs out (or straight away with `force`).
+    pub async fn stop_app(&mut self, process_id: &str, options: StopOptions) -> Result<StopReport> {
+        match self.processes.get(process_id) {
+            None => return Err(TauriMcpError::ProcessError(format!("Process not found: {}", process_id))),
+            Some(info) if info.is_attached => {
+                // For attached processes, we can't kill them directly
+                warn!("Cannot stop attached process {}, it was not launched by us", process_id);
+                return Err(TauriMcpError::ProcessError("Cannot stop externally launched process".to_string()));
+            }
+            Some(_) => {}
+        }
+        
+        let mut process_info = self.processes.remove(process_id).expect("checked above");
+        let mut supervisor = process_info.supervisor.take()
+            .ok_or_else(|| TauriMcpError::ProcessError("Process has no supervisor".to_string()))?;
+        
+        info!("Stopping app with process ID: {} (force: {}, grace period: {:?})", process_id, options.force, options.grace_period);
+        
+        // Also cancels a pending restart, so the PID below is the last one
+        supervisor.request_stop();
+        let pid = supervisor.pid();
+        
+        let escalated = if supervisor.status().state == ProcessState::Running {
+            // Collected up front: once the app exits its children are reparented
+            let tree = self.descendants_outside_group(pid);
+            Self::terminate(&mut supervisor, pid, &tree, &options).await
+        } else {
+            // Exited on its own or waiting to restart, so `pid` is reaped and
+            // its children reparented; walking from it could reach a reused
+            // PID's children. Leftovers are still in the app's process group.
+            #[cfg(unix)]
+            signal_tree(Some(pid), &[], nix::sys::signal::Signal::SIGKILL);
+            false
+        };
+        // The supervisor has flushed the log reader by now
+        let status = supervisor.wait().await;
+        
+        let final_logs = process_info.logs.lock().last_lines(FINAL_LOG_LINES);
+        
+        #[cfg(target_os = "linux")]
+        if let Some(teardown) = process_info.display_teardown.take() {
+            let _ = teardown.await;
+        }
+        let isolated_profile = process_info.isolated_profile.take();
+        if let Some(profile) = &isolated_profile {
+            profile.cleanup();
+        }
+        
+        let runtime_ms = status.runtime_ms.unwrap_or_else(|| process_info.started_at.elapsed().as_millis());
+        info!("App {} stopped: {} after {} ms", process_id, status.describe_exit().unwrap_or_default(), runtime_ms);
+        
+        Ok(StopReport {
+            exit_code: status.exit_code,
+            signal: status.signal,
+            signal_name: status.signal_name,
+            escalated,
+            runtime_ms,
+            final_logs,
+            isolated_profile,
+        })
+    }
+    
+    /// Descendants of `pid` that a signal to its process group would miss.
+    fn descendants_outside_group(&self, pid: u32) -> Vec<Descendant> {
+        let mut system = self.system.write();
+        system.refresh_processes();
+        
+        let mut tree = Vec::new();
+        let mut parents = vec![Pid::from_u32(pid)];
+        while let Some(parent) = parents.pop() {
+            for (child_pid, process) in system.processes() {
+                // sysinfo lists threads as processes on Linux
+                if process.parent() == Some(parent) && process.thread_kind().is_none() {
+                    tree.push(Descendant {
+                        pid: child_pid.as_u32(),
+                        parent: parent.as_u32(),
+                        start_time: process.start_time(),
+                    });
+                    parents.push(*child_pid);
+                }
+            }
+        }
+        
+        #[cfg(unix)]
+        tree.retain(|descendant| {
+            use nix::unistd::{getpgid, Pid as UnixPid};
+            getpgid(Some(UnixPid::from_raw(descendant.pid as i32))).map_or(true, |group| group.as_raw() as u32 != pid)
+        });
+        tree
+    }
+    
+    /// Signal the app until its supervisor has reaped it; returns whether
+    /// SIGTERM had to be escalated to SIGKILL.
+    #[cfg(unix)]
+    async fn terminate(supervisor: &mut Supervisor, pid: u32, tree: &[Descendant], options: &StopOptions) -> bool {
+        use nix::sys::signal::Signal;
+        
+        if !options.force {
+            let pids: Vec<u32> = tree.iter().map(|descendant| descendant.pid).collect();
+            signal_tree(Some(pid), &pids, Signal::SIGTERM);
+            if tokio::time::timeout(options.grace_period, supervisor.wait()).await.is_ok() {
+                // Anything the app left behind goes too; after the grace
+                // period its PIDs may belong to other processes
+                signal_tree(Some(pid), &Descendant::still_running(tree), Signal::SIGKILL);
+                return false;
+            }
+            warn!("Process {} did not exit within {:?}, sending SIGKILL", pid, options.grace_period);
+        }
+        
+        signal_tree(Some(pid), &Descendant::still_running(tree), Signal::SIGKILL);
+        supervisor.wait().await;
+        !options.force
+    }
+    
+    #[cfg(not(unix))]
+    async fn terminate(supervisor: &mut Supervisor, _pid: u32, _tree: &[Descendant], _options: &StopOptions) -> bool {
+        supervisor.kill();
+        supervisor.wait().await;
+        false
+    }
+    
+    /// Write `data` to the app's stdin; `close` sends EOF afterwards.
+    pub async fn write_stdin(&mut self, process_id: &str, data: &str, close: bool) -> Result<usize> {
+        let process_info = self.processes.get(process_id)
             .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
+        let no_pipe = || TauriMcpError::ProcessError(
+            "Process has no stdin pipe; launch it with stdin: true (or it was already closed)".to_string()
+        );
         
-        info!("Stopping app with process ID: {}", process_id);
+        let pipe = process_info.supervisor.as_ref().ok_or_else(no_pipe)?.stdin();
+        let mut pipe = pipe.lock().await;
+        let stdin = pipe.as_mut().ok_or_else(no_pipe)?;
         
-        if let Some(mut child) = process_info.child {
-            child.kill().await
-                .map_err(|e| TauriMcpError::ProcessError(format!("Failed to kill process: {}", e)))?;
-        } else if process_info.is_attached {
-            // For attached processes, we can't kill them directly
-            warn!("Cannot stop attached process {}, it was not launched by us", process_id);
-            return Err(TauriMcpError::ProcessError("Cannot stop externally launched process".to_string()));
+        stdin.write_all(data.as_bytes()).await
+            .map_err(|e| TauriMcpError::ProcessError(format!("Failed to write to stdin: {}", e)))?;
+        stdin.flush().await
+            .map_err(|e| TauriMcpError::ProcessError(format!("Failed to flush stdin: {}", e)))?;
+        
+        if close {
+            debug!("Closing stdin for process {}", process_id);
+            *pipe = None;
         }
         
-        process_info.log_handle.abort();
+        Ok(data.len())
+    }
+    
+    /// Follow a process's log lines as they arrive. The first subscriber
+    /// after launch also receives the lines printed before it subscribed.
+    pub fn subscribe_logs(&mut self, process_id: &str) -> Result<broadcast::Receiver<LogEntry>> {
+        let process_info = self.processes.get_mut(process_id)
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
         
-        Ok(())
+        Ok(process_info.startup_logs.take().unwrap_or_else(|| process_info.log_events.subscribe()))
     }
     
-    pub async fn get_app_logs(&self, process_id: &str, lines: Option<usize>) -> Result<Vec<String>> {
+    /// The retained log entries of a process, and a subscription that
+    /// continues exactly where they end.
+    pub fn follow_logs(&self, process_id: &str) -> Result<(Vec<LogEntry>, broadcast::Receiver<LogEntry>)> {
         let process_info = self.processes.get(process_id)
             .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
         
-        let mut logs = Vec::new();
+        // New entries are broadcast under the buffer lock, so none fall in between
+        let buffer = process_info.logs.lock();
+        Ok((buffer.entries().cloned().collect(), process_info.log_events.subscribe()))
+    }
+    
+    pub fn pid_of(&self, process_id: &str) -> Result<u32> {
+        self.processes.get(process_id)
+            .map(ProcessInfo::pid)
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))
+    }
+    
+    /// Lifecycle of a launched process; `None` for attached ones.
+    pub fn status_of(&self, process_id: &str) -> Result<Option<ProcessStatus>> {
+        self.processes.get(process_id)
+            .map(|info| info.supervisor.as_ref().map(Supervisor::status))
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))
+    }
+    
+    /// Lifecycle events (started, panicked, exited) of all launched processes.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ProcessEvent> {
+        self.events.subscribe()
+    }
+    
+    /// Status reports for `get_process_status`: one process, or all of them.
+    pub fn process_status(&self, process_id: Option<&str>) -> Result<Vec<Value>> {
+        let infos: Vec<&ProcessInfo> = match process_id {
+            Some(id) => vec![self.processes.get(id)
+                .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", id)))?],
+            None => self.processes.values().collect(),
+        };
         
-        while let Ok(log) = process_info.log_receiver.try_recv() {
-            logs.push(log);
+        let mut system = self.system.write();
+        system.refresh_processes();
+        
+        Ok(infos.into_iter()
+            .map(|info| {
+                let mut report = match &info.supervisor {
+                    Some(supervisor) => serde_json::json!(supervisor.status()),
+                    // Attached processes are only known through the process table
+                    None => serde_json::json!({
+                        "state": if system.process(Pid::from_u32(info.pid())).is_some() { "running" } else { "exited" },
+                    }),
+                };
+                report["process_id"] = serde_json::json!(info.id);
+                report["pid"] = serde_json::json!(info.pid());
+                report["attached"] = serde_json::json!(info.is_attached);
+                report
+            })
+            .collect())
+    }
+    
+    /// The X display a managed process runs on, when it has a private one.
+    pub fn display_for(&self, process_id: &str) -> Option<String> {
+        #[cfg(target_os = "linux")]
+        {
+            self.processes.get(process_id)
+                .filter(|info| info.display_teardown.as_ref().is_some_and(|teardown| !teardown.is_finished()))
+                .and_then(|info| info.virtual_display.clone())
         }
         
-        if let Some(line_count) = lines {
-            let start = logs.len().saturating_sub(line_count);
-            logs = logs[start..].to_vec();
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = process_id;
+            None
         }
+    }
+    
+    /// Read a process's retained output without consuming it.
+    pub async fn get_app_logs(&self, process_id: &str, query: &LogQuery) -> Result<LogPage> {
+        let process_info = self.processes.get(process_id)
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
         
-        Ok(logs)
+        Ok(process_info.logs.lock().query(query))
     }
     
-    pub async fn monitor_resources(&self, process_id: &str) -> Result<Value> {
+    pub fn search_app_logs(&self, process_id: &str, search: &LogSearch) -> Result<SearchResult> {
+        let process_info = self.processes.get(process_id)
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
+        
+        Ok(process_info.logs.lock().search(search))
+    }
+    
+    /// Resource usage of the app's process; with `tree`, also of each of
+    /// its descendants (WebKit helpers included) and their totals.
+    pub async fn monitor_resources(&self, process_id: &str, tree: bool) -> Result<Value> {
         let process_info = self.processes.get(process_id)
             .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
         
         let mut system = self.system.write();
         system.refresh_processes();
         
-        if let Some(process) = system.process(Pid::from_u32(process_info.pid)) {
-            Ok(serde_json::json!({
+        if let Some(process) = system.process(Pid::from_u32(process_info.pid())) {
+            let mut resources = serde_json::json!({
                 "cpu_usage": process.cpu_usage(),
                 "memory_usage": process.memory(),
                 "virtual_memory": process.virtual_memory(),
@@ -139,112 +720,372 @@ impl ProcessManager {
                 "status": format!("{:?}", process.status()),
                 "start_time": process.start_time(),
                 "run_time": process.run_time(),
-            }))
+            });
+            if tree {
+                resources["tree"] = serde_json::to_value(ResourceTree::collect(&system, process_info.pid()))?;
+            }
+            Ok(resources)
         } else {
             Err(TauriMcpError::ProcessError("Failed to get process info".to_string()))
         }
     }
     
-    async fn log_reader(
-        stdout: tokio::process::ChildStdout,
-        stderr: tokio::process::ChildStderr,
-        sender: Sender<String>,
-    ) {
-        let stdout_reader = BufReader::new(stdout);
-        let stderr_reader = BufReader::new(stderr);
+    /// A sampler for the app that keeps following it across restarts.
+    pub fn resource_sampler(&self, process_id: &str, tree: bool) -> Result<ResourceSampler> {
+        let process_info = self.processes.get(process_id)
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
         
-        let stdout_sender = sender.clone();
-        let stderr_sender = sender;
+        Ok(ResourceSampler::new(process_info.pid_handle(), tree))
+    }
+    
+    /// Sample the app in the background until `stop_sampling`, replacing
+    /// any sampler already running for it.
+    pub fn start_sampling(&mut self, process_id: &str, options: SampleOptions) -> Result<()> {
+        let sampler = self.resource_sampler(process_id, options.tree)?;
+        let process_info = self.processes.get_mut(process_id).expect("checked above");
         
-        let stdout_handle = tokio::spawn(async move {
-            let mut lines = stdout_reader.lines();
-            while let Ok(Some(line)) = lines.next_line().await {
-                let log_line = format!("[stdout] {}", line);
-                if stdout_sender.send(log_line).is_err() {
-                    break;
-                }
+        info!("Sampling resources of {} every {:?}", process_id, options.interval);
+        process_info.sampler = Some(BackgroundSampler::start(sampler, options));
+        Ok(())
+    }
+    
+    /// Statistics of the background sampler so far, without stopping it.
+    pub fn sampling_report(&self, process_id: &str) -> Result<SampleReport> {
+        let process_info = self.processes.get(process_id)
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
+        
+        process_info.sampler.as_ref()
+            .map(BackgroundSampler::report)
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("No background sampling for process: {}", process_id)))
+    }
+    
+    pub fn stop_sampling(&mut self, process_id: &str) -> Result<SampleReport> {
+        let report = self.sampling_report(process_id)?;
+        if let Some(process_info) = self.processes.get_mut(process_id) {
+            process_info.sampler = None;
+        }
+        Ok(SampleReport { running: false, ..report })
+    }
+    
+    /// Check the app against `thresholds` in the background, replacing any
+    /// earlier ones; `None` stops checking. Crossings are kept for
+    /// `get_alerts`, marked in the app's logs and broadcast as `alert` events.
+    pub fn set_resource_alerts(&mut self, process_id: &str, thresholds: Option<AlertThresholds>) -> Result<()> {
+        let Some(thresholds) = thresholds else {
+            let process_info = self.processes.get_mut(process_id)
+                .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))?;
+            process_info.alert_monitor = None;
+            return Ok(());
+        };
+        
+        let sampler = self.resource_sampler(process_id, thresholds.tree)?;
+        let process_info = self.processes.get_mut(process_id).expect("checked above");
+        let sink = LogSink {
+            buffer: Arc::clone(&process_info.logs),
+            file: process_info.log_file.clone(),
+            events: process_info.log_events.clone(),
+        };
+        let events = self.events.clone();
+        let notify = move |alert: &Alert| {
+            sink.push(LogStream::System, format!("[alert] {}", alert.message));
+            let _ = events.send(ProcessEvent::new(&alert.process_id, alert.pid, "alert", serde_json::json!(alert)));
+        };
+        
+        info!("Checking resource thresholds of {}: {:?}", process_id, thresholds);
+        process_info.alert_monitor = Some(AlertMonitor::start(
+            process_id.to_string(),
+            sampler,
+            thresholds,
+            Arc::clone(&self.alerts),
+            notify,
+        ));
+        Ok(())
+    }
+    
+    /// Alert history from `since_seq` on, the alerts still active, and the
+    /// thresholds being checked.
+    pub fn get_alerts(&self, process_id: Option<&str>, since_seq: u64) -> Result<Value> {
+        if let Some(process_id) = process_id {
+            if !self.processes.contains_key(process_id) {
+                return Err(TauriMcpError::ProcessError(format!("Process not found: {}", process_id)));
             }
-        });
+        }
         
-        let stderr_handle = tokio::spawn(async move {
-            let mut lines = stderr_reader.lines();
-            while let Ok(Some(line)) = lines.next_line().await {
-                let log_line = format!("[stderr] {}", line);
-                if stderr_sender.send(log_line).is_err() {
-                    break;
+        let monitored: Vec<Value> = self.processes.values()
+            .filter(|info| process_id.is_none_or(|id| info.id == id))
+            .filter_map(|info| info.alert_monitor.as_ref().map(|monitor| serde_json::json!({
+                "process_id": info.id,
+                "thresholds": monitor.thresholds(),
+            })))
+            .collect();
+        let history = self.alerts.lock();
+        
+        Ok(serde_json::json!({
+            "alerts": history.query(process_id, since_seq),
+            "active": history.active(process_id),
+            "next_seq": history.next_seq(),
+            "monitored": monitored,
+        }))
+    }
+    
+    /// Delete the isolated profiles of apps that exited without `stop_app`,
+    /// as the server goes away. Running apps keep theirs.
+    pub fn cleanup_exited_profiles(&mut self) {
+        for info in self.processes.values_mut() {
+            let exited = info.supervisor.as_ref().is_some_and(|supervisor| supervisor.status().state == ProcessState::Exited);
+            if exited {
+                if let Some(profile) = info.isolated_profile.take() {
+                    profile.cleanup();
                 }
             }
-        });
-        
-        let _ = tokio::join!(stdout_handle, stderr_handle);
+        }
+    }
+    
+    /// The throwaway profile of an app launched with `isolated`.
+    pub fn isolated_profile(&self, process_id: &str) -> Option<&IsolatedProfile> {
+        self.processes.get(process_id)?.isolated_profile.as_ref()
+    }
+    
+    /// The app's executable: the launched path, or an attached process's image.
+    pub fn executable_of(&self, process_id: &str) -> Result<Option<&Path>> {
+        self.processes.get(process_id)
+            .map(|info| info.executable.as_deref())
+            .ok_or_else(|| TauriMcpError::ProcessError(format!("Process not found: {}", process_id)))
+    }
+    
+    /// Crash reports of all launched apps, kept after they stop.
+    pub fn crash_history(&self) -> Arc<Mutex<CrashHistory>> {
+        Arc::clone(&self.crashes)
     }
     
     pub fn get_running_processes(&self) -> Vec<String> {
         self.processes.keys().cloned().collect()
     }
     
+    /// Managed processes with a liveness check against the OS process table.
+    pub fn process_summaries(&self) -> Vec<Value> {
+        let mut system = self.system.write();
+        system.refresh_processes();
+        
+        self.processes.values()
+            .map(|info| {
+                let process = system.process(Pid::from_u32(info.pid()));
+                let alive = match &info.supervisor {
+                    Some(supervisor) => supervisor.is_running(),
+                    None => process
+                        .map(|p| !matches!(p.status(), sysinfo::ProcessStatus::Zombie | sysinfo::ProcessStatus::Dead))
+                        .unwrap_or(false),
+                };
+                let exit = info.supervisor.as_ref().map(Supervisor::status);
+                
+                serde_json::json!({
+                    "process_id": info.id,
+                    "pid": info.pid(),
+                    "attached": info.is_attached,
+                    "alive": alive,
+                    "status": process.map(|p| format!("{:?}", p.status())),
+                    "exit": exit.as_ref().and_then(ProcessStatus::describe_exit),
+                    "crashed": exit.map(|status| status.crashed).unwrap_or(false),
+                    "display": self.display_for(&info.id),
+                    "isolated_home": info.isolated_profile.as_ref().map(|profile| &profile.home),
+                    "log_files": info.log_file.as_ref().map(|file| file.lock().paths()).unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+    
+    /// Running processes that look like Tauri apps, most certain first.
     pub fn find_running_apps(&self) -> Result<Vec<Value>> {
         let mut system = self.system.write();
         system.refresh_processes();
         
+        let managed: HashMap<u32, &str> = self.processes.values()
+            .map(|info| (info.pid(), info.id.as_str()))
+            .collect();
+        let mut webkit_children: HashMap<Pid, Vec<u32>> = HashMap::new();
+        for (pid, process) in system.processes() {
+            if detect::is_webkit_helper(process.name()) {
+                if let Some(parent) = process.parent() {
+                    webkit_children.entry(parent).or_default().push(pid.as_u32());
+                }
+            }
+        }
+        #[cfg(target_os = "linux")]
+        let mut window_counts: HashMap<Option<String>, HashMap<u32, usize>> = HashMap::new();
+        
         let mut tauri_apps = Vec::new();
         
         for (pid, process) in system.processes() {
             let name = process.name();
+            if process.thread_kind().is_some() || detect::is_webkit_helper(name) {
+                continue;
+            }
+            let helpers = webkit_children.get(pid).map(Vec::as_slice).unwrap_or_default();
             let cmd = process.cmd();
-            
-            // Look for processes that might be Tauri apps
-            if name.contains("archestra") || 
-               cmd.iter().any(|arg| arg.contains("tauri") || arg.contains("archestra")) {
-                tauri_apps.push(serde_json::json!({
-                    "pid": pid.as_u32(),
-                    "name": name,
-                    "cmd": cmd.join(" "),
-                    "memory": process.memory(),
-                    "cpu_usage": process.cpu_usage(),
-                    "status": format!("{:?}", process.status()),
-                }));
+            let detection = detect::detect(pid.as_u32(), helpers.len(), cmd);
+            if detection.confidence < detect::MIN_CONFIDENCE {
+                continue;
             }
+            
+            // Launched apps may run under a wrapper such as a shell
+            let process_id = std::iter::successors(Some(*pid), |pid| system.process(*pid).and_then(|p| p.parent()))
+                .find_map(|pid| managed.get(&pid.as_u32()).copied());
+            
+            #[cfg(target_os = "linux")]
+            let window_count = {
+                let display = process_id.and_then(|id| self.display_for(id));
+                window_counts.entry(display.clone())
+                    .or_insert_with(|| count_windows(display.as_deref()))
+                    .get(&pid.as_u32())
+                    .copied()
+            };
+            #[cfg(not(target_os = "linux"))]
+            let window_count: Option<usize> = None;
+            
+            tauri_apps.push(serde_json::json!({
+                "pid": pid.as_u32(),
+                "name": name,
+                "exe": process.exe(),
+                "cmd": cmd.join(" "),
+                "confidence": (detection.confidence * 100.0).round() / 100.0,
+                "evidence": detection.evidence,
+                "webkit": detection.webkit,
+                "webkit_processes": helpers,
+                "window_count": window_count,
+                "managed": process_id.is_some(),
+                "process_id": process_id,
+                "memory": process.memory(),
+                "cpu_usage": process.cpu_usage(),
+                "status": format!("{:?}", process.status()),
+            }));
         }
         
+        tauri_apps.sort_by(|a, b| b["confidence"].as_f64().partial_cmp(&a["confidence"].as_f64()).unwrap_or(std::cmp::Ordering::Equal));
         Ok(tauri_apps)
     }
     
-    pub async fn attach_to_app(&mut self, pid: u32) -> Result<String> {
-        let mut system = self.system.write();
-        system.refresh_processes();
+    /// Track a process we did not launch, optionally following its output
+    /// from `log_source` into the same log store launched apps use.
+    pub async fn attach_to_app(&mut self, pid: u32, log_source: Option<&LogSource>) -> Result<String> {
+        let executable = {
+            let mut system = self.system.write();
+            system.refresh_processes();
+            match system.process(Pid::from_u32(pid)) {
+                Some(process) => process.exe().map(Path::to_path_buf),
+                None => return Err(TauriMcpError::ProcessError(format!("Process with PID {} not found", pid))),
+            }
+        };
         
-        if let Some(_process) = system.process(Pid::from_u32(pid)) {
-            let process_id = Uuid::new_v4().to_string();
-            
-            info!("Attaching to existing process with PID: {}", pid);
-            
-            // Create a dummy child process info for tracking
-            // Note: We won't have stdout/stderr for already running processes
-            let (_log_sender, log_receiver) = bounded(1000);
-            
-            // Create a dummy log handle that does nothing
-            let log_handle = tokio::spawn(async move {
-                // This task does nothing as we can't capture logs from external processes
-                tokio::time::sleep(tokio::time::Duration::from_secs(u64::MAX)).await;
-            });
-            
-            let process_info = ProcessInfo {
-                id: process_id.clone(),
-                child: None,
-                pid,
-                log_receiver,
-                log_handle,
-                is_attached: true,
-            };
-            
-            self.processes.insert(process_id.clone(), process_info);
-            
-            info!("Successfully attached to process with PID: {}", pid);
-            
-            Ok(process_id)
-        } else {
-            Err(TauriMcpError::ProcessError(format!("Process with PID {} not found", pid)))
+        let process_id = Uuid::new_v4().to_string();
+        info!("Attaching to existing process with PID: {}", pid);
+        
+        let logs = Arc::new(Mutex::new(LogBuffer::new(DEFAULT_LOG_BUFFER_LINES)));
+        let (log_events, _) = broadcast::channel(LOG_EVENT_CAPACITY);
+        // Without a log source there is no stdout/stderr for an already running process
+        let log_tasks = match log_source {
+            Some(source) => {
+                let sink = LogSink { buffer: logs.clone(), file: None, events: log_events.clone() };
+                let tasks = source.start(pid, sink)?;
+                info!("Following logs of PID {} from {:?}", pid, source);
+                tasks
+            }
+            None => Vec::new(),
+        };
+        
+        let process_info = ProcessInfo {
+            id: process_id.clone(),
+            supervisor: None,
+            attached_pid: pid,
+            started_at: Instant::now(),
+            logs,
+            log_file: None,
+            log_events,
+            startup_logs: None,
+            log_tasks,
+            sampler: None,
+            alert_monitor: None,
+            isolated_profile: None,
+            executable,
+            is_attached: true,
+            #[cfg(target_os = "linux")]
+            virtual_display: None,
+            #[cfg(target_os = "linux")]
+            display_teardown: None,
+        };
+        
+        self.processes.insert(process_id.clone(), process_info);
+        
+        info!("Successfully attached to process with PID: {}", pid);
+        
+        Ok(process_id)
+    }
+}
+
+/// Client windows per PID on `display`; empty when it cannot be opened.
+#[cfg(target_os = "linux")]
+fn count_windows(display: Option<&str>) -> HashMap<u32, usize> {
+    let mut counts = HashMap::new();
+    if let Ok(connection) = crate::utils::x11::XConnection::open(display) {
+        for window in connection.client_windows() {
+            *counts.entry(window.pid).or_default() += 1;
+        }
+    }
+    counts
+}
+
+/// A descendant of an app, recorded so it can be told apart from a later
+/// process that reuses its PID.
+#[derive(Debug, Clone, Copy)]
+struct Descendant {
+    pid: u32,
+    parent: u32,
+    start_time: u64,
+}
+
+impl Descendant {
+    /// PIDs in `tree` that still name the same processes: started at the
+    /// same time, under the same parent unless that one is gone and they
+    /// were reparented.
+    #[cfg_attr(not(unix), allow(dead_code))]
+    fn still_running(tree: &[Descendant]) -> Vec<u32> {
+        if tree.is_empty() {
+            return Vec::new();
         }
+        let mut system = System::new();
+        system.refresh_processes();
+        
+        tree.iter()
+            .filter(|descendant| {
+                system.process(Pid::from_u32(descendant.pid)).is_some_and(|process| {
+                    let parent = Pid::from_u32(descendant.parent);
+                    process.start_time() == descendant.start_time
+                        && (process.parent() == Some(parent) || system.process(parent).is_none())
+                })
+            })
+            .map(|descendant| descendant.pid)
+            .collect()
     }
-}
\ No newline at end of file
+}
+
+/// Signal an app's process group, when given, and any descendants that left it.
+#[cfg(unix)]
+fn signal_tree(group: Option<u32>, tree: &[u32], signal: nix::sys::signal::Signal) {
+    use nix::sys::signal::{kill, killpg};
+    use nix::unistd::Pid as UnixPid;
+    
+    debug!("Sending {} to process group {:?} and {} descendants", signal, group, tree.len());
+    if let Some(group) = group {
+        let _ = killpg(UnixPid::from_raw(group as i32), signal);
+    }
+    for &descendant in tree {
+        let _ = kill(UnixPid::from_raw(descendant as i32), signal);
+    }
+}
+
+pub(crate) fn signal_name(signal: i32) -> String {
+    #[cfg(unix)]
+    if let Ok(signal) = nix::sys::signal::Signal::try_from(signal) {
+        return signal.as_str().to_string();
+    }
+    format!("signal {}", signal)
+}
@@ -0,0 +1,426 @@
+use crate::tools::debug::WEBDRIVER_URL;
+use crate::tools::diagnostics::responding_devtools_ports;
+use crate::tools::logs::{stream_argument, strip_ansi, LogEntry, LogStream};
+use crate::tools::process::ProcessManager;
+use crate::tools::supervisor::{ProcessState, ProcessStatus};
+use crate::{Result, TauriMcpError};
+use regex::Regex;
+use reqwest::Client;
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::TryRecvError};
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+use tracing::{debug, info};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Log lines included in the diagnostic bundle when the app is not ready.
+const BUNDLE_LOG_LINES: usize = 50;
+const DEFAULT_LOG_CONTEXT: usize = 2;
+const MAX_LOG_CONTEXT: usize = 20;
+/// How long a match waits for its trailing context lines.
+const AFTER_CONTEXT_WAIT: Duration = Duration::from_millis(500);
+
+/// An HTTP endpoint the app is expected to bring up.
+#[derive(Debug, Clone)]
+pub enum Endpoint {
+    /// Any port in the DevTools range answering `/json/version`
+    DevTools,
+    /// The WebDriver server answering `/status`
+    WebDriver,
+    Url(String),
+}
+
+/// Conditions `launch_app` waits for before returning; all must hold.
+#[derive(Debug, Clone)]
+pub struct ReadyOptions {
+    /// A top-level window owned by the process is mapped
+    pub window: bool,
+    /// A stdout/stderr line matches, without its stream prefix
+    pub log_pattern: Option<Regex>,
+    pub endpoint: Option<Endpoint>,
+    /// A TCP port on localhost accepts connections
+    pub port: Option<u16>,
+    pub timeout: Duration,
+}
+
+impl ReadyOptions {
+    /// Parse the `wait_for` object of `launch_app` arguments.
+    pub fn from_arguments(arguments: &Value) -> Result<Option<Self>> {
+        let Some(wait_for) = arguments.get("wait_for") else {
+            return Ok(None);
+        };
+        
+        let window = wait_for.get("window").and_then(|v| v.as_bool()).unwrap_or(false);
+        if window && cfg!(target_os = "macos") {
+            return Err(TauriMcpError::ProcessError("wait_for.window is not supported on macOS".to_string()));
+        }
+        
+        let log_pattern = wait_for.get("log")
+            .and_then(|v| v.as_str())
+            .map(|pattern| Regex::new(pattern)
+                .map_err(|e| TauriMcpError::ProcessError(format!("Invalid wait_for.log regex: {}", e))))
+            .transpose()?;
+        
+        let endpoint = wait_for.get("endpoint").and_then(|v| v.as_str()).map(|endpoint| match endpoint {
+            "devtools" => Endpoint::DevTools,
+            "webdriver" => Endpoint::WebDriver,
+            url => Endpoint::Url(url.to_string()),
+        });
+        
+        let port = wait_for.get("port")
+            .and_then(|v| v.as_u64())
+            .map(|port| u16::try_from(port)
+                .map_err(|_| TauriMcpError::ProcessError(format!("Invalid wait_for.port: {}", port))))
+            .transpose()?;
+        
+        let timeout = wait_for.get("timeout_ms")
+            .and_then(|v| v.as_u64())
+            .map(Duration::from_millis)
+            .unwrap_or(DEFAULT_TIMEOUT);
+        
+        if !window && log_pattern.is_none() && endpoint.is_none() && port.is_none() {
+            return Err(TauriMcpError::ProcessError(
+                "wait_for needs at least one of window, log, endpoint or port".to_string()
+            ));
+        }
+        
+        Ok(Some(Self { window, log_pattern, endpoint, port, timeout }))
+    }
+    
+    fn conditions(&self) -> Vec<&'static str> {
+        let mut conditions = Vec::new();
+        if self.window {
+            conditions.push("window");
+        }
+        if self.log_pattern.is_some() {
+            conditions.push("log");
+        }
+        if self.endpoint.is_some() {
+            conditions.push("endpoint");
+        }
+        if self.port.is_some() {
+            conditions.push("port");
+        }
+        conditions
+    }
+}
+
+/// Wait for a launched process to satisfy `options`.
+///
+/// The process manager is only locked briefly for each check. Returns a
+/// `ready` report, or on timeout or early exit a diagnostic bundle with the
+/// pending conditions, the process status and the last log lines.
+pub async fn wait_until_ready(
+    process_manager: &RwLock<ProcessManager>,
+    process_id: &str,
+    options: &ReadyOptions,
+) -> Result<Value> {
+    let (pid, display, mut logs) = {
+        let mut manager = process_manager.write().await;
+        (manager.pid_of(process_id)?, manager.display_for(process_id), manager.subscribe_logs(process_id)?)
+    };
+    
+    let client = Client::builder()
+        .timeout(Duration::from_secs(1))
+        .build()
+        .map_err(|e| TauriMcpError::Other(format!("Failed to create HTTP client: {}", e)))?;
+    
+    let started = Instant::now();
+    let deadline = started + options.timeout;
+    let mut pending = options.conditions();
+    let mut recent_logs = VecDeque::with_capacity(BUNDLE_LOG_LINES);
+    let mut matched_log = None;
+    
+    info!("Waiting up to {:?} for process {} to be ready: {:?}", options.timeout, process_id, pending);
+    
+    let failure = 'wait: loop {
+        drain_logs(&mut logs, &mut recent_logs, options.log_pattern.as_ref(), &mut matched_log);
+        
+        let mut still_pending = Vec::new();
+        for &condition in &pending {
+            let satisfied = match condition {
+                "window" => match window_mapped(pid, display.as_deref()) {
+                    Ok(mapped) => mapped,
+                    Err(e) => break 'wait format!("Cannot watch for the window: {}", e),
+                },
+                "log" => matched_log.is_some(),
+                "endpoint" => endpoint_answers(&client, options.endpoint.as_ref()).await,
+                "port" => port_listening(options.port).await,
+                _ => true,
+            };
+            if !satisfied {
+                still_pending.push(condition);
+            }
+        }
+        pending = still_pending;
+        
+        if pending.is_empty() {
+            info!("Process {} ready after {:?}", process_id, started.elapsed());
+            return Ok(json!({
+                "ready": true,
+                "waited_ms": started.elapsed().as_millis(),
+                "matched_log": matched_log,
+            }));
+        }
+        
+        let status = process_manager.read().await.status_of(process_id)?;
+        if let Some(exit) = status.as_ref().and_then(ProcessStatus::describe_exit) {
+            break 'wait format!("Process exited before becoming ready: {}", exit);
+        }
+        
+        if Instant::now() >= deadline {
+            break 'wait format!("Timed out after {:?}", options.timeout);
+        }
+        
+        tokio::time::sleep(POLL_INTERVAL).await;
+    };
+    
+    info!("Process {} not ready: {}", process_id, failure);
+    
+    let status = process_manager.read().await.status_of(process_id)?;
+    let exit_status = status.as_ref().and_then(ProcessStatus::describe_exit);
+    if exit_status.is_some() {
+        // Give the log reader a moment to deliver the final lines
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        drain_logs(&mut logs, &mut recent_logs, options.log_pattern.as_ref(), &mut matched_log);
+    }
+    
+    Ok(json!({
+        "ready": false,
+        "reason": failure,
+        "waited_ms": started.elapsed().as_millis(),
+        "pending": pending,
+        "process": {
+            "pid": pid,
+            "running": exit_status.is_none(),
+            "exit_status": exit_status,
+            "panics": status.map(|s| s.panics).unwrap_or_default(),
+        },
+        "last_logs": recent_logs,
+    }))
+}
+
+/// Options of the `wait_for_log` tool.
+#[derive(Debug, Clone)]
+pub struct LogWaitOptions {
+    pub pattern: Regex,
+    pub timeout: Duration,
+    /// Ignore lines printed before the wait started
+    pub new_only: bool,
+    /// Lines returned before and after the match
+    pub context: usize,
+    pub stream: Option<LogStream>,
+}
+
+impl LogWaitOptions {
+    pub fn from_arguments(arguments: &Value) -> Result<Self> {
+        let pattern = arguments.get("pattern")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| TauriMcpError::ProcessError("Missing pattern".to_string()))?;
+        let pattern = Regex::new(pattern)
+            .map_err(|e| TauriMcpError::ProcessError(format!("Invalid pattern regex: {}", e)))?;
+        
+        Ok(Self {
+            pattern,
+            timeout: arguments.get("timeout_ms").and_then(|v| v.as_u64()).map(Duration::from_millis).unwrap_or(DEFAULT_TIMEOUT),
+            new_only: arguments.get("new_only").and_then(|v| v.as_bool()).unwrap_or(false),
+            context: arguments.get("context").and_then(|v| v.as_u64()).map_or(DEFAULT_LOG_CONTEXT, |n| n as usize).min(MAX_LOG_CONTEXT),
+            stream: stream_argument(arguments)?,
+        })
+    }
+    
+    fn wanted(&self, entry: &LogEntry) -> bool {
+        self.stream.is_none_or(|stream| entry.stream == stream)
+    }
+    
+    fn matches(&self, entry: &LogEntry) -> bool {
+        self.wanted(entry) && self.pattern.is_match(&strip_ansi(&entry.line))
+    }
+}
+
+/// Wait until a process prints a line matching `options.pattern`.
+///
+/// Unless `new_only` is set, lines already retained count too. Returns the
+/// match with its context, or `matched: false` with the reason (timeout or
+/// exit) and the last lines seen.
+pub async fn wait_for_log(
+    process_manager: &RwLock<ProcessManager>,
+    process_id: &str,
+    options: &LogWaitOptions,
+) -> Result<Value> {
+    let (retained, mut logs) = process_manager.read().await.follow_logs(process_id)?;
+    let started = Instant::now();
+    let deadline = started + options.timeout;
+    let mut before: VecDeque<LogEntry> = VecDeque::with_capacity(options.context + 1);
+    
+    if !options.new_only {
+        let wanted: Vec<&LogEntry> = retained.iter().filter(|entry| options.wanted(entry)).collect();
+        if let Some(index) = wanted.iter().position(|entry| options.matches(entry)) {
+            let start = index.saturating_sub(options.context);
+            let mut after: Vec<LogEntry> = wanted[index + 1..].iter()
+                .take(options.context)
+                .map(|entry| (*entry).clone())
+                .collect();
+            if after.len() < options.context {
+                collect_after(&mut logs, options, &mut after, Instant::now() + AFTER_CONTEXT_WAIT).await;
+            }
+            let before = wanted[start..index].iter().map(|entry| (*entry).clone()).collect();
+            return Ok(log_match(wanted[index], before, after, started));
+        }
+        let skip = wanted.len().saturating_sub(options.context);
+        before.extend(wanted[skip..].iter().map(|entry| (*entry).clone()));
+    }
+    
+    info!("Waiting up to {:?} for process {} to log /{}/", options.timeout, process_id, options.pattern);
+    
+    let mut poll = tokio::time::interval(POLL_INTERVAL);
+    let reason = loop {
+        tokio::select! {
+            entry = logs.recv() => match entry {
+                Ok(entry) if options.matches(&entry) => {
+                    let mut after = Vec::new();
+                    collect_after(&mut logs, options, &mut after, deadline.min(Instant::now() + AFTER_CONTEXT_WAIT)).await;
+                    return Ok(log_match(&entry, before.into(), after, started));
+                }
+                Ok(entry) => remember(&mut before, entry, options),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug!("wait_for_log on {} skipped {} lines", process_id, skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => break "Process was removed".to_string(),
+            },
+            _ = poll.tick() => {
+                let status = process_manager.read().await.status_of(process_id)?;
+                if let Some(status) = status.filter(|status| status.state == ProcessState::Exited) {
+                    // Lines printed just before exit are already queued
+                    while let Ok(entry) = logs.try_recv() {
+                        if options.matches(&entry) {
+                            let after = std::iter::from_fn(|| logs.try_recv().ok())
+                                .filter(|entry| options.wanted(entry))
+                                .take(options.context)
+                                .collect();
+                            return Ok(log_match(&entry, before.into(), after, started));
+                        }
+                        remember(&mut before, entry, options);
+                    }
+                    break format!("Process exited: {}", status.describe_exit().unwrap_or_default());
+                }
+                if Instant::now() >= deadline {
+                    break format!("Timed out after {:?}", options.timeout);
+                }
+            }
+        }
+    };
+    
+    info!("wait_for_log on {} gave up: {}", process_id, reason);
+    Ok(json!({
+        "matched": false,
+        "reason": reason,
+        "waited_ms": started.elapsed().as_millis(),
+        "last_lines": before.iter().map(LogEntry::formatted).collect::<Vec<_>>(),
+    }))
+}
+
+/// Keep the last `options.context` wanted lines as leading context.
+fn remember(before: &mut VecDeque<LogEntry>, entry: LogEntry, options: &LogWaitOptions) {
+    if !options.wanted(&entry) || options.context == 0 {
+        return;
+    }
+    if before.len() == options.context {
+        before.pop_front();
+    }
+    before.push_back(entry);
+}
+
+/// Receive up to `options.context` further lines until `until`.
+async fn collect_after(
+    logs: &mut broadcast::Receiver<LogEntry>,
+    options: &LogWaitOptions,
+    after: &mut Vec<LogEntry>,
+    until: Instant,
+) {
+    while after.len() < options.context {
+        match tokio::time::timeout_at(until, logs.recv()).await {
+            Ok(Ok(entry)) if options.wanted(&entry) => after.push(entry),
+            Ok(Ok(_)) | Ok(Err(broadcast::error::RecvError::Lagged(_))) => {}
+            Ok(Err(broadcast::error::RecvError::Closed)) | Err(_) => break,
+        }
+    }
+}
+
+fn log_match(entry: &LogEntry, before: Vec<LogEntry>, after: Vec<LogEntry>, started: Instant) -> Value {
+    json!({
+        "matched": true,
+        "waited_ms": started.elapsed().as_millis(),
+        "line": entry.formatted(),
+        "seq": entry.seq,
+        "timestamp": entry.timestamp,
+        "before": before.iter().map(LogEntry::formatted).collect::<Vec<_>>(),
+        "after": after.iter().map(LogEntry::formatted).collect::<Vec<_>>(),
+    })
+}
+
+fn drain_logs(
+    logs: &mut broadcast::Receiver<LogEntry>,
+    recent_logs: &mut VecDeque<String>,
+    pattern: Option<&Regex>,
+    matched_log: &mut Option<String>,
+) {
+    loop {
+        match logs.try_recv() {
+            Ok(entry) => {
+                let line = entry.formatted();
+                if matched_log.is_none() && pattern.is_some_and(|re| re.is_match(&strip_ansi(&entry.line))) {
+                    *matched_log = Some(line.clone());
+                }
+                if recent_logs.len() == BUNDLE_LOG_LINES {
+                    recent_logs.pop_front();
+                }
+                recent_logs.push_back(line);
+            }
+            Err(TryRecvError::Lagged(skipped)) => debug!("Readiness wait skipped {} log lines", skipped),
+            Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => break,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn window_mapped(pid: u32, display: Option<&str>) -> Result<bool> {
+    use crate::utils::x11::XConnection;
+    
+    let connection = XConnection::open(display)?;
+    Ok(connection.windows_for_pid(pid).iter().any(|w| w.mapped))
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn window_mapped(pid: u32, _display: Option<&str>) -> Result<bool> {
+    Ok(crate::utils::platform::get_window_by_pid(pid)?.is_some())
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn window_mapped(_pid: u32, _display: Option<&str>) -> Result<bool> {
+    Err(TauriMcpError::WindowError("Window readiness is not supported on macOS".to_string()))
+}
+
+async fn endpoint_answers(client: &Client, endpoint: Option<&Endpoint>) -> bool {
+    match endpoint {
+        Some(Endpoint::DevTools) => !responding_devtools_ports(client).await.is_empty(),
+        Some(Endpoint::WebDriver) => client.get(format!("{}/status", WEBDRIVER_URL)).send().await
+            .map(|response| response.status().is_success())
+            .unwrap_or(false),
+        // Any HTTP response means the server is up, even an error page
+        Some(Endpoint::Url(url)) => client.get(url).send().await.is_ok(),
+        None => true,
+    }
+}
+
+async fn port_listening(port: Option<u16>) -> bool {
+    let Some(port) = port else {
+        return true;
+    };
+    
+    let connect = tokio::net::TcpStream::connect(("127.0.0.1", port));
+    matches!(tokio::time::timeout(Duration::from_millis(500), connect).await, Ok(Ok(_)))
+}
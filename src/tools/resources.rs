@@ -0,0 +1,155 @@
+use serde::Serialize;
+use sysinfo::{Pid, Process, System};
+
+/// What a process in an app's tree is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessRole {
+    /// The app's own process
+    Main,
+    /// `WebKitWebProcess`: renders a webview and runs its JavaScript
+    WebContent,
+    /// `WebKitNetworkProcess`: all of the webviews' network traffic
+    Network,
+    /// `WebKitGPUProcess`
+    Gpu,
+    /// Anything else the app started
+    Child,
+}
+
+impl ProcessRole {
+    fn of(name: &str) -> Self {
+        // Linux truncates process names to 15 bytes
+        if name.starts_with("WebKitWebProces") {
+            ProcessRole::WebContent
+        } else if name.starts_with("WebKitNetworkPr") {
+            ProcessRole::Network
+        } else if name.starts_with("WebKitGPUProces") {
+            ProcessRole::Gpu
+        } else {
+            ProcessRole::Child
+        }
+    }
+}
+
+/// Resource usage of one process. Fields read from `/proc` are `None` on
+/// other platforms or when the process is not readable.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessResources {
+    pub pid: u32,
+    pub parent: Option<u32>,
+    pub name: String,
+    pub role: ProcessRole,
+    pub cpu_usage: f32,
+    pub rss_bytes: u64,
+    /// Proportional set size: shared pages split between their users
+    pub pss_bytes: Option<u64>,
+    pub threads: Option<usize>,
+    pub open_fds: Option<usize>,
+}
+
+impl ProcessResources {
+    pub(crate) fn of(pid: Pid, process: &Process, role: ProcessRole) -> Self {
+        let pid = pid.as_u32();
+        Self {
+            pid,
+            parent: process.parent().map(Pid::as_u32),
+            name: process.name().to_string(),
+            role,
+            cpu_usage: process.cpu_usage(),
+            rss_bytes: process.memory(),
+            pss_bytes: proc::pss_bytes(pid),
+            threads: proc::threads(pid),
+            open_fds: proc::open_fds(pid),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResourceTotals {
+    pub processes: usize,
+    pub cpu_usage: f32,
+    pub rss_bytes: u64,
+    /// Sum over the processes whose PSS could be read
+    pub pss_bytes: u64,
+    pub threads: usize,
+    pub open_fds: usize,
+}
+
+/// An app's process and all of its descendants, main process first.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceTree {
+    pub processes: Vec<ProcessResources>,
+    pub totals: ResourceTotals,
+}
+
+impl ResourceTree {
+    /// Collect the tree rooted at `root` from an already refreshed `system`.
+    pub fn collect(system: &System, root: u32) -> Option<Self> {
+        let root = Pid::from_u32(root);
+        let main = system.process(root)?;
+        
+        let mut processes = vec![ProcessResources::of(root, main, ProcessRole::Main)];
+        let mut parents = vec![root];
+        while let Some(parent) = parents.pop() {
+            for (pid, process) in system.processes() {
+                // sysinfo lists threads as processes on Linux
+                if process.parent() == Some(parent) && process.thread_kind().is_none() {
+                    processes.push(ProcessResources::of(*pid, process, ProcessRole::of(process.name())));
+                    parents.push(*pid);
+                }
+            }
+        }
+        
+        let mut totals = ResourceTotals { processes: processes.len(), ..Default::default() };
+        for process in &processes {
+            totals.cpu_usage += process.cpu_usage;
+            totals.rss_bytes += process.rss_bytes;
+            totals.pss_bytes += process.pss_bytes.unwrap_or(0);
+            totals.threads += process.threads.unwrap_or(0);
+            totals.open_fds += process.open_fds.unwrap_or(0);
+        }
+        
+        Some(Self { processes, totals })
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod proc {
+    /// `Pss:` from `smaps_rollup`, which reports kB.
+    pub fn pss_bytes(pid: u32) -> Option<u64> {
+        let rollup = std::fs::read_to_string(format!("/proc/{}/smaps_rollup", pid)).ok()?;
+        field_value(&rollup, "Pss:").map(|kb| kb * 1024)
+    }
+    
+    pub fn threads(pid: u32) -> Option<usize> {
+        let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+        field_value(&status, "Threads:").map(|threads| threads as usize)
+    }
+    
+    pub fn open_fds(pid: u32) -> Option<usize> {
+        Some(std::fs::read_dir(format!("/proc/{}/fd", pid)).ok()?.count())
+    }
+    
+    fn field_value(text: &str, name: &str) -> Option<u64> {
+        text.lines()
+            .find_map(|line| line.strip_prefix(name))
+            .and_then(|value| value.split_whitespace().next())
+            .and_then(|value| value.parse().ok())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod proc {
+    pub fn pss_bytes(_pid: u32) -> Option<u64> {
+        None
+    }
+    
+    pub fn threads(_pid: u32) -> Option<usize> {
+        None
+    }
+    
+    pub fn open_fds(_pid: u32) -> Option<usize> {
+        None
+    }
+}
@@ -0,0 +1,427 @@
+use crate::tools::process::ProcessManager;
+use crate::tools::resources::{self, ResourceTree};
+use crate::{Result, TauriMcpError};
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, System, MINIMUM_CPU_UPDATE_INTERVAL};
+use tokio::task::JoinHandle;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+const DEFAULT_DURATION: Duration = Duration::from_secs(10);
+/// Tool calls are handled one at a time, so a foreground run holds up every
+/// other request; longer sampling goes through `start` and `read`.
+const MAX_DURATION: Duration = Duration::from_secs(60);
+/// Samples a background sampler keeps; older ones are dropped.
+const MAX_SAMPLES: usize = 10_000;
+/// Sparklines are averaged down to at most this many characters.
+const SPARKLINE_WIDTH: usize = 60;
+const SPARKLINE_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+/// A leak is only suspected with enough samples, steady growth (a good
+/// linear fit) and a slope that is more than noise.
+const LEAK_MIN_SAMPLES: usize = 10;
+const LEAK_MIN_R_SQUARED: f64 = 0.8;
+const LEAK_MIN_GROWTH_PER_MIN: f64 = 1024.0 * 1024.0;
+
+/// What `sample_resources` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SampleAction {
+    /// Sample for `duration` and return the report
+    #[default]
+    Run,
+    /// Start sampling in the background until stopped
+    Start,
+    /// Report on the background sampler so far
+    Read,
+    /// Stop the background sampler and return its final report
+    Stop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeriesFormat {
+    #[default]
+    Sparkline,
+    Csv,
+}
+
+/// Options for `sample_resources`.
+#[derive(Debug, Clone)]
+pub struct SampleOptions {
+    pub action: SampleAction,
+    pub interval: Duration,
+    /// Ignored by background samplers, which run until stopped
+    pub duration: Duration,
+    /// Sum the whole process tree (WebKit helpers included) instead of the main process
+    pub tree: bool,
+    pub format: SeriesFormat,
+}
+
+impl Default for SampleOptions {
+    fn default() -> Self {
+        Self {
+            action: SampleAction::default(),
+            interval: DEFAULT_INTERVAL,
+            duration: DEFAULT_DURATION,
+            tree: true,
+            format: SeriesFormat::default(),
+        }
+    }
+}
+
+impl SampleOptions {
+    pub fn from_arguments(arguments: &Value) -> Result<Self> {
+        let defaults = Self::default();
+        let action = match arguments.get("action").and_then(|v| v.as_str()) {
+            None | Some("run") => SampleAction::Run,
+            Some("start") => SampleAction::Start,
+            Some("read") => SampleAction::Read,
+            Some("stop") => SampleAction::Stop,
+            Some(other) => return Err(TauriMcpError::ProcessError(format!("Invalid action: {} (expected run, start, read or stop)", other))),
+        };
+        let format = match arguments.get("format").and_then(|v| v.as_str()) {
+            None | Some("sparkline") => SeriesFormat::Sparkline,
+            Some("csv") => SeriesFormat::Csv,
+            Some(other) => return Err(TauriMcpError::ProcessError(format!("Invalid format: {} (expected sparkline or csv)", other))),
+        };
+        
+        let duration = arguments.get("duration_ms").and_then(|v| v.as_u64()).map(Duration::from_millis).unwrap_or(defaults.duration);
+        if action == SampleAction::Run && duration > MAX_DURATION {
+            return Err(TauriMcpError::ProcessError(format!(
+                "duration_ms is at most {} for action run, which blocks the server; use action start, then read or stop",
+                MAX_DURATION.as_millis()
+            )));
+        }
+        
+        Ok(Self {
+            action,
+            interval: arguments.get("interval_ms").and_then(|v| v.as_u64()).map(Duration::from_millis).unwrap_or(defaults.interval).max(MIN_INTERVAL),
+            duration,
+            tree: arguments.get("tree").and_then(|v| v.as_bool()).unwrap_or(defaults.tree),
+            format,
+        })
+    }
+}
+
+/// Run `sample_resources`. Foreground runs do not hold the manager lock
+/// while sampling, so other tools keep working meanwhile.
+pub async fn sample_resources(
+    process_manager: &tokio::sync::RwLock<ProcessManager>,
+    process_id: &str,
+    options: SampleOptions,
+) -> Result<Value> {
+    let report = match options.action {
+        SampleAction::Run => {
+            let sampler = process_manager.read().await.resource_sampler(process_id, options.tree)?;
+            let samples = sampler.run(&options).await;
+            SampleReport::new(&samples, &options)
+        }
+        SampleAction::Start => {
+            process_manager.write().await.start_sampling(process_id, options.clone())?;
+            return Ok(json!({
+                "process_id": process_id,
+                "status": "sampling",
+                "interval_ms": options.interval.as_millis() as u64,
+            }));
+        }
+        SampleAction::Read => process_manager.read().await.sampling_report(process_id)?,
+        SampleAction::Stop => process_manager.write().await.stop_sampling(process_id)?,
+    };
+    Ok(serde_json::to_value(report)?)
+}
+
+/// One reading of an app's resource usage.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResourceSample {
+    pub elapsed_ms: u64,
+    pub pid: u32,
+    pub cpu_usage: f32,
+    pub rss_bytes: u64,
+    pub pss_bytes: Option<u64>,
+    pub threads: Option<usize>,
+    pub open_fds: Option<usize>,
+}
+
+/// Takes readings of one managed app, following its PID across restarts.
+///
+/// Each sampler refreshes a `System` of its own: sysinfo's CPU usage covers
+/// the time since the last refresh, and a shared instance is also refreshed
+/// by other samplers, alert monitors and `monitor_resources`.
+#[derive(Clone)]
+pub struct ResourceSampler {
+    system: Arc<RwLock<System>>,
+    pid: Arc<AtomicU32>,
+    tree: bool,
+}
+
+impl ResourceSampler {
+    pub fn new(pid: Arc<AtomicU32>, tree: bool) -> Self {
+        Self { system: Arc::new(RwLock::new(System::new())), pid, tree }
+    }
+    
+    /// Refresh process data so the next reading's CPU usage covers the
+    /// time since now. sysinfo needs two refreshes, `MINIMUM_CPU_UPDATE_INTERVAL`
+    /// apart, before its CPU usage means anything; until then it reports ~0%.
+    pub async fn prime(&self) {
+        self.system.write().refresh_processes();
+        tokio::time::sleep(MINIMUM_CPU_UPDATE_INTERVAL).await;
+        self.system.write().refresh_processes();
+    }
+    
+    /// A reading, or `None` while the app is not running.
+    pub fn take(&self, started: Instant) -> Option<ResourceSample> {
+        let mut system = self.system.write();
+        system.refresh_processes();
+        let pid = self.pid.load(Ordering::SeqCst);
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        
+        if self.tree {
+            let tree = ResourceTree::collect(&system, pid)?;
+            let readable = |field: fn(&resources::ProcessResources) -> bool| tree.processes.iter().all(field);
+            Some(ResourceSample {
+                elapsed_ms,
+                pid,
+                cpu_usage: tree.totals.cpu_usage,
+                rss_bytes: tree.totals.rss_bytes,
+                pss_bytes: readable(|p| p.pss_bytes.is_some()).then_some(tree.totals.pss_bytes),
+                threads: readable(|p| p.threads.is_some()).then_some(tree.totals.threads),
+                open_fds: readable(|p| p.open_fds.is_some()).then_some(tree.totals.open_fds),
+            })
+        } else {
+            let process = system.process(Pid::from_u32(pid))?;
+            let usage = resources::ProcessResources::of(Pid::from_u32(pid), process, resources::ProcessRole::Main);
+            Some(ResourceSample {
+                elapsed_ms,
+                pid,
+                cpu_usage: usage.cpu_usage,
+                rss_bytes: usage.rss_bytes,
+                pss_bytes: usage.pss_bytes,
+                threads: usage.threads,
+                open_fds: usage.open_fds,
+            })
+        }
+    }
+    
+    /// Sample every `interval` for `duration`.
+    pub async fn run(&self, options: &SampleOptions) -> Vec<ResourceSample> {
+        let samples = Arc::new(Mutex::new(VecDeque::new()));
+        self.record(options.interval, Some(options.duration), Arc::clone(&samples)).await;
+        let samples = samples.lock();
+        samples.iter().cloned().collect()
+    }
+    
+    async fn record(&self, interval: Duration, duration: Option<Duration>, samples: Arc<Mutex<VecDeque<ResourceSample>>>) {
+        let started = Instant::now();
+        self.prime().await;
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        
+        while duration.is_none_or(|duration| started.elapsed() < duration) {
+            ticker.tick().await;
+            let sampler = self.clone();
+            let sample = tokio::task::spawn_blocking(move || sampler.take(started)).await.ok().flatten();
+            if let Some(sample) = sample {
+                let mut samples = samples.lock();
+                if samples.len() == MAX_SAMPLES {
+                    samples.pop_front();
+                }
+                samples.push_back(sample);
+            }
+        }
+    }
+}
+
+/// A sampler running until stopped, owned by the app's `ProcessInfo`.
+pub struct BackgroundSampler {
+    task: JoinHandle<()>,
+    samples: Arc<Mutex<VecDeque<ResourceSample>>>,
+    options: SampleOptions,
+}
+
+impl BackgroundSampler {
+    pub fn start(sampler: ResourceSampler, options: SampleOptions) -> Self {
+        let samples = Arc::new(Mutex::new(VecDeque::new()));
+        let recorded = Arc::clone(&samples);
+        let interval = options.interval;
+        let task = tokio::spawn(async move { sampler.record(interval, None, recorded).await });
+        Self { task, samples, options }
+    }
+    
+    pub fn report(&self) -> SampleReport {
+        let samples: Vec<ResourceSample> = self.samples.lock().iter().cloned().collect();
+        let mut report = SampleReport::new(&samples, &self.options);
+        report.running = !self.task.is_finished();
+        report
+    }
+}
+
+impl Drop for BackgroundSampler {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Stats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub p95: f64,
+}
+
+impl Stats {
+    pub(crate) fn of(values: &[f64]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        // Nearest-rank percentile
+        let rank = ((sorted.len() as f64) * 0.95).ceil() as usize;
+        Some(Self {
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+            p95: sorted[rank.clamp(1, sorted.len()) - 1],
+        })
+    }
+}
+
+/// Least-squares fit of memory over time.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryTrend {
+    /// `pss` when every sample has it, else `rss`
+    pub source: &'static str,
+    pub growth_bytes_per_min: f64,
+    pub r_squared: f64,
+    pub leak_suspected: bool,
+}
+
+impl MemoryTrend {
+    fn of(samples: &[ResourceSample]) -> Option<Self> {
+        if samples.len() < 2 {
+            return None;
+        }
+        let (source, memory): (_, Vec<f64>) = if samples.iter().all(|s| s.pss_bytes.is_some()) {
+            ("pss", samples.iter().map(|s| s.pss_bytes.unwrap_or(0) as f64).collect())
+        } else {
+            ("rss", samples.iter().map(|s| s.rss_bytes as f64).collect())
+        };
+        let minutes: Vec<f64> = samples.iter().map(|s| s.elapsed_ms as f64 / 60_000.0).collect();
+        
+        let n = samples.len() as f64;
+        let mean_x = minutes.iter().sum::<f64>() / n;
+        let mean_y = memory.iter().sum::<f64>() / n;
+        let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+        for (x, y) in minutes.iter().zip(&memory) {
+            sxx += (x - mean_x) * (x - mean_x);
+            sxy += (x - mean_x) * (y - mean_y);
+            syy += (y - mean_y) * (y - mean_y);
+        }
+        let slope = if sxx > 0.0 { sxy / sxx } else { 0.0 };
+        // Flat memory fits no trend at all
+        let r_squared = if sxx > 0.0 && syy > 0.0 { (sxy * sxy) / (sxx * syy) } else { 0.0 };
+        
+        Some(Self {
+            source,
+            growth_bytes_per_min: slope.round(),
+            r_squared: (r_squared * 1000.0).round() / 1000.0,
+            leak_suspected: samples.len() >= LEAK_MIN_SAMPLES
+                && r_squared >= LEAK_MIN_R_SQUARED
+                && slope >= LEAK_MIN_GROWTH_PER_MIN,
+        })
+    }
+}
+
+/// Statistics over a series of samples.
+#[derive(Debug, Clone, Serialize)]
+pub struct SampleReport {
+    pub samples: usize,
+    pub interval_ms: u64,
+    /// Time covered by the samples
+    pub duration_ms: u64,
+    pub running: bool,
+    pub cpu_usage: Option<Stats>,
+    pub rss_bytes: Option<Stats>,
+    pub pss_bytes: Option<Stats>,
+    pub memory_trend: Option<MemoryTrend>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sparkline: Option<Sparklines>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub csv: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Sparklines {
+    pub cpu_usage: String,
+    pub memory: String,
+}
+
+impl SampleReport {
+    pub fn new(samples: &[ResourceSample], options: &SampleOptions) -> Self {
+        let cpu: Vec<f64> = samples.iter().map(|s| s.cpu_usage as f64).collect();
+        let rss: Vec<f64> = samples.iter().map(|s| s.rss_bytes as f64).collect();
+        let pss: Vec<f64> = samples.iter().filter_map(|s| s.pss_bytes.map(|p| p as f64)).collect();
+        let memory = if pss.len() == samples.len() { &pss } else { &rss };
+        
+        Self {
+            samples: samples.len(),
+            interval_ms: options.interval.as_millis() as u64,
+            duration_ms: match (samples.first(), samples.last()) {
+                (Some(first), Some(last)) => last.elapsed_ms - first.elapsed_ms,
+                _ => 0,
+            },
+            running: false,
+            cpu_usage: Stats::of(&cpu),
+            rss_bytes: Stats::of(&rss),
+            pss_bytes: Stats::of(&pss),
+            memory_trend: MemoryTrend::of(samples),
+            sparkline: (options.format == SeriesFormat::Sparkline).then(|| Sparklines {
+                cpu_usage: sparkline(&cpu),
+                memory: sparkline(memory),
+            }),
+            csv: (options.format == SeriesFormat::Csv).then(|| csv(samples)),
+        }
+    }
+}
+
+fn sparkline(values: &[f64]) -> String {
+    if values.is_empty() {
+        return String::new();
+    }
+    let bucket = values.len().div_ceil(SPARKLINE_WIDTH);
+    let averaged: Vec<f64> = values.chunks(bucket)
+        .map(|chunk| chunk.iter().sum::<f64>() / chunk.len() as f64)
+        .collect();
+    let min = averaged.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = averaged.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let top = SPARKLINE_LEVELS.len() - 1;
+    averaged.iter()
+        .map(|value| {
+            let level = if max > min { ((value - min) / (max - min) * top as f64).round() as usize } else { 0 };
+            SPARKLINE_LEVELS[level.min(top)]
+        })
+        .collect()
+}
+
+fn csv(samples: &[ResourceSample]) -> String {
+    let optional = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or_default();
+    let mut csv = String::from("elapsed_ms,pid,cpu_usage,rss_bytes,pss_bytes,threads,open_fds\n");
+    for s in samples {
+        csv.push_str(&format!(
+            "{},{},{:.1},{},{},{},{}\n",
+            s.elapsed_ms,
+            s.pid,
+            s.cpu_usage,
+            s.rss_bytes,
+            optional(s.pss_bytes),
+            optional(s.threads.map(|t| t as u64)),
+            optional(s.open_fds.map(|f| f as u64)),
+        ));
+    }
+    csv
+}
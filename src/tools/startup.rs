@@ -0,0 +1,320 @@
+use crate::tools::debug::devtools_evaluate;
+use crate::tools::diagnostics::responding_devtools_ports;
+use crate::tools::process::{LaunchOptions, ProcessManager, StopOptions};
+use crate::tools::ready::window_mapped;
+use crate::tools::sampling::Stats;
+use crate::utils::app_dirs::{AppDirs, BaseDirs};
+use crate::{Result, TauriMcpError};
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::broadcast::error::TryRecvError;
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+const DEFAULT_RUNS: usize = 5;
+const MAX_RUNS: usize = 10;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Tool calls are handled one at a time, so the whole series holds up every
+/// other request; runs × timeout may not exceed this.
+const MAX_TOTAL_TIMEOUT: Duration = Duration::from_secs(60);
+/// How long after spawn peak RSS is tracked.
+const DEFAULT_PEAK_RSS_WINDOW: Duration = Duration::from_secs(5);
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+const RSS_INTERVAL: Duration = Duration::from_millis(100);
+const DEVTOOLS_INTERVAL: Duration = Duration::from_millis(250);
+/// Runs are stopped quickly; startup is being measured, not shutdown.
+const STOP_GRACE_PERIOD: Duration = Duration::from_secs(2);
+/// `performance.timing` is wall-clock milliseconds, 0 until the event fired.
+const DOM_CONTENT_LOADED: &str = "performance.timing.domContentLoadedEventEnd";
+
+/// Options for `measure_startup`.
+#[derive(Debug, Clone)]
+pub struct StartupOptions {
+    pub runs: usize,
+    /// Delete the app's cache directories before each run
+    pub cold: bool,
+    /// Bundle identifier, used to find the cache directory for `cold`
+    pub identifier: Option<String>,
+    /// Extra directories to delete for `cold`
+    pub cache_dirs: Vec<PathBuf>,
+    /// Per run
+    pub timeout: Duration,
+    pub peak_rss_window: Duration,
+}
+
+impl StartupOptions {
+    pub fn from_arguments(arguments: &Value) -> Result<Self> {
+        let runs = arguments.get("runs").and_then(|v| v.as_u64()).map_or(DEFAULT_RUNS, |n| n as usize);
+        if runs == 0 || runs > MAX_RUNS {
+            return Err(TauriMcpError::ProcessError(format!("runs must be between 1 and {}", MAX_RUNS)));
+        }
+        let timeout = arguments.get("timeout_ms").and_then(|v| v.as_u64()).map(Duration::from_millis).unwrap_or(DEFAULT_TIMEOUT);
+        // A run lasts at least the peak RSS window, so it counts towards the budget too
+        let peak_rss_window = arguments.get("peak_rss_ms").and_then(|v| v.as_u64()).map(Duration::from_millis).unwrap_or(DEFAULT_PEAK_RSS_WINDOW);
+        if timeout.max(peak_rss_window) * runs as u32 > MAX_TOTAL_TIMEOUT {
+            return Err(TauriMcpError::ProcessError(format!(
+                "runs × timeout_ms (or peak_rss_ms) may be at most {} ms; the server answers nothing else meanwhile",
+                MAX_TOTAL_TIMEOUT.as_millis()
+            )));
+        }
+        
+        Ok(Self {
+            runs,
+            cold: arguments.get("cold").and_then(|v| v.as_bool()).unwrap_or(false),
+            identifier: arguments.get("identifier").and_then(|v| v.as_str()).map(String::from),
+            cache_dirs: arguments.get("cache_dirs")
+                .and_then(|v| v.as_array())
+                .map(|dirs| dirs.iter().filter_map(|d| d.as_str().map(PathBuf::from)).collect())
+                .unwrap_or_default(),
+            timeout,
+            peak_rss_window,
+        })
+    }
+    
+    /// Directories a cold run starts without. Each is deleted outright, so
+    /// only directories strictly inside the user's cache directory qualify.
+    fn cold_dirs(&self) -> Result<Vec<PathBuf>> {
+        if self.identifier.is_none() && self.cache_dirs.is_empty() {
+            return Err(TauriMcpError::ProcessError(
+                "cold runs need the app's identifier (or a project app_path) or cache_dirs".to_string()
+            ));
+        }
+        let base = BaseDirs::from_env()
+            .ok_or_else(|| TauriMcpError::Other("Could not determine the user's cache directory".to_string()))?;
+        
+        let mut dirs = Vec::new();
+        for dir in &self.cache_dirs {
+            if !is_strictly_inside(dir, &base.cache) {
+                return Err(TauriMcpError::ProcessError(format!(
+                    "cache_dirs must be inside {}: {}", base.cache.display(), dir.display()
+                )));
+            }
+            dirs.push(dir.clone());
+        }
+        if let Some(identifier) = &self.identifier {
+            dirs.push(AppDirs::new(identifier, &base)?.cache);
+        }
+        Ok(dirs)
+    }
+}
+
+/// Whether `dir` names a directory below `base`, not `base` itself and not
+/// anything a `..` leads out to.
+fn is_strictly_inside(dir: &Path, base: &Path) -> bool {
+    match dir.strip_prefix(base) {
+        Ok(rest) => {
+            rest.components().next().is_some()
+                && rest.components().all(|component| matches!(component, Component::Normal(_)))
+        }
+        Err(_) => false,
+    }
+}
+
+/// Timings of one launch, in milliseconds since spawn.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StartupRun {
+    pub run: usize,
+    pub pid: u32,
+    pub first_window_ms: Option<u64>,
+    pub first_log_ms: Option<u64>,
+    /// Only with a DevTools endpoint that appeared after launch
+    pub dom_content_loaded_ms: Option<u64>,
+    pub peak_rss_bytes: Option<u64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub caches_cleared: Vec<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Launch `executable` `options.runs` times, one after another, and report
+/// each run's startup milestones with their distribution across runs.
+pub async fn measure_startup(
+    process_manager: &RwLock<ProcessManager>,
+    executable: &str,
+    args: Vec<String>,
+    launch: LaunchOptions,
+    options: &StartupOptions,
+) -> Result<Value> {
+    let cold_dirs = if options.cold { options.cold_dirs()? } else { Vec::new() };
+    let client = Client::builder()
+        .timeout(Duration::from_secs(1))
+        .build()
+        .map_err(|e| TauriMcpError::Other(format!("Failed to create HTTP client: {}", e)))?;
+    
+    let mut runs = Vec::with_capacity(options.runs);
+    for run in 1..=options.runs {
+        // A run that fails to start is reported with the others, not instead of them
+        let mut caches_cleared = Vec::new();
+        let cleared = cold_dirs.iter()
+            .filter(|dir| dir.is_dir())
+            .try_for_each(|dir| {
+                std::fs::remove_dir_all(dir)
+                    .map_err(|e| format!("Failed to clear cache {}: {}", dir.display(), e))?;
+                caches_cleared.push(dir.clone());
+                Ok::<_, String>(())
+            });
+        if let Err(error) = cleared {
+            warn!("Startup run {}: {}", run, error);
+            runs.push(StartupRun { run, caches_cleared, error: Some(error), ..Default::default() });
+            continue;
+        }
+        // Endpoints already up belong to something else
+        let devtools_before = responding_devtools_ports(&client).await;
+        
+        let process_id = match process_manager.write().await.launch_app(executable, args.clone(), launch.clone()).await {
+            Ok(process_id) => process_id,
+            Err(e) => {
+                warn!("Startup run {} failed to launch: {}", run, e);
+                runs.push(StartupRun { run, caches_cleared, error: Some(format!("Failed to launch: {}", e)), ..Default::default() });
+                continue;
+            }
+        };
+        let mut result = measure_run(process_manager, &process_id, &client, &devtools_before, options).await;
+        result.run = run;
+        result.caches_cleared = caches_cleared;
+        info!("Startup run {}: {:?}", run, result);
+        runs.push(result);
+        
+        let stop = StopOptions { grace_period: STOP_GRACE_PERIOD, ..Default::default() };
+        if let Err(e) = process_manager.write().await.stop_app(&process_id, stop).await {
+            warn!("Failed to stop startup run {}: {}", run, e);
+        }
+    }
+    
+    let distribution = |metric: fn(&StartupRun) -> Option<u64>| {
+        let values: Vec<f64> = runs.iter().filter_map(metric).map(|v| v as f64).collect();
+        json!({ "runs": values.len(), "stats": Stats::of(&values) })
+    };
+    
+    Ok(json!({
+        "executable": executable,
+        "cold": options.cold,
+        "runs": runs,
+        "first_window_ms": distribution(|r| r.first_window_ms),
+        "first_log_ms": distribution(|r| r.first_log_ms),
+        "dom_content_loaded_ms": distribution(|r| r.dom_content_loaded_ms),
+        "peak_rss_bytes": distribution(|r| r.peak_rss_bytes),
+    }))
+}
+
+/// Watch one launched run until its window is up (where that can be seen),
+/// the peak RSS window has passed and DOMContentLoaded, if measurable, fired.
+async fn measure_run(
+    process_manager: &RwLock<ProcessManager>,
+    process_id: &str,
+    client: &Client,
+    devtools_before: &[u16],
+    options: &StartupOptions,
+) -> StartupRun {
+    let setup = async {
+        let mut manager = process_manager.write().await;
+        let status = manager.status_of(process_id)?
+            .ok_or_else(|| TauriMcpError::ProcessError("Startup runs need a launched app".to_string()))?;
+        let spawned = DateTime::parse_from_rfc3339(&status.started_at)
+            .map_err(|e| TauriMcpError::Other(format!("Invalid start time: {}", e)))?
+            .with_timezone(&Utc);
+        Ok::<_, TauriMcpError>((
+            spawned,
+            manager.pid_of(process_id)?,
+            manager.display_for(process_id),
+            manager.subscribe_logs(process_id)?,
+            manager.resource_sampler(process_id, true)?,
+        ))
+    };
+    let (spawned, pid, display, mut logs, sampler) = match setup.await {
+        Ok(setup) => setup,
+        Err(e) => return StartupRun { error: Some(e.to_string()), ..Default::default() },
+    };
+    let since_spawn = |at: DateTime<Utc>| (at - spawned).num_milliseconds().max(0) as u64;
+    
+    let mut result = StartupRun { pid, ..Default::default() };
+    let deadline = Instant::now() + options.timeout;
+    let peak_until = Instant::now() + options.peak_rss_window;
+    let mut window_visible = true;
+    let mut devtools_port = None;
+    let mut next_rss = Instant::now();
+    // Only RSS is read, so the sampler needs no priming for CPU usage
+    let mut next_devtools = Instant::now();
+    
+    loop {
+        loop {
+            match logs.try_recv() {
+                Ok(entry) => {
+                    result.first_log_ms.get_or_insert(since_spawn(entry.timestamp));
+                }
+                Err(TryRecvError::Lagged(_)) => continue,
+                Err(_) => break,
+            }
+        }
+        
+        if window_visible && result.first_window_ms.is_none() {
+            match window_mapped(pid, display.as_deref()) {
+                Ok(true) => result.first_window_ms = Some(since_spawn(Utc::now())),
+                Ok(false) => {}
+                Err(e) => {
+                    debug!("Window detection unavailable: {}", e);
+                    window_visible = false;
+                }
+            }
+        }
+        
+        let now = Instant::now();
+        if now < peak_until && now >= next_rss {
+            next_rss = now + RSS_INTERVAL;
+            if let Some(sample) = sampler.take(std::time::Instant::now()) {
+                result.peak_rss_bytes = Some(result.peak_rss_bytes.unwrap_or(0).max(sample.rss_bytes));
+            }
+        }
+        
+        if result.dom_content_loaded_ms.is_none() && now >= next_devtools {
+            next_devtools = now + DEVTOOLS_INTERVAL;
+            if devtools_port.is_none() {
+                devtools_port = responding_devtools_ports(client).await.into_iter().find(|port| !devtools_before.contains(port));
+            }
+            if let Some(port) = devtools_port {
+                if let Ok(Ok(loaded)) = tokio::time::timeout(Duration::from_secs(1), devtools_evaluate(client, port, DOM_CONTENT_LOADED)).await {
+                    if let Some(at) = loaded.as_i64().filter(|ms| *ms > 0).and_then(DateTime::from_timestamp_millis) {
+                        result.dom_content_loaded_ms = Some(since_spawn(at));
+                    }
+                }
+            }
+        }
+        
+        let window_done = !window_visible || result.first_window_ms.is_some();
+        let dom_done = devtools_port.is_none() || result.dom_content_loaded_ms.is_some();
+        if window_done && dom_done && Instant::now() >= peak_until {
+            break;
+        }
+        
+        let exited = process_manager.read().await.status_of(process_id)
+            .ok()
+            .flatten()
+            .and_then(|status| status.describe_exit());
+        if let Some(exit) = exited {
+            result.error = Some(format!("Exited during startup: {}", exit));
+            break;
+        }
+        if Instant::now() >= deadline {
+            let mut pending = Vec::new();
+            if !window_done {
+                pending.push("window");
+            }
+            if !dom_done {
+                pending.push("DOMContentLoaded");
+            }
+            if !pending.is_empty() {
+                result.error = Some(format!("Timed out after {} ms waiting for {}", options.timeout.as_millis(), pending.join(", ")));
+            }
+            break;
+        }
+        
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+    
+    result
+}
@@ -0,0 +1,698 @@
+use crate::tools::crashes::{parse_rust_backtrace, CrashHistory, ExecutableImage};
+use crate::tools::logs::{strip_ansi, LogEntry, LogLevel, LogStream};
+use crate::tools::process::{LaunchSpec, LogSink};
+use crate::tools::threads::StackFrame;
+use crate::{Result, TauriMcpError};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::process::{Child, ChildStdin};
+use tokio::sync::{broadcast, watch, Notify};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// Panics kept per process; later ones are usually fallout from the first.
+const MAX_PANICS: usize = 10;
+/// Lines of panic message kept before the backtrace starts.
+const MAX_PANIC_MESSAGE_LINES: usize = 50;
+/// A panic with no further stderr for this long is complete; the `note: run
+/// with RUST_BACKTRACE` line that usually ends it is only printed once.
+const PANIC_IDLE_TIMEOUT: Duration = Duration::from_millis(500);
+const MAX_BACKTRACE_LINES: usize = 400;
+/// Grandchildren can keep the output pipes open after the app exits.
+const LOG_FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_RESTARTS: u32 = 5;
+const DEFAULT_RESTART_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_RESTART_BACKOFF: Duration = Duration::from_secs(30);
+/// A run lasting this long resets the backoff and the restart budget.
+const STABLE_RUN: Duration = Duration::from_secs(30);
+/// Restart records kept per process.
+const MAX_RESTART_RECORDS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessState {
+    Running,
+    /// Waiting out the backoff before the next run
+    Restarting,
+    Exited,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicy {
+    #[default]
+    Never,
+    /// Restart after a crash: a failing exit code, a signal or a panic
+    OnFailure,
+    Always,
+}
+
+/// When and how often the supervisor relaunches an app that exited.
+#[derive(Debug, Clone)]
+pub struct RestartOptions {
+    pub policy: RestartPolicy,
+    /// Consecutive restarts allowed; a stable run resets the count
+    pub max_restarts: u32,
+    /// Delay before the first restart, doubled for each consecutive one
+    pub backoff: Duration,
+}
+
+impl Default for RestartOptions {
+    fn default() -> Self {
+        Self {
+            policy: RestartPolicy::Never,
+            max_restarts: DEFAULT_MAX_RESTARTS,
+            backoff: DEFAULT_RESTART_BACKOFF,
+        }
+    }
+}
+
+impl RestartOptions {
+    /// Parse `restart`, `max_restarts` and `restart_backoff_ms` tool arguments.
+    pub fn from_arguments(arguments: &Value) -> Result<Self> {
+        let mut options = RestartOptions::default();
+        
+        if let Some(policy) = arguments.get("restart") {
+            options.policy = serde_json::from_value(policy.clone()).map_err(|_| TauriMcpError::ProcessError(format!(
+                "Invalid restart policy {}: expected never, on-failure or always", policy
+            )))?;
+        }
+        if let Some(max_restarts) = arguments.get("max_restarts").and_then(|v| v.as_u64()) {
+            options.max_restarts = max_restarts.min(u32::MAX as u64) as u32;
+        }
+        if let Some(ms) = arguments.get("restart_backoff_ms").and_then(|v| v.as_u64()) {
+            options.backoff = Duration::from_millis(ms);
+        }
+        
+        Ok(options)
+    }
+}
+
+/// One run of a restarted app, recorded when the supervisor relaunched it.
+#[derive(Debug, Clone, Serialize)]
+pub struct RestartRecord {
+    /// The run that ended, counting from 1
+    pub run: u32,
+    pub exit: Option<String>,
+    pub exit_code: Option<i32>,
+    pub signal: Option<i32>,
+    pub panicked: bool,
+    pub runtime_ms: u128,
+    pub exited_at: String,
+    pub backoff_ms: u128,
+}
+
+/// A Rust panic seen on the app's stderr.
+#[derive(Debug, Clone, Serialize)]
+pub struct PanicReport {
+    pub thread: String,
+    /// OS thread id, printed by recent Rust versions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<u32>,
+    pub location: Option<String>,
+    pub message: String,
+    /// Backtrace lines as printed, when `RUST_BACKTRACE` was set
+    pub backtrace: Vec<String>,
+    /// The backtrace parsed, innermost frame first
+    pub frames: Vec<StackFrame>,
+    pub detected_at: String,
+}
+
+/// Lifecycle of a launched process, kept up to date by its supervisor task.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessStatus {
+    pub state: ProcessState,
+    pub started_at: String,
+    pub exited_at: Option<String>,
+    pub runtime_ms: Option<u128>,
+    pub exit_code: Option<i32>,
+    pub signal: Option<i32>,
+    pub signal_name: Option<String>,
+    /// Exited on its own with a failure, a signal or a panic
+    pub crashed: bool,
+    pub panics: Vec<PanicReport>,
+    /// Current run, counting from 1; grows with each restart
+    pub run: u32,
+    pub restart_policy: RestartPolicy,
+    pub restarts: Vec<RestartRecord>,
+}
+
+impl ProcessStatus {
+    fn new(restart_policy: RestartPolicy) -> Self {
+        Self {
+            state: ProcessState::Running,
+            started_at: chrono::Utc::now().to_rfc3339(),
+            exited_at: None,
+            runtime_ms: None,
+            exit_code: None,
+            signal: None,
+            signal_name: None,
+            crashed: false,
+            panics: Vec::new(),
+            run: 1,
+            restart_policy,
+            restarts: Vec::new(),
+        }
+    }
+    
+    /// Human-readable exit, e.g. `exit code 101` or `signal 11 (SIGSEGV)`.
+    pub fn describe_exit(&self) -> Option<String> {
+        match (self.exit_code, self.signal, &self.signal_name) {
+            (Some(code), _, _) => Some(format!("exit code {}", code)),
+            (None, Some(signal), Some(name)) => Some(format!("signal {} ({})", signal, name)),
+            (None, Some(signal), None) => Some(format!("signal {}", signal)),
+            _ if self.state == ProcessState::Exited => Some("exited".to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// A lifecycle change, broadcast to subscribers such as the MCP notification stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessEvent {
+    pub process_id: String,
+    pub pid: u32,
+    /// `started`, `panicked`, `exited`, `restarting` or `alert`
+    pub event: &'static str,
+    pub timestamp: String,
+    pub details: Value,
+}
+
+impl ProcessEvent {
+    pub fn new(process_id: &str, pid: u32, event: &'static str, details: Value) -> Self {
+        Self {
+            process_id: process_id.to_string(),
+            pid,
+            event,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            details,
+        }
+    }
+}
+
+fn panic_header() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    // Recent Rust versions add the OS thread id: `thread 'main' (4242) panicked at`
+    RE.get_or_init(|| Regex::new(r"^thread '([^']*)'(?: \((\d+)\))? panicked at (.*)$").expect("valid regex"))
+}
+
+fn backtrace_line() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\s+(\d+: |at )").expect("valid regex"))
+}
+
+enum PanicPhase {
+    Message,
+    Backtrace,
+}
+
+/// Assembles panic reports from stderr lines.
+///
+/// Handles both the current format (`panicked at src/main.rs:2:5:` with the
+/// message on the following lines) and the pre-1.73 one
+/// (`panicked at 'boom', src/main.rs:2:5`).
+#[derive(Default)]
+pub struct PanicDetector {
+    current: Option<(PanicReport, PanicPhase)>,
+}
+
+impl PanicDetector {
+    /// Feed one stderr line; returns a report once a panic is complete.
+    pub fn feed(&mut self, line: &str) -> Option<PanicReport> {
+        if let Some(captures) = panic_header().captures(line) {
+            let finished = self.finish();
+            let thread_id = captures.get(2).and_then(|id| id.as_str().parse().ok());
+            self.current = Some((Self::start(&captures[1], thread_id, &captures[3]), PanicPhase::Message));
+            return finished;
+        }
+        
+        let (report, phase) = self.current.as_mut()?;
+        match phase {
+            PanicPhase::Message => {
+                if line.starts_with("stack backtrace:") {
+                    *phase = PanicPhase::Backtrace;
+                } else if line.starts_with("note: run with `RUST_BACKTRACE")
+                    || line.trim().is_empty()
+                    || LogLevel::of_line(&strip_ansi(line)).is_some()
+                {
+                    // The next log record is the app carrying on, not the message
+                    return self.finish();
+                } else if report.message.lines().count() < MAX_PANIC_MESSAGE_LINES {
+                    if !report.message.is_empty() {
+                        report.message.push('\n');
+                    }
+                    report.message.push_str(line);
+                }
+                None
+            }
+            PanicPhase::Backtrace => {
+                if backtrace_line().is_match(line) {
+                    if report.backtrace.len() < MAX_BACKTRACE_LINES {
+                        report.backtrace.push(line.to_string());
+                    }
+                    None
+                } else {
+                    // "note: Some details are omitted..." or unrelated output
+                    self.finish()
+                }
+            }
+        }
+    }
+    
+    /// Whether a panic has started and is not complete yet.
+    pub fn in_progress(&self) -> bool {
+        self.current.is_some()
+    }
+    
+    /// Complete whatever panic is in progress, e.g. when the process exits.
+    pub fn finish(&mut self) -> Option<PanicReport> {
+        self.current.take().map(|(mut report, _)| {
+            report.frames = parse_rust_backtrace(&report.backtrace);
+            report
+        })
+    }
+    
+    fn start(thread: &str, thread_id: Option<u32>, rest: &str) -> PanicReport {
+        let (location, message) = match rest.strip_suffix(':') {
+            Some(location) => (Some(location.to_string()), String::new()),
+            // Old format: 'message', location
+            None => match rest.strip_prefix('\'').and_then(|r| r.rsplit_once("', ")) {
+                Some((message, location)) => (Some(location.to_string()), message.to_string()),
+                None => (None, rest.to_string()),
+            },
+        };
+        
+        PanicReport {
+            thread: thread.to_string(),
+            thread_id,
+            location,
+            message,
+            backtrace: Vec::new(),
+            frames: Vec::new(),
+            detected_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Handle to the task that owns a launched app's `Child`, and relaunches it
+/// according to its restart policy.
+pub struct Supervisor {
+    status: Arc<parking_lot::Mutex<ProcessStatus>>,
+    exited: watch::Receiver<bool>,
+    stop_requested: Arc<AtomicBool>,
+    stop: Arc<Notify>,
+    kill: Arc<Notify>,
+    pid: Arc<AtomicU32>,
+    stdin: Arc<tokio::sync::Mutex<Option<ChildStdin>>>,
+}
+
+impl Supervisor {
+    /// Spawn the first run of `spec` and watch it until it exits for good.
+    /// Failing to spawn it is an error; failing to relaunch ends supervision.
+    pub fn spawn(
+        spec: LaunchSpec,
+        process_id: String,
+        logs: LogSink,
+        events: broadcast::Sender<ProcessEvent>,
+        crashes: Arc<parking_lot::Mutex<CrashHistory>>,
+    ) -> Result<Self> {
+        let mut child = spec.spawn()?;
+        let pid = child.id()
+            .ok_or_else(|| TauriMcpError::ProcessError("Failed to get process ID".to_string()))?;
+        // Exec has completed, so the executable's own mapping is in place
+        let image = ExecutableImage::of_process(pid);
+        // Subscribed before the reader starts so no panic line is missed
+        let supervisor_logs = logs.subscribe();
+        let reader = logs.read(&mut child)?;
+        
+        let status = Arc::new(parking_lot::Mutex::new(ProcessStatus::new(spec.options.restart.policy)));
+        let (exited_tx, exited) = watch::channel(false);
+        let supervisor = Self {
+            status: Arc::clone(&status),
+            exited,
+            stop_requested: Arc::new(AtomicBool::new(false)),
+            stop: Arc::new(Notify::new()),
+            kill: Arc::new(Notify::new()),
+            pid: Arc::new(AtomicU32::new(pid)),
+            stdin: Arc::new(tokio::sync::Mutex::new(child.stdin.take())),
+        };
+        
+        let _ = events.send(ProcessEvent::new(&process_id, pid, "started", json!({ "run": 1 })));
+        
+        let task = SupervisorTask {
+            process_id,
+            spec,
+            logs,
+            events,
+            status,
+            stop_requested: Arc::clone(&supervisor.stop_requested),
+            stop: Arc::clone(&supervisor.stop),
+            kill: Arc::clone(&supervisor.kill),
+            pid: Arc::clone(&supervisor.pid),
+            stdin: Arc::clone(&supervisor.stdin),
+            run_panics: AtomicUsize::new(0),
+            crashes,
+            image: parking_lot::Mutex::new(image),
+        };
+        tokio::spawn(task.run(child, reader, supervisor_logs, exited_tx));
+        
+        Ok(supervisor)
+    }
+    
+    pub fn status(&self) -> ProcessStatus {
+        self.status.lock().clone()
+    }
+    
+    /// PID of the current run.
+    pub fn pid(&self) -> u32 {
+        self.pid.load(Ordering::SeqCst)
+    }
+    
+    /// Shared PID that follows the app across restarts.
+    pub fn pid_handle(&self) -> Arc<AtomicU32> {
+        Arc::clone(&self.pid)
+    }
+    
+    /// The current run's stdin pipe, when launched with one.
+    pub fn stdin(&self) -> Arc<tokio::sync::Mutex<Option<ChildStdin>>> {
+        Arc::clone(&self.stdin)
+    }
+    
+    /// Whether the app is running or about to be restarted.
+    pub fn is_running(&self) -> bool {
+        !*self.exited.borrow()
+    }
+    
+    /// Mark the coming exit as requested, so it is neither reported as a
+    /// crash nor restarted. Cancels a pending restart.
+    pub fn request_stop(&self) {
+        self.stop_requested.store(true, Ordering::SeqCst);
+        self.stop.notify_one();
+    }
+    
+    /// Kill the child through its handle (platforms without signals).
+    pub fn kill(&mut self) {
+        self.request_stop();
+        self.kill.notify_one();
+    }
+    
+    /// Resolves once the last run has exited and been reaped, without
+    /// borrowing this handle.
+    pub fn exited(&self) -> impl std::future::Future<Output = ()> + Send + 'static {
+        let mut exited = self.exited.clone();
+        async move {
+            let _ = exited.wait_for(|exited| *exited).await;
+        }
+    }
+    
+    /// Wait until the last run has exited and been reaped.
+    pub async fn wait(&self) -> ProcessStatus {
+        self.exited().await;
+        self.status()
+    }
+}
+
+/// State shared between a `Supervisor` handle and its task.
+struct SupervisorTask {
+    process_id: String,
+    spec: LaunchSpec,
+    logs: LogSink,
+    events: broadcast::Sender<ProcessEvent>,
+    status: Arc<parking_lot::Mutex<ProcessStatus>>,
+    stop_requested: Arc<AtomicBool>,
+    stop: Arc<Notify>,
+    kill: Arc<Notify>,
+    pid: Arc<AtomicU32>,
+    stdin: Arc<tokio::sync::Mutex<Option<ChildStdin>>>,
+    /// Panics seen in the current run, including ones past `MAX_PANICS`
+    run_panics: AtomicUsize,
+    crashes: Arc<parking_lot::Mutex<CrashHistory>>,
+    /// The current run's executable mapping, for symbolizing its crashes
+    image: parking_lot::Mutex<Option<ExecutableImage>>,
+}
+
+impl SupervisorTask {
+    async fn run(
+        self,
+        mut child: Child,
+        mut reader: JoinHandle<()>,
+        mut logs: broadcast::Receiver<LogEntry>,
+        exited: watch::Sender<bool>,
+    ) {
+        let restart = self.spec.options.restart.clone();
+        let mut backoff = restart.backoff;
+        let mut consecutive_restarts = 0;
+        
+        loop {
+            let pid = self.pid.load(Ordering::SeqCst);
+            let started = Instant::now();
+            let mut detector = PanicDetector::default();
+            self.run_panics.store(0, Ordering::SeqCst);
+            
+            let exit_status = self.watch(&mut child, &mut logs, &mut detector, pid).await;
+            let runtime = started.elapsed();
+            let exited_at = chrono::Utc::now().to_rfc3339();
+            
+            // Let the reader deliver the last lines, which may finish a panic report
+            if tokio::time::timeout(LOG_FLUSH_TIMEOUT, &mut reader).await.is_err() {
+                reader.abort();
+            }
+            self.drain_panics(&mut logs, &mut detector, pid);
+            
+            let (details, record) = self.record_exit(pid, exit_status, runtime, exited_at);
+            let _ = self.events.send(ProcessEvent::new(&self.process_id, pid, "exited", details));
+            
+            if runtime >= STABLE_RUN {
+                backoff = restart.backoff;
+                consecutive_restarts = 0;
+            }
+            
+            let wanted = match restart.policy {
+                RestartPolicy::Never => false,
+                RestartPolicy::OnFailure => self.status.lock().crashed,
+                RestartPolicy::Always => true,
+            };
+            if !wanted || self.stop_requested.load(Ordering::SeqCst) {
+                break;
+            }
+            if consecutive_restarts >= restart.max_restarts {
+                warn!("Process {} exhausted its {} restarts", self.process_id, restart.max_restarts);
+                self.logs.push(LogStream::System, format!(
+                    "[restart] run {} exited ({}); giving up after {} restarts",
+                    record.run, record.exit.as_deref().unwrap_or("exited"), restart.max_restarts
+                ));
+                break;
+            }
+            consecutive_restarts += 1;
+            
+            let record = RestartRecord { backoff_ms: backoff.as_millis(), ..record };
+            info!(
+                "Restarting process {} in {:?} (restart {} of {})",
+                self.process_id, backoff, consecutive_restarts, restart.max_restarts
+            );
+            self.logs.push(LogStream::System, format!(
+                "[restart] run {} exited ({}); restarting in {} ms (restart {} of {})",
+                record.run, record.exit.as_deref().unwrap_or("exited"), record.backoff_ms,
+                consecutive_restarts, restart.max_restarts
+            ));
+            let _ = self.events.send(ProcessEvent::new(&self.process_id, pid, "restarting", json!(record)));
+            {
+                let mut status = self.status.lock();
+                status.state = ProcessState::Restarting;
+                if status.restarts.len() == MAX_RESTART_RECORDS {
+                    status.restarts.remove(0);
+                }
+                status.restarts.push(record);
+            }
+            
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                _ = self.stop.notified() => {}
+            }
+            backoff = (backoff * 2).min(MAX_RESTART_BACKOFF);
+            if self.stop_requested.load(Ordering::SeqCst) {
+                info!("Restart of process {} cancelled by stop", self.process_id);
+                break;
+            }
+            
+            match self.relaunch().await {
+                Ok((next_child, next_reader)) => {
+                    child = next_child;
+                    reader = next_reader;
+                }
+                Err(e) => {
+                    warn!("Failed to restart process {}: {}", self.process_id, e);
+                    self.logs.push(LogStream::System, format!("[restart] failed to relaunch: {}", e));
+                    break;
+                }
+            }
+        }
+        
+        self.status.lock().state = ProcessState::Exited;
+        let _ = exited.send(true);
+    }
+    
+    /// Wait for the current run to exit, collecting panics from its stderr.
+    async fn watch(
+        &self,
+        child: &mut Child,
+        logs: &mut broadcast::Receiver<LogEntry>,
+        detector: &mut PanicDetector,
+        pid: u32,
+    ) -> std::io::Result<std::process::ExitStatus> {
+        let mut logs_open = true;
+        
+        loop {
+            tokio::select! {
+                result = child.wait() => break result,
+                line = logs.recv(), if logs_open => match line {
+                    Ok(entry) => self.feed_panic(detector, &entry, pid),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        debug!("Supervisor for {} skipped {} log lines", self.process_id, skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => logs_open = false,
+                },
+                _ = tokio::time::sleep(PANIC_IDLE_TIMEOUT), if detector.in_progress() => {
+                    if let Some(report) = detector.finish() {
+                        self.record_panic(report, pid);
+                    }
+                }
+                _ = self.kill.notified() => {
+                    if let Err(e) = child.start_kill() {
+                        warn!("Failed to kill process {}: {}", self.process_id, e);
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Finish panic reports from lines still queued after exit.
+    fn drain_panics(&self, logs: &mut broadcast::Receiver<LogEntry>, detector: &mut PanicDetector, pid: u32) {
+        loop {
+            match logs.try_recv() {
+                Ok(entry) => self.feed_panic(detector, &entry, pid),
+                Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Err(_) => break,
+            }
+        }
+        if let Some(report) = detector.finish() {
+            self.record_panic(report, pid);
+        }
+    }
+    
+    fn feed_panic(&self, detector: &mut PanicDetector, entry: &LogEntry, pid: u32) {
+        if entry.stream != LogStream::Stderr {
+            return;
+        }
+        if let Some(report) = detector.feed(&entry.line) {
+            self.record_panic(report, pid);
+        }
+    }
+    
+    fn record_panic(&self, report: PanicReport, pid: u32) {
+        warn!("Process {} panicked in thread '{}': {}", self.process_id, report.thread, report.message);
+        let _ = self.events.send(ProcessEvent::new(&self.process_id, pid, "panicked", json!(report)));
+        self.run_panics.fetch_add(1, Ordering::SeqCst);
+        let run = {
+            let mut status = self.status.lock();
+            if status.panics.len() < MAX_PANICS {
+                status.panics.push(report.clone());
+            }
+            status.run
+        };
+        let image = self.image.lock().clone();
+        self.crashes.lock().record_panic(&self.process_id, run, pid, report, image);
+    }
+    
+    /// Store how the current run ended; returns the `exited` event details
+    /// and the run's restart record.
+    fn record_exit(
+        &self,
+        pid: u32,
+        exit_status: std::io::Result<std::process::ExitStatus>,
+        runtime: Duration,
+        exited_at: String,
+    ) -> (Value, RestartRecord) {
+        let mut status = self.status.lock();
+        status.state = ProcessState::Exited;
+        status.exited_at = Some(exited_at.clone());
+        status.runtime_ms = Some(runtime.as_millis());
+        
+        match &exit_status {
+            Ok(exit_status) => {
+                status.exit_code = exit_status.code();
+                #[cfg(unix)]
+                {
+                    use std::os::unix::process::ExitStatusExt;
+                    status.signal = exit_status.signal();
+                    status.signal_name = status.signal.map(super::process::signal_name);
+                }
+            }
+            Err(e) => warn!("Failed to reap process {}: {}", self.process_id, e),
+        }
+        
+        // Panics of earlier runs stay in the report but do not make this run a crash
+        let panicked = self.run_panics.load(Ordering::SeqCst) > 0;
+        let failed = status.exit_code.is_some_and(|code| code != 0) || status.signal.is_some();
+        status.crashed = !self.stop_requested.load(Ordering::SeqCst) && (failed || panicked);
+        if status.crashed {
+            let exit = status.describe_exit().unwrap_or_else(|| "exited".to_string());
+            self.crashes.lock().record_exit(&self.process_id, status.run, pid, exit, status.signal.is_some());
+        }
+        
+        info!(
+            "Process {} (PID {}) exited: {}{}",
+            self.process_id,
+            pid,
+            status.describe_exit().unwrap_or_default(),
+            if status.crashed { " (crashed)" } else { "" }
+        );
+        
+        let record = RestartRecord {
+            run: status.run,
+            exit: status.describe_exit(),
+            exit_code: status.exit_code,
+            signal: status.signal,
+            panicked,
+            runtime_ms: runtime.as_millis(),
+            exited_at,
+            backoff_ms: 0,
+        };
+        (json!(*status), record)
+    }
+    
+    /// Spawn the next run with the original arguments and environment.
+    async fn relaunch(&self) -> Result<(Child, JoinHandle<()>)> {
+        let mut child = self.spec.spawn()?;
+        let pid = child.id()
+            .ok_or_else(|| TauriMcpError::ProcessError("Failed to get process ID".to_string()))?;
+        *self.image.lock() = ExecutableImage::of_process(pid);
+        let reader = self.logs.read(&mut child)?;
+        *self.stdin.lock().await = child.stdin.take();
+        
+        let run = {
+            let mut status = self.status.lock();
+            self.pid.store(pid, Ordering::SeqCst);
+            status.state = ProcessState::Running;
+            status.run += 1;
+            status.started_at = chrono::Utc::now().to_rfc3339();
+            status.exited_at = None;
+            status.runtime_ms = None;
+            status.exit_code = None;
+            status.signal = None;
+            status.signal_name = None;
+            status.crashed = false;
+            status.run
+        };
+        
+        info!("Process {} restarted as PID {} (run {})", self.process_id, pid, run);
+        self.logs.push(LogStream::System, format!("[restart] run {} started (PID {})", run, pid));
+        let _ = self.events.send(ProcessEvent::new(&self.process_id, pid, "started", json!({ "run": run })));
+        
+        Ok((child, reader))
+    }
+}
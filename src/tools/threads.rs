@@ -0,0 +1,328 @@
+use crate::{Result, TauriMcpError};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// gdb has to stop every thread; a wedged ptrace should not wedge the tool.
+const BACKTRACE_TIMEOUT: Duration = Duration::from_secs(30);
+/// Kernel functions a thread waiting for events sleeps in.
+const EVENT_WAITS: &[&str] = &["poll", "epoll", "select", "futex"];
+/// Frames of an idle GLib main loop.
+const MAIN_LOOP_FRAMES: &[&str] = &["g_main_context_iterate", "g_main_loop_run", "gtk_main"];
+
+/// Which debugger collects backtraces for `dump_threads`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BacktraceTool {
+    /// eu-stack if installed, else gdb
+    Auto,
+    EuStack,
+    Gdb,
+    /// `/proc` only; the process is not stopped
+    None,
+}
+
+impl BacktraceTool {
+    /// Parse the `backtrace` argument of `dump_threads`.
+    pub fn from_arguments(arguments: &Value) -> Result<Self> {
+        match arguments.get("backtrace").and_then(|v| v.as_str()) {
+            None | Some("auto") => Ok(BacktraceTool::Auto),
+            Some("eu-stack") => Ok(BacktraceTool::EuStack),
+            Some("gdb") => Ok(BacktraceTool::Gdb),
+            Some("none") => Ok(BacktraceTool::None),
+            Some(other) => Err(TauriMcpError::ProcessError(format!(
+                "Invalid backtrace: {} (expected auto, eu-stack, gdb or none)", other
+            ))),
+        }
+    }
+}
+
+/// One frame of a thread's stack, innermost first.
+#[derive(Debug, Clone, Serialize)]
+pub struct StackFrame {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub function: String,
+    /// `file:line`, or the shared object the function is in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreadDump {
+    pub tid: u32,
+    pub name: String,
+    /// `R`, `S`, `D`, `T`, ... as in `/proc/<pid>/task/<tid>/stat`
+    pub state: String,
+    pub state_description: &'static str,
+    /// Kernel function the thread is sleeping in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wchan: Option<String>,
+    /// User plus system CPU time
+    pub cpu_ms: u64,
+    /// The process's first thread, which runs the GTK main loop
+    pub main: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub frames: Vec<StackFrame>,
+    /// One line: state, wait and innermost frames
+    pub summary: String,
+}
+
+impl ThreadDump {
+    fn waiting_for_events(&self) -> bool {
+        self.state == "S" && self.wchan.as_deref().is_some_and(|wchan| EVENT_WAITS.iter().any(|wait| wchan.contains(wait)))
+    }
+    
+    fn in_main_loop(&self) -> bool {
+        self.frames.iter().any(|frame| MAIN_LOOP_FRAMES.iter().any(|name| frame.function.starts_with(name)))
+    }
+    
+    /// What the main thread's state says about a hang.
+    fn main_thread_assessment(&self) -> &'static str {
+        match self.state.as_str() {
+            "R" => "running: the main loop is busy, e.g. a long computation on the UI thread",
+            "D" => "blocked in uninterruptible I/O: the UI cannot respond until the kernel call returns",
+            "T" | "t" => "stopped by a signal or debugger",
+            "Z" | "X" => "exited",
+            _ if self.in_main_loop() => "idle in the GLib main loop: the UI thread is responsive, look at the other threads",
+            _ if self.frames.is_empty() && self.waiting_for_events() => "waiting for events, probably in the main loop",
+            _ if self.waiting_for_events() => "waiting outside the main loop: likely blocked on a lock, channel or synchronous call",
+            _ => "sleeping outside the main loop: the UI thread is likely blocked",
+        }
+    }
+}
+
+fn state_description(state: &str) -> &'static str {
+    match state {
+        "R" => "running",
+        "S" => "sleeping",
+        "D" => "uninterruptible sleep",
+        "T" => "stopped",
+        "t" => "tracing stop",
+        "Z" => "zombie",
+        "X" => "dead",
+        "I" => "idle",
+        _ => "unknown",
+    }
+}
+
+/// Dump every thread of `pid`: state and wait channel from `/proc`, plus
+/// backtraces when a debugger is available.
+#[cfg(target_os = "linux")]
+pub async fn dump_threads(pid: u32, tool: BacktraceTool) -> Result<Value> {
+    let mut threads = proc::threads(pid)?;
+    
+    let mut backtrace = serde_json::json!({ "tool": "none" });
+    if tool != BacktraceTool::None {
+        match backtraces(pid, tool).await {
+            Ok((used, mut stacks)) => {
+                backtrace["tool"] = serde_json::json!(used);
+                for thread in &mut threads {
+                    thread.frames = stacks.remove(&thread.tid).unwrap_or_default();
+                }
+            }
+            Err(e) => backtrace["error"] = serde_json::json!(e.to_string()),
+        }
+    }
+    
+    let mut states: HashMap<&str, usize> = HashMap::new();
+    for thread in &mut threads {
+        thread.summary = summarize(thread);
+        *states.entry(thread.state_description).or_default() += 1;
+    }
+    
+    let main_thread = threads.iter().find(|thread| thread.main).map(|main| serde_json::json!({
+        "tid": main.tid,
+        "summary": main.summary,
+        "assessment": main.main_thread_assessment(),
+    }));
+    
+    Ok(serde_json::json!({
+        "pid": pid,
+        "thread_count": threads.len(),
+        "states": states,
+        "main_thread": main_thread,
+        "backtrace": backtrace,
+        "threads": threads,
+    }))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub async fn dump_threads(_pid: u32, _tool: BacktraceTool) -> Result<Value> {
+    Err(TauriMcpError::ProcessError("dump_threads requires Linux".to_string()))
+}
+
+fn summarize(thread: &ThreadDump) -> String {
+    let mut summary = format!("{} ({})", thread.name, thread.state_description);
+    if let Some(wchan) = &thread.wchan {
+        summary.push_str(&format!(" in {}", wchan));
+    }
+    let top: Vec<&str> = thread.frames.iter().take(3).map(|frame| frame.function.as_str()).collect();
+    if !top.is_empty() {
+        summary.push_str(&format!(": {}", top.join(" < ")));
+    }
+    summary
+}
+
+/// Backtraces by thread id, and the tool that produced them.
+#[cfg(target_os = "linux")]
+async fn backtraces(pid: u32, tool: BacktraceTool) -> Result<(BacktraceTool, HashMap<u32, Vec<StackFrame>>)> {
+    let candidates: &[BacktraceTool] = match tool {
+        BacktraceTool::Auto => &[BacktraceTool::EuStack, BacktraceTool::Gdb],
+        BacktraceTool::EuStack => &[BacktraceTool::EuStack],
+        BacktraceTool::Gdb => &[BacktraceTool::Gdb],
+        BacktraceTool::None => &[],
+    };
+    
+    for candidate in candidates {
+        let pid = pid.to_string();
+        let (program, args): (&str, Vec<&str>) = match candidate {
+            BacktraceTool::EuStack => ("eu-stack", vec!["-p", &pid]),
+            _ => ("gdb", vec!["-p", &pid, "-batch", "-nx", "-ex", "set pagination off", "-ex", "thread apply all bt"]),
+        };
+        
+        let output = tokio::process::Command::new(program)
+            .args(&args)
+            .stdin(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .output();
+        let output = match tokio::time::timeout(BACKTRACE_TIMEOUT, output).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Ok(Err(e)) => return Err(TauriMcpError::ProcessError(format!("Failed to run {}: {}", program, e))),
+            Err(_) => return Err(TauriMcpError::ProcessError(format!(
+                "{} did not finish within {} s", program, BACKTRACE_TIMEOUT.as_secs()
+            ))),
+        };
+        
+        let stacks = parse_backtraces(&String::from_utf8_lossy(&output.stdout));
+        if stacks.is_empty() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr.lines().rev().find(|line| !line.trim().is_empty()).unwrap_or("no output");
+            return Err(TauriMcpError::ProcessError(format!(
+                "{} could not read the stacks: {}. Attaching needs ptrace permission (kernel.yama.ptrace_scope=0 or CAP_SYS_PTRACE)",
+                program, reason.trim()
+            )));
+        }
+        return Ok((*candidate, stacks));
+    }
+    
+    Err(TauriMcpError::ProcessError(
+        "No backtrace tool found; install elfutils (eu-stack) or gdb".to_string()
+    ))
+}
+
+/// Split gdb `thread apply all bt` or eu-stack output into stacks by thread id.
+pub fn parse_backtraces(output: &str) -> HashMap<u32, Vec<StackFrame>> {
+    let mut stacks: HashMap<u32, Vec<StackFrame>> = HashMap::new();
+    let mut current = None;
+    
+    for line in output.lines() {
+        let line = line.trim_end();
+        if let Some(tid) = thread_header(line) {
+            current = Some(tid);
+            stacks.entry(tid).or_default();
+        } else if let (Some(tid), Some(frame)) = (current, parse_frame(line)) {
+            stacks.entry(tid).or_default().push(frame);
+        }
+    }
+    stacks
+}
+
+/// `Thread 2 (Thread 0x7f.. (LWP 1235) "name"):` from gdb, `TID 1235:` from eu-stack.
+fn thread_header(line: &str) -> Option<u32> {
+    if let Some(rest) = line.strip_prefix("TID ") {
+        return rest.strip_suffix(':')?.trim().parse().ok();
+    }
+    if line.starts_with("Thread ") {
+        let lwp = line.split("(LWP ").nth(1)?;
+        return lwp.split(')').next()?.trim().parse().ok();
+    }
+    None
+}
+
+/// `#0  0x00007f.. in poll (fds=..) at poll.c:29` (gdb) or
+/// `#0  0x00007f.. __poll` (eu-stack).
+fn parse_frame(line: &str) -> Option<StackFrame> {
+    let rest = line.strip_prefix('#')?;
+    let (_, rest) = rest.split_once(char::is_whitespace)?;
+    let mut rest = rest.trim_start();
+    
+    let mut address = None;
+    if rest.starts_with("0x") {
+        let (addr, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        address = Some(addr.to_string());
+        rest = tail.trim_start();
+        rest = rest.strip_prefix("in ").unwrap_or(rest);
+    }
+    
+    // gdb appends ` at file:line` or ` from library` after the arguments
+    let (call, location) = match rest.rfind(" at ").or_else(|| rest.rfind(" from ")) {
+        Some(index) if rest[..index].ends_with(')') => {
+            let location = rest[index..].trim_start_matches(" at ").trim_start_matches(" from ");
+            (&rest[..index], Some(location.to_string()))
+        }
+        _ => (rest, None),
+    };
+    // Drop gdb's argument list, keeping generics like `foo<(A, B)>` intact
+    let function = match call.find(" (") {
+        Some(index) => &call[..index],
+        None => call,
+    };
+    let function = function.trim();
+    
+    Some(StackFrame {
+        address,
+        function: if function.is_empty() { "??".to_string() } else { function.to_string() },
+        location,
+    })
+}
+
+#[cfg(target_os = "linux")]
+mod proc {
+    use super::*;
+    
+    /// `/proc` reports CPU times in USER_HZ ticks, 100 per second on Linux.
+    const TICKS_PER_SECOND: u64 = 100;
+    
+    /// All threads of `pid`, main thread first.
+    pub fn threads(pid: u32) -> Result<Vec<ThreadDump>> {
+        let tasks = std::fs::read_dir(format!("/proc/{}/task", pid))
+            .map_err(|e| TauriMcpError::ProcessError(format!("Cannot read threads of {}: {}", pid, e)))?;
+        
+        let mut threads: Vec<ThreadDump> = tasks
+            .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+            .filter_map(|tid| thread(pid, tid))
+            .collect();
+        threads.sort_by_key(|thread| (!thread.main, thread.tid));
+        Ok(threads)
+    }
+    
+    fn thread(pid: u32, tid: u32) -> Option<ThreadDump> {
+        let dir = format!("/proc/{}/task/{}", pid, tid);
+        let stat = std::fs::read_to_string(format!("{}/stat", dir)).ok()?;
+        // The name in parentheses may itself contain spaces and parentheses
+        let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+        let state = fields.first()?.to_string();
+        let ticks = |index: usize| fields.get(index).and_then(|v| v.parse::<u64>().ok()).unwrap_or(0);
+        let cpu_ms = (ticks(11) + ticks(12)) * 1000 / TICKS_PER_SECOND;
+        
+        let name = std::fs::read_to_string(format!("{}/comm", dir)).ok()?.trim_end().to_string();
+        let wchan = std::fs::read_to_string(format!("{}/wchan", dir))
+            .ok()
+            .filter(|wchan| !wchan.is_empty() && wchan != "0");
+        
+        Some(ThreadDump {
+            tid,
+            name,
+            state_description: state_description(&state),
+            state,
+            wchan,
+            cpu_ms,
+            main: tid == pid,
+            frames: Vec::new(),
+            summary: String::new(),
+        })
+    }
+}
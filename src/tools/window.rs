@@ -5,7 +5,7 @@ use screenshots::Screen;
 use serde_json::Value;
 use std::io::Cursor;
 use std::path::PathBuf;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 #[cfg(target_os = "macos")]
 use cocoa::base::{id, nil};
@@ -22,11 +22,15 @@ use windows::Win32::Foundation::{HWND, RECT};
 use windows::Win32::UI::WindowsAndMessaging::{GetWindowRect, GetWindowText, GetWindowTextLengthW};
 
 #[cfg(target_os = "linux")]
-use x11::xlib;
+use crate::utils::x11::XConnection;
+#[cfg(target_os = "linux")]
+use parking_lot::Mutex;
 
 pub struct WindowManager {
+    /// Opened on first use and reopened if the X server goes away, so the
+    /// server can start without a display.
     #[cfg(target_os = "linux")]
-    display: *mut xlib::Display,
+    display: Mutex<Option<XConnection>>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -44,28 +48,62 @@ impl WindowManager {
     pub fn new() -> Self {
         #[cfg(target_os = "linux")]
         {
-            let display = unsafe { xlib::XOpenDisplay(std::ptr::null()) };
-            if display.is_null() {
-                panic!("Failed to open X11 display");
-            }
-            Self { display }
+            Self { display: Mutex::new(None) }
         }
         
         #[cfg(not(target_os = "linux"))]
         Self {}
     }
     
-    pub async fn take_screenshot(&self, process_id: &str, output_path: Option<PathBuf>) -> Result<String> {
-        info!("Taking screenshot for process: {}", process_id);
+    /// Run `f` with a live X11 connection, connecting or reconnecting as needed.
+    #[cfg(target_os = "linux")]
+    pub fn with_display<R>(&self, f: impl FnOnce(&XConnection) -> R) -> Result<R> {
+        let mut display = self.display.lock();
         
-        let screens = Screen::all().map_err(|e| TauriMcpError::ScreenshotError(e.to_string()))?;
+        if let Some(connection) = display.as_ref() {
+            if !connection.is_alive() {
+                warn!("Lost connection to X11 display {}, reconnecting", connection.name());
+                *display = None;
+            }
+        }
         
-        if screens.is_empty() {
-            return Err(TauriMcpError::ScreenshotError("No screens found".to_string()));
+        if display.is_none() {
+            *display = Some(XConnection::open(None)?);
         }
         
-        let screen = &screens[0];
-        let image = screen.capture().map_err(|e| TauriMcpError::ScreenshotError(e.to_string()))?;
+        Ok(f(display.as_ref().expect("display connected above")))
+    }
+    
+    /// Fail with a `WindowError` when no display is available.
+    pub fn require_display(&self) -> Result<()> {
+        #[cfg(target_os = "linux")]
+        {
+            self.with_display(|_| ())
+        }
+        
+        #[cfg(not(target_os = "linux"))]
+        Ok(())
+    }
+    
+    pub fn has_display(&self) -> bool {
+        self.require_display().is_ok()
+    }
+    
+    /// Take a screenshot, from the process's private display when it has one.
+    pub async fn take_screenshot(&self, process_id: &str, output_path: Option<PathBuf>, display: Option<&str>) -> Result<String> {
+        info!("Taking screenshot for process: {}", process_id);
+        
+        #[cfg(target_os = "linux")]
+        let image = match display {
+            Some(name) => XConnection::open(Some(name))?.capture_root()?,
+            None => self.capture_default_screen()?,
+        };
+        
+        #[cfg(not(target_os = "linux"))]
+        let image = {
+            let _ = display;
+            self.capture_default_screen()?
+        };
         
         if let Some(path) = output_path {
             image.save(&path).map_err(|e| TauriMcpError::ScreenshotError(e.to_string()))?;
@@ -81,9 +119,27 @@ impl WindowManager {
         }
     }
     
-    pub async fn get_window_info(&self, process_id: &str) -> Result<Value> {
+    fn capture_default_screen(&self) -> Result<image::RgbaImage> {
+        self.require_display()?;
+        
+        let screens = Screen::all().map_err(|e| TauriMcpError::ScreenshotError(e.to_string()))?;
+        
+        if screens.is_empty() {
+            return Err(TauriMcpError::ScreenshotError("No screens found".to_string()));
+        }
+        
+        screens[0].capture().map_err(|e| TauriMcpError::ScreenshotError(e.to_string()))
+    }
+    
+    pub async fn get_window_info(&self, process_id: &str, display: Option<&str>) -> Result<Value> {
         info!("Getting window info for process: {}", process_id);
         
+        match display {
+            #[cfg(target_os = "linux")]
+            Some(name) => drop(XConnection::open(Some(name))?),
+            _ => self.require_display()?,
+        }
+        
         #[cfg(target_os = "macos")]
         {
             self.get_window_info_macos(process_id).await
@@ -175,13 +231,4 @@ impl WindowManager {
         info!("Moving window for process: {} to ({}, {})", process_id, x, y);
         Ok(())
     }
-}
-
-#[cfg(target_os = "linux")]
-impl Drop for WindowManager {
-    fn drop(&mut self) {
-        unsafe {
-            xlib::XCloseDisplay(self.display);
-        }
-    }
 }
\ No newline at end of file
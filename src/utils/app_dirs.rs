@@ -0,0 +1,148 @@
+use crate::{Result, TauriMcpError};
+use serde::Serialize;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// The user's base directories, as Tauri's path resolver finds them.
+#[derive(Debug, Clone)]
+pub struct BaseDirs {
+    pub config: PathBuf,
+    pub data: PathBuf,
+    pub local_data: PathBuf,
+    pub cache: PathBuf,
+    /// Holds `<identifier>` log directories on macOS; elsewhere logs live
+    /// under the app's local data directory
+    #[cfg(target_os = "macos")]
+    pub logs: PathBuf,
+}
+
+impl BaseDirs {
+    /// Base directories of this process's environment.
+    pub fn from_env() -> Option<Self> {
+        Self::with_env(|name| std::env::var_os(name))
+    }
+    
+    /// Base directories of an environment with different variables, such as
+    /// an app launched with its own `HOME`.
+    pub fn with_env(var: impl Fn(&str) -> Option<OsString>) -> Option<Self> {
+        #[cfg(target_os = "linux")]
+        {
+            let home = home_dir(&var);
+            let xdg_dir = |variable: &str, default: &str| {
+                var(variable)
+                    .map(PathBuf::from)
+                    .filter(|dir| dir.is_absolute())
+                    .or_else(|| home.as_ref().map(|home| home.join(default)))
+            };
+            let data = xdg_dir("XDG_DATA_HOME", ".local/share")?;
+            Some(Self {
+                config: xdg_dir("XDG_CONFIG_HOME", ".config")?,
+                local_data: data.clone(),
+                data,
+                cache: xdg_dir("XDG_CACHE_HOME", ".cache")?,
+            })
+        }
+        #[cfg(target_os = "macos")]
+        {
+            let library = home_dir(&var)?.join("Library");
+            let support = library.join("Application Support");
+            Some(Self {
+                config: support.clone(),
+                data: support.clone(),
+                local_data: support,
+                cache: library.join("Caches"),
+                logs: library.join("Logs"),
+            })
+        }
+        #[cfg(target_os = "windows")]
+        {
+            let roaming = var("APPDATA").map(PathBuf::from)?;
+            let local = var("LOCALAPPDATA").map(PathBuf::from)?;
+            Some(Self {
+                config: roaming.clone(),
+                data: roaming,
+                local_data: local.clone(),
+                cache: local,
+            })
+        }
+    }
+}
+
+/// Where a Tauri app keeps its files: `app_config_dir()`, `app_data_dir()`
+/// and friends of its path resolver.
+#[derive(Debug, Clone, Serialize)]
+pub struct AppDirs {
+    pub identifier: String,
+    pub config: PathBuf,
+    pub data: PathBuf,
+    pub local_data: PathBuf,
+    pub cache: PathBuf,
+    pub log: PathBuf,
+}
+
+impl AppDirs {
+    pub fn new(identifier: &str, base: &BaseDirs) -> Result<Self> {
+        // The identifier becomes a path component; anything else would point
+        // at (and let `reset` delete) the base directory or outside it
+        let component = Path::new(identifier).components().collect::<Vec<_>>();
+        if !matches!(component.as_slice(), [std::path::Component::Normal(_)]) {
+            return Err(TauriMcpError::Other(format!("Invalid app identifier: {:?}", identifier)));
+        }
+        
+        #[cfg(target_os = "macos")]
+        let log = base.logs.join(identifier);
+        #[cfg(not(target_os = "macos"))]
+        let log = base.local_data.join(identifier).join("logs");
+        
+        Ok(Self {
+            identifier: identifier.to_string(),
+            config: base.config.join(identifier),
+            data: base.data.join(identifier),
+            local_data: base.local_data.join(identifier),
+            cache: base.cache.join(identifier),
+            log,
+        })
+    }
+    
+    /// Every directory by name, in resolver order.
+    pub fn all(&self) -> [(&'static str, &Path); 5] {
+        [
+            ("config", &self.config),
+            ("data", &self.data),
+            ("local_data", &self.local_data),
+            ("cache", &self.cache),
+            ("log", &self.log),
+        ]
+    }
+    
+    /// The directories that hold all the others, by their first name, e.g.
+    /// only `data` on Linux where local data is the same directory and logs
+    /// live inside it.
+    pub fn roots(&self) -> Vec<(&'static str, &Path)> {
+        let all = self.all();
+        all.iter()
+            .enumerate()
+            .filter(|(i, (_, dir))| {
+                !all.iter().enumerate().any(|(j, (_, other))| {
+                    (dir.starts_with(other) && dir != other) || (dir == other && j < *i)
+                })
+            })
+            .map(|(_, entry)| *entry)
+            .collect()
+    }
+    
+    /// The directory called `name`, as named in `all`.
+    pub fn named(&self, name: &str) -> Result<(&'static str, &Path)> {
+        self.all()
+            .into_iter()
+            .find(|(dir_name, _)| *dir_name == name)
+            .ok_or_else(|| TauriMcpError::Other(format!(
+                "Unknown app directory: {} (expected config, data, local_data, cache or log)", name
+            )))
+    }
+}
+
+#[cfg(unix)]
+fn home_dir(var: &impl Fn(&str) -> Option<OsString>) -> Option<PathBuf> {
+    var("HOME").filter(|home| !home.is_empty()).map(PathBuf::from)
+}
@@ -0,0 +1,127 @@
+use crate::{Result, TauriMcpError};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// How to set up a throwaway profile for `launch_app`.
+#[derive(Debug, Clone, Default)]
+pub struct IsolationOptions {
+    /// Copied into the new home directory, laid out like one
+    /// (`.local/share/<identifier>/...`, `.config/...`)
+    pub fixture: Option<PathBuf>,
+    /// Leave the directories in place when the app is stopped
+    pub keep: bool,
+}
+
+impl IsolationOptions {
+    /// Parse `isolated`, `fixture_dir` and `keep_isolated` tool arguments.
+    pub fn from_arguments(arguments: &Value) -> Result<Option<Self>> {
+        if !arguments.get("isolated").and_then(|v| v.as_bool()).unwrap_or(false) {
+            return Ok(None);
+        }
+        
+        let fixture = arguments.get("fixture_dir").and_then(|v| v.as_str()).map(PathBuf::from);
+        if let Some(fixture) = &fixture {
+            if !fixture.is_dir() {
+                return Err(TauriMcpError::ProcessError(format!("Fixture directory does not exist: {}", fixture.display())));
+            }
+        }
+        
+        Ok(Some(Self {
+            fixture,
+            keep: arguments.get("keep_isolated").and_then(|v| v.as_bool()).unwrap_or(false),
+        }))
+    }
+}
+
+/// A temporary home directory with its own XDG config, data and cache
+/// directories, so the app's data dir, WebKit localStorage and IndexedDB
+/// start out empty (or as the fixture left them).
+#[derive(Debug, Clone, Serialize)]
+pub struct IsolatedProfile {
+    pub root: PathBuf,
+    pub home: PathBuf,
+    pub config_dir: PathBuf,
+    pub data_dir: PathBuf,
+    pub cache_dir: PathBuf,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixture: Option<PathBuf>,
+    pub keep: bool,
+}
+
+impl IsolatedProfile {
+    pub fn create(process_id: &str, options: &IsolationOptions) -> Result<Self> {
+        // Aliases are free-form, so they do not name the directory
+        let root = std::env::temp_dir().join(format!("tauri-mcp-isolated-{}", Uuid::new_v4()));
+        let home = root.join("home");
+        let profile = Self {
+            config_dir: home.join(".config"),
+            data_dir: home.join(".local/share"),
+            cache_dir: home.join(".cache"),
+            home,
+            root,
+            fixture: options.fixture.clone(),
+            keep: options.keep,
+        };
+        let populate = || -> Result<()> {
+            for dir in [&profile.config_dir, &profile.data_dir, &profile.cache_dir] {
+                std::fs::create_dir_all(dir)?;
+            }
+            if let Some(fixture) = &options.fixture {
+                copy_dir(fixture, &profile.home)?;
+            }
+            Ok(())
+        };
+        if let Err(e) = populate() {
+            let _ = std::fs::remove_dir_all(&profile.root);
+            return Err(e);
+        }
+        
+        info!("Created isolated profile for {} at {}", process_id, profile.root.display());
+        Ok(profile)
+    }
+    
+    /// Variables pointing the app at this profile.
+    pub fn env(&self) -> [(&'static str, &Path); 4] {
+        [
+            ("HOME", &self.home),
+            ("XDG_CONFIG_HOME", &self.config_dir),
+            ("XDG_DATA_HOME", &self.data_dir),
+            ("XDG_CACHE_HOME", &self.cache_dir),
+        ]
+    }
+    
+    /// Delete the profile unless it is kept for inspection.
+    pub fn cleanup(&self) {
+        if self.keep {
+            info!("Keeping isolated profile at {}", self.root.display());
+            return;
+        }
+        if let Err(e) = std::fs::remove_dir_all(&self.root) {
+            warn!("Failed to remove isolated profile {}: {}", self.root.display(), e);
+        }
+    }
+}
+
+/// Copy the contents of `from` into `to`, recreating symlinks as symlinks.
+pub(crate) fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(std::fs::read_link(entry.path())?, &target)?;
+            #[cfg(not(unix))]
+            std::fs::copy(entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
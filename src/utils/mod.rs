@@ -1,2 +1,9 @@
+pub mod app_dirs;
+pub mod isolation;
 pub mod screenshot;
-pub mod platform;
\ No newline at end of file
+pub mod platform;
+pub mod websocket;
+#[cfg(target_os = "linux")]
+pub mod x11;
+#[cfg(target_os = "linux")]
+pub mod xvfb;
\ No newline at end of file
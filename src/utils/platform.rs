@@ -56,9 +56,21 @@ pub fn get_window_by_pid(pid: u32) -> Result<Option<u64>> {
 }
 
 pub fn is_tauri_app(path: &str) -> bool {
+    if crate::tools::build::TauriProject::detect(std::path::Path::new(path)).is_some() {
+        return true;
+    }
+    
     path.contains("tauri") || path.ends_with(".app") || path.ends_with(".exe") || path.ends_with(".AppImage")
 }
 
+/// The Tauri project an executable was built from, found by walking up from
+/// its `target/<profile>/` directory.
+pub fn project_for_executable(executable: &std::path::Path) -> Option<crate::tools::build::TauriProject> {
+    executable.ancestors()
+        .filter(|dir| dir.file_name().is_some_and(|name| name == "target"))
+        .find_map(|target| target.parent().and_then(crate::tools::build::TauriProject::detect))
+}
+
 pub fn find_tauri_apps_in_directory(dir: &std::path::Path) -> Result<Vec<std::path::PathBuf>> {
     let mut apps = Vec::new();
     
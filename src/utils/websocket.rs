@@ -0,0 +1,138 @@
+use crate::{Result, TauriMcpError};
+use base64::{Engine as _, engine::general_purpose};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xa;
+
+/// Largest control frame payload allowed by RFC 6455.
+const MAX_CONTROL_PAYLOAD: u64 = 125;
+
+/// A client websocket over plain TCP, with just enough of RFC 6455 for
+/// local endpoints such as Chromium DevTools.
+pub struct WebSocket {
+    stream: BufReader<TcpStream>,
+    max_message: usize,
+}
+
+impl WebSocket {
+    /// Connect to a `ws://` URL, rejecting any message from the server
+    /// longer than `max_message` bytes once reassembled.
+    pub async fn connect(url: &str, max_message: usize) -> Result<Self> {
+        let address = url.strip_prefix("ws://")
+            .ok_or_else(|| TauriMcpError::WebDriverError(format!("Unsupported websocket URL: {}", url)))?;
+        let (authority, path) = match address.split_once('/') {
+            Some((authority, path)) => (authority, format!("/{}", path)),
+            None => (address, "/".to_string()),
+        };
+        
+        let mut stream = BufReader::new(TcpStream::connect(authority).await?);
+        let key = general_purpose::STANDARD.encode(uuid::Uuid::new_v4().as_bytes());
+        let handshake = format!(
+            "GET {} HTTP/1.1\r\nHost: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {}\r\nSec-WebSocket-Version: 13\r\n\r\n",
+            path, authority, key
+        );
+        stream.get_mut().write_all(handshake.as_bytes()).await?;
+        
+        let mut status = String::new();
+        stream.read_line(&mut status).await?;
+        if !status.contains(" 101 ") {
+            return Err(TauriMcpError::WebDriverError(format!("Websocket upgrade refused: {}", status.trim())));
+        }
+        loop {
+            let mut header = String::new();
+            if stream.read_line(&mut header).await? == 0 || header == "\r\n" {
+                break;
+            }
+        }
+        
+        Ok(Self { stream, max_message })
+    }
+    
+    /// Send a text message.
+    pub async fn send_text(&mut self, message: &str) -> Result<()> {
+        self.send_frame(OPCODE_TEXT, message.as_bytes()).await
+    }
+    
+    /// Read the next text or binary message, answering pings on the way.
+    pub async fn read_message(&mut self) -> Result<Vec<u8>> {
+        let mut message = Vec::new();
+        loop {
+            let mut header = [0u8; 2];
+            self.stream.read_exact(&mut header).await?;
+            let fin = header[0] & 0x80 != 0;
+            let opcode = header[0] & 0x0f;
+            let mut len = (header[1] & 0x7f) as u64;
+            if len == 126 {
+                len = self.stream.read_u16().await? as u64;
+            } else if len == 127 {
+                len = self.stream.read_u64().await?;
+            }
+            
+            let is_control = opcode & 0x8 != 0;
+            let limit = if is_control {
+                MAX_CONTROL_PAYLOAD
+            } else {
+                // Continuation frames count towards the same message
+                (self.max_message - message.len()) as u64
+            };
+            if len > limit {
+                return Err(TauriMcpError::WebDriverError(format!(
+                    "Websocket message too large: over {} bytes",
+                    if is_control { MAX_CONTROL_PAYLOAD as usize } else { self.max_message }
+                )));
+            }
+            
+            let mask = if header[1] & 0x80 != 0 {
+                let mut mask = [0u8; 4];
+                self.stream.read_exact(&mut mask).await?;
+                Some(mask)
+            } else {
+                None
+            };
+            let mut payload = vec![0u8; len as usize];
+            self.stream.read_exact(&mut payload).await?;
+            if let Some(mask) = mask {
+                payload.iter_mut().enumerate().for_each(|(i, byte)| *byte ^= mask[i % 4]);
+            }
+            
+            match opcode {
+                OPCODE_CONTINUATION | OPCODE_TEXT | OPCODE_BINARY => {
+                    message.extend_from_slice(&payload);
+                    if fin {
+                        return Ok(message);
+                    }
+                }
+                OPCODE_PING => self.send_frame(OPCODE_PONG, &payload).await?,
+                OPCODE_CLOSE => return Err(TauriMcpError::WebDriverError("Websocket closed by the server".to_string())),
+                _ => {}
+            }
+        }
+    }
+    
+    async fn send_frame(&mut self, opcode: u8, payload: &[u8]) -> Result<()> {
+        // Client frames must be masked
+        let mask: [u8; 4] = uuid::Uuid::new_v4().as_bytes()[..4].try_into().expect("16 byte UUID");
+        let mut frame = vec![0x80 | opcode];
+        match payload.len() {
+            len if len < 126 => frame.push(0x80 | len as u8),
+            len if len <= u16::MAX as usize => {
+                frame.push(0x80 | 126);
+                frame.extend_from_slice(&(len as u16).to_be_bytes());
+            }
+            len => {
+                frame.push(0x80 | 127);
+                frame.extend_from_slice(&(len as u64).to_be_bytes());
+            }
+        }
+        frame.extend_from_slice(&mask);
+        frame.extend(payload.iter().enumerate().map(|(i, byte)| byte ^ mask[i % 4]));
+        self.stream.get_mut().write_all(&frame).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{Result, TauriMcpError};
+use image::RgbaImage;
+use serde::Serialize;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_uchar, c_ulong};
+use std::os::fd::BorrowedFd;
+use std::sync::Once;
+use tracing::debug;
+use x11::xlib;
+
+static ERROR_HANDLER: Once = Once::new();
+
+/// Xlib's default error handler exits the process on any protocol error
+/// (e.g. BadWindow when a window disappears mid-query). Log instead.
+unsafe extern "C" fn log_x_error(_display: *mut xlib::Display, event: *mut xlib::XErrorEvent) -> i32 {
+    let event = &*event;
+    debug!(
+        "X11 error: code {} (request {}.{}) on resource {:#x}",
+        event.error_code, event.request_code, event.minor_code, event.resourceid
+    );
+    0
+}
+
+/// How deep below the root to look for client windows; window managers
+/// reparent clients into one or two levels of frame windows.
+const MAX_WINDOW_DEPTH: usize = 3;
+
+/// A client window that advertises its owning process through `_NET_WM_PID`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClientWindow {
+    pub id: xlib::Window,
+    pub pid: u32,
+    pub title: Option<String>,
+    pub mapped: bool,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// An owned Xlib connection.
+pub struct XConnection {
+    display: *mut xlib::Display,
+    name: String,
+}
+
+// SAFETY: the connection is only ever used by one thread at a time; callers
+// keep it behind a mutex.
+unsafe impl Send for XConnection {}
+
+impl XConnection {
+    /// Open a display by name (`":99"`), or the one named by `DISPLAY`.
+    pub fn open(name: Option<&str>) -> Result<Self> {
+        ERROR_HANDLER.call_once(|| unsafe {
+            xlib::XSetErrorHandler(Some(log_x_error));
+        });
+        
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => std::env::var("DISPLAY").unwrap_or_default(),
+        };
+        if name.is_empty() {
+            return Err(TauriMcpError::WindowError(
+                "No X11 display available: DISPLAY is not set. Start an X server or Xvfb and set DISPLAY to use screenshot, window and input tools".to_string()
+            ));
+        }
+        
+        let c_name = CString::new(name.clone())
+            .map_err(|_| TauriMcpError::WindowError(format!("Invalid display name: {}", name)))?;
+        let display = unsafe { xlib::XOpenDisplay(c_name.as_ptr()) };
+        if display.is_null() {
+            return Err(TauriMcpError::WindowError(format!(
+                "Failed to open X11 display {}: check that the X server is running and accepts connections",
+                name
+            )));
+        }
+        
+        debug!("Opened X11 display {}", name);
+        Ok(Self { display, name })
+    }
+    
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    
+    pub fn raw(&self) -> *mut xlib::Display {
+        self.display
+    }
+    
+    /// Whether the server side of the connection is still there.
+    ///
+    /// Xlib exits the process on I/O errors, so a dead connection must be
+    /// detected before issuing any request on it.
+    pub fn is_alive(&self) -> bool {
+        use nix::poll::{poll, PollFd, PollFlags};
+        
+        let fd = unsafe { xlib::XConnectionNumber(self.display) };
+        let fd = unsafe { BorrowedFd::borrow_raw(fd) };
+        let mut fds = [PollFd::new(&fd, PollFlags::POLLIN)];
+        
+        match poll(&mut fds, 0) {
+            Ok(_) => {
+                let revents = fds[0].revents().unwrap_or(PollFlags::empty());
+                !revents.intersects(PollFlags::POLLHUP | PollFlags::POLLERR | PollFlags::POLLNVAL)
+            }
+            Err(_) => false,
+        }
+    }
+    
+    /// Capture the whole root window of the default screen.
+    pub fn capture_root(&self) -> Result<RgbaImage> {
+        unsafe {
+            let screen = xlib::XDefaultScreen(self.display);
+            let root = xlib::XRootWindow(self.display, screen);
+            let width = xlib::XDisplayWidth(self.display, screen) as u32;
+            let height = xlib::XDisplayHeight(self.display, screen) as u32;
+            
+            let image = xlib::XGetImage(self.display, root, 0, 0, width, height, xlib::XAllPlanes(), xlib::ZPixmap);
+            if image.is_null() {
+                return Err(TauriMcpError::ScreenshotError(format!("Failed to capture display {}", self.name)));
+            }
+            
+            let (red_mask, green_mask, blue_mask) = ((*image).red_mask, (*image).green_mask, (*image).blue_mask);
+            let channel = |pixel: u64, mask: u64| -> u8 {
+                if mask == 0 {
+                    return 0;
+                }
+                let value = (pixel & mask) >> mask.trailing_zeros();
+                let max = mask >> mask.trailing_zeros();
+                (value * 255 / max) as u8
+            };
+            
+            let mut rgba = RgbaImage::new(width, height);
+            for y in 0..height {
+                for x in 0..width {
+                    let pixel = xlib::XGetPixel(image, x as i32, y as i32);
+                    rgba.put_pixel(x, y, image::Rgba([
+                        channel(pixel, red_mask),
+                        channel(pixel, green_mask),
+                        channel(pixel, blue_mask),
+                        255,
+                    ]));
+                }
+            }
+            
+            xlib::XDestroyImage(image);
+            Ok(rgba)
+        }
+    }
+}
+
+impl XConnection {
+    /// All client windows on the display that carry a `_NET_WM_PID`.
+    pub fn client_windows(&self) -> Vec<ClientWindow> {
+        let mut windows = Vec::new();
+        
+        unsafe {
+            let pid_atom = self.atom(b"_NET_WM_PID\0", true);
+            if pid_atom == 0 {
+                // No client has ever set the property on this server
+                return windows;
+            }
+            
+            let root = xlib::XDefaultRootWindow(self.display);
+            let mut stack = vec![(root, 0)];
+            
+            while let Some((window, depth)) = stack.pop() {
+                if depth > 0 {
+                    if let Some(pid) = self.cardinal_property(window, pid_atom) {
+                        if let Some(client) = self.describe_window(window, root, pid) {
+                            windows.push(client);
+                        }
+                        continue;
+                    }
+                }
+                
+                if depth < MAX_WINDOW_DEPTH {
+                    for child in self.children(window) {
+                        stack.push((child, depth + 1));
+                    }
+                }
+            }
+        }
+        
+        windows
+    }
+    
+    /// Client windows owned by `pid`.
+    pub fn windows_for_pid(&self, pid: u32) -> Vec<ClientWindow> {
+        self.client_windows().into_iter().filter(|w| w.pid == pid).collect()
+    }
+    
+    unsafe fn atom(&self, name: &[u8], only_if_exists: bool) -> xlib::Atom {
+        xlib::XInternAtom(self.display, name.as_ptr() as *const c_char, only_if_exists as c_int)
+    }
+    
+    unsafe fn children(&self, window: xlib::Window) -> Vec<xlib::Window> {
+        let mut root = 0;
+        let mut parent = 0;
+        let mut children: *mut xlib::Window = std::ptr::null_mut();
+        let mut count = 0;
+        
+        if xlib::XQueryTree(self.display, window, &mut root, &mut parent, &mut children, &mut count) == 0 {
+            return Vec::new();
+        }
+        if children.is_null() {
+            return Vec::new();
+        }
+        
+        let result = std::slice::from_raw_parts(children, count as usize).to_vec();
+        xlib::XFree(children as *mut _);
+        result
+    }
+    
+    /// Raw bytes of a window property, with its format (8, 16 or 32).
+    unsafe fn property(&self, window: xlib::Window, atom: xlib::Atom, kind: xlib::Atom) -> Option<(Vec<u8>, c_int)> {
+        let mut actual_type = 0;
+        let mut actual_format = 0;
+        let mut items = 0;
+        let mut bytes_after = 0;
+        let mut data: *mut c_uchar = std::ptr::null_mut();
+        
+        let status = xlib::XGetWindowProperty(
+            self.display, window, atom, 0, 1024, xlib::False, kind,
+            &mut actual_type, &mut actual_format, &mut items, &mut bytes_after, &mut data,
+        );
+        if status != xlib::Success as c_int || data.is_null() {
+            return None;
+        }
+        
+        // Xlib hands 32-bit items back as C longs
+        let item_size = match actual_format {
+            8 => 1,
+            16 => std::mem::size_of::<std::os::raw::c_short>(),
+            32 => std::mem::size_of::<std::os::raw::c_long>(),
+            _ => 0,
+        };
+        let bytes = std::slice::from_raw_parts(data, items as usize * item_size).to_vec();
+        xlib::XFree(data as *mut _);
+        
+        (items > 0).then_some((bytes, actual_format))
+    }
+    
+    unsafe fn cardinal_property(&self, window: xlib::Window, atom: xlib::Atom) -> Option<u32> {
+        let (bytes, format) = self.property(window, atom, xlib::XA_CARDINAL)?;
+        if format != 32 {
+            return None;
+        }
+        let value = std::ptr::read_unaligned(bytes.as_ptr() as *const c_ulong);
+        Some(value as u32)
+    }
+    
+    unsafe fn title(&self, window: xlib::Window) -> Option<String> {
+        let net_wm_name = self.atom(b"_NET_WM_NAME\0", false);
+        let utf8 = self.atom(b"UTF8_STRING\0", false);
+        if let Some((bytes, 8)) = self.property(window, net_wm_name, utf8) {
+            return Some(String::from_utf8_lossy(&bytes).into_owned());
+        }
+        
+        let mut name: *mut c_char = std::ptr::null_mut();
+        if xlib::XFetchName(self.display, window, &mut name) != 0 && !name.is_null() {
+            let title = CStr::from_ptr(name).to_string_lossy().into_owned();
+            xlib::XFree(name as *mut _);
+            return Some(title);
+        }
+        
+        None
+    }
+    
+    unsafe fn describe_window(&self, window: xlib::Window, root: xlib::Window, pid: u32) -> Option<ClientWindow> {
+        let mut attributes: xlib::XWindowAttributes = std::mem::zeroed();
+        if xlib::XGetWindowAttributes(self.display, window, &mut attributes) == 0 {
+            // Destroyed while we were walking the tree
+            return None;
+        }
+        
+        let (mut x, mut y, mut child) = (0, 0, 0);
+        xlib::XTranslateCoordinates(self.display, window, root, 0, 0, &mut x, &mut y, &mut child);
+        
+        Some(ClientWindow {
+            id: window,
+            pid,
+            title: self.title(window),
+            mapped: attributes.map_state == xlib::IsViewable,
+            x,
+            y,
+            width: attributes.width.max(0) as u32,
+            height: attributes.height.max(0) as u32,
+        })
+    }
+}
+
+impl Drop for XConnection {
+    fn drop(&mut self) {
+        // Closing a connection whose server went away would hit the fatal
+        // I/O error handler, so a dead connection is simply leaked.
+        if self.is_alive() {
+            unsafe {
+                xlib::XCloseDisplay(self.display);
+            }
+        }
+    }
+}
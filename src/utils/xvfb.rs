@@ -0,0 +1,135 @@
+use crate::utils::x11::XConnection;
+use crate::{Result, TauriMcpError};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{Child, Command};
+use tracing::{debug, info, warn};
+
+/// Display numbers below this are left to real X servers.
+const FIRST_DISPLAY: u32 = 99;
+const MAX_DISPLAYS: u32 = 100;
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadlessOptions {
+    pub width: u32,
+    pub height: u32,
+    pub depth: u8,
+    /// Xvfb or a compatible server accepting the same arguments
+    pub server_binary: String,
+}
+
+impl Default for HeadlessOptions {
+    fn default() -> Self {
+        Self {
+            width: 1280,
+            height: 800,
+            depth: 24,
+            server_binary: "Xvfb".to_string(),
+        }
+    }
+}
+
+/// A private Xvfb server, killed when stopped or dropped.
+pub struct VirtualDisplay {
+    number: u32,
+    child: Child,
+    options: HeadlessOptions,
+}
+
+impl VirtualDisplay {
+    pub async fn start(options: &HeadlessOptions) -> Result<Self> {
+        for number in FIRST_DISPLAY..FIRST_DISPLAY + MAX_DISPLAYS {
+            if Self::in_use(number) {
+                continue;
+            }
+            
+            match Self::start_on(number, options).await {
+                Ok(display) => return Ok(display),
+                // Another server may have raced us for this number; try the next one
+                Err(e) if Self::in_use(number) => {
+                    debug!("Display :{} taken while starting: {}", number, e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        
+        Err(TauriMcpError::ProcessError("No free X display number for headless mode".to_string()))
+    }
+    
+    async fn start_on(number: u32, options: &HeadlessOptions) -> Result<Self> {
+        let name = format!(":{}", number);
+        let screen = format!("{}x{}x{}", options.width, options.height, options.depth);
+        
+        info!("Starting {} on {} ({})", options.server_binary, name, screen);
+        
+        let mut child = Command::new(&options.server_binary)
+            .args([name.as_str(), "-screen", "0", screen.as_str(), "-nolisten", "tcp"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| TauriMcpError::ProcessError(format!(
+                "Failed to start {}: {}. Install Xvfb (e.g. `apt install xvfb`) to use headless mode",
+                options.server_binary, e
+            )))?;
+        
+        if let Some(stderr) = child.stderr.take() {
+            let name = name.clone();
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stderr).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    debug!("[Xvfb {}] {}", name, line);
+                }
+            });
+        }
+        
+        let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Err(TauriMcpError::ProcessError(format!(
+                    "{} exited during startup on {}: {}", options.server_binary, name, status
+                )));
+            }
+            
+            if XConnection::open(Some(&name)).is_ok() {
+                info!("Virtual display {} is ready", name);
+                return Ok(Self { number, child, options: options.clone() });
+            }
+            
+            if tokio::time::Instant::now() >= deadline {
+                let _ = child.kill().await;
+                return Err(TauriMcpError::ProcessError(format!(
+                    "{} did not accept connections on {} within {:?}", options.server_binary, name, STARTUP_TIMEOUT
+                )));
+            }
+            
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+    
+    fn in_use(number: u32) -> bool {
+        Path::new(&format!("/tmp/.X{}-lock", number)).exists()
+            || Path::new(&format!("/tmp/.X11-unix/X{}", number)).exists()
+    }
+    
+    /// Value for `DISPLAY`, e.g. `:99`.
+    pub fn name(&self) -> String {
+        format!(":{}", self.number)
+    }
+    
+    pub fn options(&self) -> &HeadlessOptions {
+        &self.options
+    }
+    
+    pub async fn stop(mut self) {
+        info!("Stopping virtual display {}", self.name());
+        if let Err(e) = self.child.kill().await {
+            warn!("Failed to stop virtual display {}: {}", self.name(), e);
+        }
+    }
+}
@@ -22,6 +22,696 @@ mod process_tests {
         let processes = manager.get_running_processes();
         assert_eq!(processes.len(), 0);
     }
+    
+    #[cfg(unix)]
+    #[tokio::test]
+    #[serial]
+    async fn test_launch_env_cwd_and_stdin() -> Result<()> {
+        use tauri_mcp::tools::logs::{LogQuery, LogStream};
+        use tauri_mcp::tools::process::LaunchOptions;
+        
+        let mut manager = ProcessManager::new();
+        let options = LaunchOptions::from_arguments(&serde_json::json!({
+            "env": { "TAURI_MCP_TEST": "hello", "HOME": null },
+            "cwd": "/tmp",
+            "stdin": true,
+        }))?;
+        let script = "echo \"$TAURI_MCP_TEST ${HOME:-unset} $(pwd)\"; read line; echo \"got $line\"; echo oops >&2";
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), script.to_string()], options).await?;
+        
+        manager.write_stdin(&process_id, "ping\n", true).await?;
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        
+        let page = manager.get_app_logs(&process_id, &LogQuery::default()).await?;
+        let logs: Vec<String> = page.logs.iter().map(|entry| entry.formatted()).collect();
+        assert!(logs.contains(&"[stdout] hello unset /tmp".to_string()), "{:?}", logs);
+        assert!(logs.contains(&"[stdout] got ping".to_string()), "{:?}", logs);
+        assert_eq!(page.next_cursor, 3);
+        
+        // Reading is non-destructive, and cursors page through the buffer
+        let first = manager.get_app_logs(&process_id, &LogQuery { head: Some(1), ..Default::default() }).await?;
+        assert_eq!(first.logs[0].seq, 0);
+        assert!(first.has_more);
+        let rest = manager.get_app_logs(&process_id, &LogQuery { since_cursor: Some(first.next_cursor), ..Default::default() }).await?;
+        assert_eq!(rest.logs.len(), 2);
+        let stderr = LogQuery { stream: Some(LogStream::Stderr), ..Default::default() };
+        let stderr = manager.get_app_logs(&process_id, &stderr).await?;
+        assert_eq!(stderr.logs.iter().map(|e| e.line.as_str()).collect::<Vec<_>>(), vec!["oops"]);
+        assert!(manager.write_stdin(&process_id, "again", false).await.is_err());
+        
+        manager.stop_app(&process_id, Default::default()).await?;
+        Ok(())
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    #[serial]
+    async fn test_find_running_apps_ignores_name_matches() -> Result<()> {
+        let mut manager = ProcessManager::new();
+        // Mentioning tauri on the command line is not evidence of a Tauri app
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), "sleep 5 # tauri".to_string()], Default::default()).await?;
+        let pid = manager.pid_of(&process_id)?;
+        
+        let apps = manager.find_running_apps()?;
+        assert!(apps.iter().all(|app| app["pid"] != pid), "{:?}", apps);
+        
+        manager.stop_app(&process_id, Default::default()).await?;
+        Ok(())
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    #[serial]
+    async fn test_monitor_resources_tree() -> Result<()> {
+        let mut manager = ProcessManager::new();
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), "sleep 5 & wait".to_string()], Default::default()).await?;
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        
+        let resources = manager.monitor_resources(&process_id, true).await?;
+        let tree = &resources["tree"];
+        let processes = tree["processes"].as_array().unwrap();
+        assert_eq!(processes.len(), 2, "{}", tree);
+        assert_eq!(processes[0]["role"], "main");
+        assert_eq!((processes[1]["role"].as_str(), processes[1]["name"].as_str()), (Some("child"), Some("sleep")));
+        assert!(processes.iter().all(|p| p["pss_bytes"].as_u64().unwrap() > 0 && p["threads"] == 1));
+        let rss: u64 = processes.iter().map(|p| p["rss_bytes"].as_u64().unwrap()).sum();
+        assert_eq!(tree["totals"]["rss_bytes"], rss);
+        assert!(manager.monitor_resources(&process_id, false).await?.get("tree").is_none());
+        
+        manager.stop_app(&process_id, Default::default()).await?;
+        Ok(())
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    #[serial]
+    async fn test_attach_follows_rotated_log_file() -> Result<()> {
+        use std::io::Write;
+        use tauri_mcp::tools::log_sources::LogSource;
+        use tauri_mcp::tools::logs::LogQuery;
+        
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("app.log");
+        std::fs::write(&path, "written before attaching\n")?;
+        let mut app = std::process::Command::new("sleep").arg("30")
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()?;
+        
+        let mut manager = ProcessManager::new();
+        // /dev/null is neither a file nor a pipe
+        assert!(manager.attach_to_app(app.id(), Some(&LogSource::ProcFds)).await.is_err());
+        
+        let source = LogSource::File { path: path.clone() };
+        let process_id = manager.attach_to_app(app.id(), Some(&source)).await?;
+        let mut log = std::fs::OpenOptions::new().append(true).open(&path)?;
+        writeln!(log, "first")?;
+        write!(log, "before ")?;
+        tokio::time::sleep(std::time::Duration::from_millis(400)).await;
+        writeln!(log, "rotation")?;
+        std::fs::rename(&path, dir.path().join("app.log.1"))?;
+        std::fs::write(&path, "after rotation\n")?;
+        tokio::time::sleep(std::time::Duration::from_millis(800)).await;
+        
+        let page = manager.get_app_logs(&process_id, &LogQuery::default()).await?;
+        let lines: Vec<&str> = page.logs.iter().map(|entry| entry.line.as_str()).collect();
+        assert_eq!(lines, vec!["first", "before rotation", "after rotation"]);
+        
+        app.kill()?;
+        app.wait()?;
+        Ok(())
+    }
+    
+    #[cfg(unix)]
+    #[tokio::test]
+    #[serial]
+    async fn test_restart_on_failure_keeps_alias() -> Result<()> {
+        use std::time::Duration;
+        use tauri_mcp::tools::process::LaunchOptions;
+        use tauri_mcp::tools::supervisor::ProcessState;
+        
+        let mut manager = ProcessManager::new();
+        let arguments = serde_json::json!({
+            "alias": "flaky",
+            "restart": "on-failure",
+            "max_restarts": 2,
+            "restart_backoff_ms": 50,
+        });
+        let args = vec!["-c".to_string(), "echo run; exit 3".to_string()];
+        let process_id = manager.launch_app("/bin/sh", args.clone(), LaunchOptions::from_arguments(&arguments)?).await?;
+        assert_eq!(process_id, "flaky");
+        assert!(manager.launch_app("/bin/sh", args, LaunchOptions::from_arguments(&arguments)?).await.is_err());
+        
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        let status = manager.status_of("flaky")?.expect("launched process has a status");
+        assert_eq!(status.state, ProcessState::Exited);
+        assert_eq!(status.run, 3);
+        assert_eq!(status.restarts.len(), 2);
+        assert_eq!(status.restarts[0].exit_code, Some(3));
+        
+        let logs: Vec<String> = manager.get_app_logs("flaky", &Default::default()).await?
+            .logs.iter().map(|entry| entry.formatted()).collect();
+        assert_eq!(logs.iter().filter(|l| *l == "[stdout] run").count(), 3, "{:?}", logs);
+        assert!(logs.contains(&"[restart] run 1 exited (exit code 3); restarting in 50 ms (restart 1 of 2)".to_string()), "{:?}", logs);
+        assert!(logs.last().is_some_and(|l| l.contains("giving up")), "{:?}", logs);
+        manager.stop_app("flaky", Default::default()).await?;
+        
+        // A pending restart is cancelled by stop_app
+        let options = LaunchOptions::from_arguments(&serde_json::json!({ "restart": "always", "restart_backoff_ms": 60000 }))?;
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), "exit 0".to_string()], options).await?;
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert_eq!(manager.status_of(&process_id)?.map(|s| s.state), Some(ProcessState::Restarting));
+        let report = tokio::time::timeout(Duration::from_secs(5), manager.stop_app(&process_id, Default::default())).await
+            .expect("stop_app cancels the backoff")?;
+        assert_eq!(report.exit_code, Some(0));
+        Ok(())
+    }
+    
+    #[cfg(unix)]
+    #[tokio::test]
+    #[serial]
+    async fn test_stop_app_escalates_and_reports_exit() -> Result<()> {
+        use std::time::Duration;
+        use tauri_mcp::tools::process::{LaunchOptions, StopOptions};
+        
+        let mut manager = ProcessManager::new();
+        
+        // Exits cleanly on SIGTERM after flushing a final line
+        let script = "trap 'echo flushed; exit 0' TERM; echo up; while true; do sleep 0.1; done";
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), script.to_string()], LaunchOptions::default()).await?;
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let report = manager.stop_app(&process_id, StopOptions::default()).await?;
+        assert_eq!(report.exit_code, Some(0));
+        assert!(!report.escalated);
+        assert!(report.final_logs.contains(&"[stdout] flushed".to_string()), "{:?}", report.final_logs);
+        
+        // Ignores SIGTERM, so the grace period runs out
+        let script = "trap '' TERM; while true; do sleep 0.1; done";
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), script.to_string()], LaunchOptions::default()).await?;
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let options = StopOptions { grace_period: Duration::from_millis(300), force: false };
+        let report = manager.stop_app(&process_id, options).await?;
+        assert!(report.escalated);
+        assert_eq!(report.signal_name.as_deref(), Some("SIGKILL"));
+        assert!(manager.get_running_processes().is_empty());
+        Ok(())
+    }
+    
+    #[cfg(unix)]
+    #[tokio::test]
+    #[serial]
+    async fn test_supervisor_records_panic_and_exit() -> Result<()> {
+        use tauri_mcp::tools::process::LaunchOptions;
+        use tauri_mcp::tools::supervisor::ProcessState;
+        
+        let mut manager = ProcessManager::new();
+        let mut events = manager.subscribe_events();
+        let script = r#"
+            echo "thread 'main' panicked at src/main.rs:2:5:" >&2
+            echo "boom" >&2
+            echo "stack backtrace:" >&2
+            echo "   0: demo::main" >&2
+            echo "             at ./src/main.rs:2:5" >&2
+            echo "note: Some details are omitted" >&2
+            exit 101
+        "#;
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), script.to_string()], LaunchOptions::default()).await?;
+        
+        let mut seen = Vec::new();
+        while !seen.contains(&"exited") {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(5), events.recv()).await
+                .expect("lifecycle event").expect("event channel open");
+            seen.push(event.event);
+        }
+        assert_eq!(seen, vec!["started", "panicked", "exited"]);
+        
+        let status = manager.status_of(&process_id)?.expect("launched process has a status");
+        assert_eq!(status.state, ProcessState::Exited);
+        assert_eq!(status.exit_code, Some(101));
+        assert!(status.crashed);
+        assert_eq!(status.panics[0].thread, "main");
+        assert_eq!(status.panics[0].location.as_deref(), Some("src/main.rs:2:5"));
+        assert_eq!(status.panics[0].message, "boom");
+        assert_eq!(status.panics[0].backtrace.len(), 2);
+        Ok(())
+    }
+    
+    #[test]
+    fn test_panic_message_ends_without_backtrace_note() {
+        use tauri_mcp::tools::supervisor::PanicDetector;
+        
+        // Second and later panics print no `note: run with RUST_BACKTRACE`
+        let mut detector = PanicDetector::default();
+        assert!(detector.feed("thread 'tokio-runtime-worker' panicked at src/lib.rs:9:1:").is_none());
+        assert!(detector.feed("index out of bounds").is_none());
+        let report = detector.feed("\x1b[2m2024-05-01T10:00:00.123Z\x1b[0m \x1b[32m INFO\x1b[0m app: still running").expect("log record ends the panic");
+        assert_eq!(report.message, "index out of bounds");
+        assert!(!detector.in_progress());
+        
+        assert!(detector.feed("thread 'main' panicked at src/main.rs:2:5:").is_none());
+        assert!(detector.feed("boom").is_none());
+        assert_eq!(detector.feed("").expect("blank line ends the panic").message, "boom");
+    }
+    
+    #[cfg(unix)]
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial]
+    async fn test_list_crashes_across_restarts() -> Result<()> {
+        use tauri_mcp::tools::crashes::list_crashes;
+        use tauri_mcp::tools::process::LaunchOptions;
+        
+        let mut manager = ProcessManager::new();
+        let mut events = manager.subscribe_events();
+        let script = r#"
+            echo "thread 'main' (4242) panicked at src/main.rs:7:9:" >&2
+            echo "boom: $RUST_BACKTRACE" >&2
+            echo "stack backtrace:" >&2
+            echo "   0:     0x55d1c2a1b2c3 - std[e28293b1aa0f68bd]::rt::lang_start" >&2
+            echo "                               at /rustc/library/std/src/rt.rs:205:5" >&2
+            echo "   1:     0x55d1c2a1b2d4 - app::main::h0123456789abcdef" >&2
+            echo "   2:     0x7f3a2051e9ee - <unknown>" >&2
+            exit 101
+        "#;
+        let options = LaunchOptions::from_arguments(&serde_json::json!({
+            "alias": "crashy",
+            "rust_backtrace": true,
+            "restart": "on-failure",
+            "max_restarts": 1,
+            "restart_backoff_ms": 10,
+        }))?;
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), script.to_string()], options).await?;
+        
+        let mut exits = 0;
+        while exits < 2 {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(5), events.recv()).await
+                .expect("lifecycle event").expect("event channel open");
+            exits += (event.event == "exited") as usize;
+        }
+        manager.stop_app(&process_id, Default::default()).await?;
+        
+        // The history outlives the process
+        let manager = tokio::sync::RwLock::new(manager);
+        let result = list_crashes(&manager, Some("crashy"), 0, true).await?;
+        let crashes = result["crashes"].as_array().unwrap();
+        assert_eq!(crashes.len(), 2, "{}", result);
+        assert_eq!((crashes[0]["run"].as_u64(), crashes[1]["run"].as_u64()), (Some(1), Some(2)));
+        
+        let crash = &crashes[1];
+        assert_eq!(crash["kind"], "panic");
+        assert_eq!(crash["exit"], "exit code 101");
+        assert_eq!(crash["panic"]["thread_id"], 4242);
+        assert_eq!(crash["panic"]["message"], "boom: full");
+        let frames = crash["panic"]["frames"].as_array().unwrap();
+        assert_eq!(frames[0]["function"], "std::rt::lang_start");
+        assert_eq!(frames[0]["location"], "/rustc/library/std/src/rt.rs:205:5");
+        assert_eq!(frames[1]["function"], "app::main");
+        // Outside the executable's mapping: left for the system libraries
+        assert_eq!(frames[2]["function"], "<unknown>");
+        
+        assert!(list_crashes(&manager, None, result["next_seq"].as_u64().unwrap(), false).await?["crashes"].as_array().unwrap().is_empty());
+        Ok(())
+    }
+    
+    #[cfg(unix)]
+    #[tokio::test]
+    #[serial]
+    async fn test_isolated_launch_seeds_and_cleans_profile() -> Result<()> {
+        use tauri_mcp::tools::logs::LogQuery;
+        use tauri_mcp::tools::process::LaunchOptions;
+        
+        let fixture = tempfile::tempdir()?;
+        std::fs::create_dir_all(fixture.path().join(".local/share/com.example.app"))?;
+        std::fs::write(fixture.path().join(".local/share/com.example.app/settings.json"), "{\"seeded\":true}")?;
+        
+        let mut manager = ProcessManager::new();
+        let options = LaunchOptions::from_arguments(&serde_json::json!({
+            "isolated": true,
+            "fixture_dir": fixture.path(),
+        }))?;
+        let script = "echo \"$HOME\"; cat \"$XDG_DATA_HOME/com.example.app/settings.json\"; echo; sleep 5";
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), script.to_string()], options).await?;
+        let profile = manager.isolated_profile(&process_id).expect("isolated profile").clone();
+        assert!(profile.root.starts_with(std::env::temp_dir()));
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        
+        let page = manager.get_app_logs(&process_id, &LogQuery::default()).await?;
+        let lines: Vec<&str> = page.logs.iter().map(|entry| entry.line.as_str()).collect();
+        assert_eq!(lines, vec![profile.home.to_str().unwrap(), "{\"seeded\":true}"]);
+        
+        let report = manager.stop_app(&process_id, Default::default()).await?;
+        assert_eq!(report.isolated_profile.map(|p| p.root), Some(profile.root.clone()));
+        assert!(!profile.root.exists());
+        // The fixture itself is never modified
+        assert!(fixture.path().join(".local/share/com.example.app/settings.json").is_file());
+        Ok(())
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_headless_launch_options() {
+        use tauri_mcp::tools::process::LaunchOptions;
+        
+        let options = LaunchOptions::from_arguments(&serde_json::json!({
+            "headless": true,
+            "resolution": "1920x1080",
+        })).unwrap();
+        let headless = options.headless.expect("headless options");
+        assert_eq!((headless.width, headless.height), (1920, 1080));
+        
+        assert!(LaunchOptions::from_arguments(&serde_json::json!({ "headless": true, "resolution": "big" })).is_err());
+        assert!(LaunchOptions::from_arguments(&serde_json::json!({})).unwrap().headless.is_none());
+    }
+}
+
+#[cfg(test)]
+mod build_tests {
+    use super::*;
+    use tauri_mcp::tools::build::{build_project, BuildOptions, TauriProject};
+    
+    #[tokio::test]
+    #[serial]
+    async fn test_build_project_reports_diagnostics() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let src_tauri = dir.path().join("src-tauri");
+        std::fs::create_dir_all(src_tauri.join("src"))?;
+        std::fs::write(src_tauri.join("Cargo.toml"), "[package]\nname = \"demo\"\nversion = \"0.1.0\"\nedition = \"2021\"\n[workspace]\n")?;
+        std::fs::write(src_tauri.join("tauri.conf.json"), r#"{"identifier": "com.example.demo"}"#)?;
+        std::fs::write(src_tauri.join("src/main.rs"), "fn main() { let x: u32 = \"s\"; }\n")?;
+        
+        assert!(TauriProject::detect(&dir.path().join("missing")).is_none());
+        let project = TauriProject::detect(dir.path()).expect("project detected from its root");
+        assert_eq!(project.src_tauri, src_tauri);
+        
+        let report = build_project(&project, &BuildOptions::default()).await?;
+        assert!(!report.success);
+        assert_eq!(report.errors, 1);
+        assert_eq!(report.diagnostics[0].code.as_deref(), Some("E0308"));
+        assert_eq!(report.diagnostics[0].line, Some(1));
+        
+        std::fs::write(src_tauri.join("src/main.rs"), "fn main() {}\n")?;
+        let report = build_project(&project, &BuildOptions::default()).await?;
+        assert!(report.success);
+        assert!(report.frontend.is_none());
+        assert!(report.executable.expect("built binary").ends_with("demo"));
+        
+        // The frontend is built first, and a failure stops the build there
+        std::fs::write(src_tauri.join("tauri.conf.json"), r#"{"identifier": "com.example.demo", "build": {"beforeBuildCommand": "echo bundled > dist.txt"}}"#)?;
+        let report = build_project(&project, &BuildOptions::default()).await?;
+        assert!(report.success && report.frontend.expect("frontend built").success);
+        assert!(dir.path().join("dist.txt").is_file());
+        
+        let dev_server = BuildOptions { dev_server: true, ..Default::default() };
+        std::fs::write(src_tauri.join("tauri.conf.json"), r#"{"identifier": "com.example.demo", "build": {"beforeBuildCommand": {"script": "echo broken >&2; exit 3"}}}"#)?;
+        assert!(build_project(&project, &dev_server).await?.frontend.is_none());
+        let report = build_project(&project, &BuildOptions::default()).await?;
+        let frontend = report.frontend.expect("frontend build reported");
+        assert!(!report.success && report.executable.is_none());
+        assert_eq!((frontend.exit_code, frontend.output), (Some(3), vec!["broken".to_string()]));
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod ready_tests {
+    use super::*;
+    use tauri_mcp::tools::process::{LaunchOptions, ProcessManager};
+    use tauri_mcp::tools::ready::{wait_for_log, wait_until_ready, LogWaitOptions, ReadyOptions};
+    use tokio::sync::RwLock;
+    
+    #[tokio::test]
+    #[serial]
+    async fn test_wait_for_log_and_exit_bundle() -> Result<()> {
+        assert!(ReadyOptions::from_arguments(&serde_json::json!({ "wait_for": {} })).is_err());
+        assert!(ReadyOptions::from_arguments(&serde_json::json!({ "wait_for": { "log": "(" } })).is_err());
+        
+        let manager = RwLock::new(ProcessManager::new());
+        // Colored like tracing output; the pattern sees the line without escapes
+        let script = r"echo booting; sleep 0.3; printf 'ready on \033[1m1420\033[0m\n'; sleep 0.3; echo bye >&2; exit 3";
+        let process_id = manager.write().await
+            .launch_app("/bin/sh", vec!["-c".to_string(), script.to_string()], LaunchOptions::default()).await?;
+        
+        let options = ReadyOptions::from_arguments(&serde_json::json!({ "wait_for": { "log": "ready on \\d+" } }))?.unwrap();
+        let ready = wait_until_ready(&manager, &process_id, &options).await?;
+        assert_eq!(ready["ready"], true);
+        assert_eq!(ready["matched_log"], "[stdout] ready on \u{1b}[1m1420\u{1b}[0m");
+        
+        let options = ReadyOptions::from_arguments(&serde_json::json!({ "wait_for": { "port": 1, "timeout_ms": 5000 } }))?.unwrap();
+        let bundle = wait_until_ready(&manager, &process_id, &options).await?;
+        assert_eq!(bundle["ready"], false);
+        assert_eq!(bundle["process"]["running"], false);
+        assert_eq!(bundle["pending"], serde_json::json!(["port"]));
+        assert!(bundle["last_logs"].as_array().unwrap().contains(&serde_json::json!("[stderr] bye")));
+        Ok(())
+    }
+    
+    #[tokio::test]
+    #[serial]
+    async fn test_wait_for_log_context_and_exit() -> Result<()> {
+        let manager = RwLock::new(ProcessManager::new());
+        let script = "echo boot; sleep 0.3; echo one; echo listening on 1420; echo after; sleep 0.5; exit 0";
+        let process_id = manager.write().await
+            .launch_app("/bin/sh", vec!["-c".to_string(), script.to_string()], LaunchOptions::default()).await?;
+        
+        let options = LogWaitOptions::from_arguments(&serde_json::json!({ "pattern": "listening on \\d+", "context": 1 }))?;
+        let found = wait_for_log(&manager, &process_id, &options).await?;
+        assert_eq!(found["matched"], true);
+        assert_eq!(found["line"], "[stdout] listening on 1420");
+        assert_eq!(found["before"], serde_json::json!(["[stdout] one"]));
+        assert_eq!(found["after"], serde_json::json!(["[stdout] after"]));
+        
+        // Retained lines count unless new_only is set
+        let options = LogWaitOptions::from_arguments(&serde_json::json!({ "pattern": "^boot$", "timeout_ms": 5000 }))?;
+        assert_eq!(wait_for_log(&manager, &process_id, &options).await?["matched"], true);
+        let options = LogWaitOptions { new_only: true, ..options };
+        let missed = wait_for_log(&manager, &process_id, &options).await?;
+        assert_eq!(missed["matched"], false);
+        assert!(missed["reason"].as_str().unwrap().starts_with("Process exited"), "{}", missed);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod logs_tests {
+    use tauri_mcp::tools::logs::{LogBuffer, LogFile, LogFileOptions, LogLevel, LogSearch, LogStream};
+    
+    #[test]
+    fn test_search_levels_context_and_cap() {
+        let mut buffer = LogBuffer::new(100);
+        for line in [
+            "\x1b[2m2024-05-01T10:00:00.000Z\x1b[0m \x1b[32m INFO\x1b[0m app: starting",
+            "[2024-05-01T10:00:01Z WARN  app::db] slow query",
+            "plain line",
+            "[2024-05-01][10:00:02][ERROR][app] request failed",
+            "thread 'main' panicked at src/main.rs:2:5:",
+            "healthcheck ERROR ignored",
+        ] {
+            buffer.push(LogStream::Stderr, line.to_string());
+        }
+        assert_eq!(LogLevel::of_line("DEBUG app > details"), Some(LogLevel::Debug));
+        
+        let search = LogSearch::from_arguments(&serde_json::json!({ "level": "warn", "context": 1 })).unwrap();
+        let result = buffer.search(&search);
+        assert_eq!(result.total_matches, 3);
+        assert_eq!(result.matches_by_level["error"], 2);
+        // Adjacent matches and their context form a single group
+        assert_eq!(result.groups.len(), 1);
+        assert_eq!(result.groups[0][0].line, "2024-05-01T10:00:00.000Z  INFO app: starting");
+        assert!(!result.groups[0][0].is_match);
+        
+        let search = LogSearch::from_arguments(&serde_json::json!({
+            "pattern": "ERROR|query",
+            "exclude": "healthcheck",
+            "max_matches": 1,
+        })).unwrap();
+        let result = buffer.search(&search);
+        assert_eq!((result.total_matches, result.returned_matches, result.truncated), (2, 1, true));
+        assert_eq!(result.groups[0][0].seq, 1);
+    }
+    
+    #[test]
+    fn test_log_file_rotation_and_retention() -> tauri_mcp::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let options = LogFileOptions { dir: dir.path().to_path_buf(), max_bytes: 200, max_files: 3 };
+        let mut file = LogFile::open("my app/../x", &options)?;
+        
+        let mut buffer = LogBuffer::new(10);
+        for n in 0..20 {
+            file.append(&buffer.push(LogStream::Stdout, format!("line {:02} of the app's output", n)));
+        }
+        
+        let paths = file.paths();
+        assert_eq!(paths.len(), 3, "{:?}", paths);
+        assert!(paths.iter().all(|path| path.starts_with(dir.path())));
+        assert_eq!(paths[0].file_name().unwrap(), "my_app_.._x.log");
+        let current = std::fs::read_to_string(&paths[0])?;
+        assert!(current.trim_end().ends_with("[stdout] line 19 of the app's output"), "{}", current);
+        assert!(current.len() <= 200);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod sampling_tests {
+    use super::*;
+    use tauri_mcp::tools::process::ProcessManager;
+    use tauri_mcp::tools::sampling::{ResourceSample, SampleOptions, SampleReport};
+    
+    fn sample(elapsed_ms: u64, cpu_usage: f32, rss_bytes: u64) -> ResourceSample {
+        ResourceSample { elapsed_ms, pid: 1, cpu_usage, rss_bytes, pss_bytes: None, threads: None, open_fds: None }
+    }
+    
+    #[test]
+    fn test_report_stats_and_leak_trend() {
+        const MIB: u64 = 1024 * 1024;
+        // 2 MiB more every 6 s: 20 MiB/min with some jitter
+        let growing: Vec<ResourceSample> = (0..20)
+            .map(|n| sample(n * 6000, n as f32, 100 * MIB + n * 2 * MIB + (n % 3) * 100_000))
+            .collect();
+        let report = SampleReport::new(&growing, &SampleOptions::default());
+        let cpu = report.cpu_usage.unwrap();
+        assert_eq!((cpu.min, cpu.max, cpu.p95), (0.0, 19.0, 18.0));
+        let trend = report.memory_trend.unwrap();
+        assert_eq!(trend.source, "rss");
+        assert!(trend.leak_suspected, "{:?}", trend);
+        assert!((trend.growth_bytes_per_min / MIB as f64 - 20.0).abs() < 0.5, "{:?}", trend);
+        assert_eq!(report.sparkline.unwrap().cpu_usage.chars().count(), 20);
+        
+        // Noise around a flat line is not a leak
+        let flat: Vec<ResourceSample> = (0..20).map(|n| sample(n * 6000, 1.0, 100 * MIB + (n % 2) * 4 * MIB)).collect();
+        let options = SampleOptions::from_arguments(&serde_json::json!({ "format": "csv" })).unwrap();
+        let report = SampleReport::new(&flat, &options);
+        assert!(!report.memory_trend.unwrap().leak_suspected);
+        assert!(report.sparkline.is_none());
+        assert_eq!(report.csv.unwrap().lines().count(), 21);
+        
+        // Long foreground runs would block the server; background ones may run on
+        assert!(SampleOptions::from_arguments(&serde_json::json!({ "duration_ms": 120_000 })).is_err());
+        assert!(SampleOptions::from_arguments(&serde_json::json!({ "action": "start", "duration_ms": 120_000 })).is_ok());
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial]
+    async fn test_background_sampling_reads_cpu() -> Result<()> {
+        let mut manager = ProcessManager::new();
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), "while :; do :; done".to_string()], Default::default()).await?;
+        // sysinfo needs some CPU time on record before it reports usage
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        
+        let options = SampleOptions::from_arguments(&serde_json::json!({ "action": "start", "interval_ms": 100 }))?;
+        manager.start_sampling(&process_id, options)?;
+        // Priming takes two refreshes 200 ms apart before the first sample
+        tokio::time::sleep(std::time::Duration::from_millis(850)).await;
+        let running = manager.sampling_report(&process_id)?;
+        assert!(running.running && running.samples >= 4, "{:?}", running);
+        
+        let report = manager.stop_sampling(&process_id)?;
+        assert!(!report.running);
+        // Even the first sample has a real CPU reading
+        assert!(report.cpu_usage.unwrap().min > 10.0, "{:?}", report);
+        assert!(report.pss_bytes.is_some());
+        assert!(manager.sampling_report(&process_id).is_err());
+        
+        manager.stop_app(&process_id, Default::default()).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod alerts_tests {
+    use super::*;
+    use tauri_mcp::tools::process::ProcessManager;
+    
+    #[cfg(target_os = "linux")]
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial]
+    async fn test_resource_alerts_trip_once_and_clear() -> Result<()> {
+        use tauri_mcp::tools::alerts::AlertThresholds;
+        use tauri_mcp::tools::logs::LogQuery;
+        
+        let mut manager = ProcessManager::new();
+        let mut events = manager.subscribe_events();
+        let process_id = manager.launch_app("/bin/sh", vec!["-c".to_string(), "while :; do :; done".to_string()], Default::default()).await?;
+        
+        let thresholds = AlertThresholds::from_arguments(&serde_json::json!({
+            "rss_mb": 1,
+            "cpu_percent": 5,
+            "cpu_sustained_ms": 300,
+            "interval_ms": 100,
+        }))?;
+        manager.set_resource_alerts(&process_id, thresholds)?;
+        tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+        
+        let alerts = manager.get_alerts(Some(&process_id), 0)?;
+        let metrics: Vec<&str> = alerts["alerts"].as_array().unwrap().iter().filter_map(|a| a["metric"].as_str()).collect();
+        // Each threshold trips once while it stays exceeded
+        assert_eq!(metrics, vec!["rss_mb", "cpu_percent"], "{}", alerts);
+        assert_eq!(alerts["active"].as_array().unwrap().len(), 2);
+        assert_eq!(alerts["monitored"][0]["thresholds"]["cpu_sustained"], 300);
+        
+        let logs = manager.get_app_logs(&process_id, &LogQuery::default()).await?;
+        assert!(logs.logs.iter().any(|entry| entry.line.starts_with("[alert] CPU")), "{:?}", logs.logs);
+        let mut alert_events = 0;
+        while let Ok(event) = events.try_recv() {
+            alert_events += (event.event == "alert") as usize;
+        }
+        assert_eq!(alert_events, 2);
+        
+        manager.set_resource_alerts(&process_id, AlertThresholds::from_arguments(&serde_json::json!({}))?)?;
+        let alerts = manager.get_alerts(None, alerts["next_seq"].as_u64().unwrap())?;
+        assert!(alerts["alerts"].as_array().unwrap().is_empty() && alerts["monitored"].as_array().unwrap().is_empty());
+        
+        manager.stop_app(&process_id, Default::default()).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod startup_tests {
+    use super::*;
+    use tauri_mcp::tools::process::ProcessManager;
+    
+    #[cfg(target_os = "linux")]
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial]
+    async fn test_measure_startup_runs_cold() -> Result<()> {
+        use tauri_mcp::tools::startup::{measure_startup, StartupOptions};
+        
+        // Cold runs only delete directories inside the user's cache directory
+        let cache = tempfile::tempdir()?;
+        std::env::set_var("XDG_CACHE_HOME", cache.path());
+        let cache_dir = cache.path().join("com.example.app");
+        let outside = StartupOptions::from_arguments(&serde_json::json!({ "cold": true, "cache_dirs": [cache.path()] }))?;
+        let manager = tokio::sync::RwLock::new(ProcessManager::new());
+        assert!(measure_startup(&manager, "/bin/true", vec![], Default::default(), &outside).await.is_err());
+        assert!(StartupOptions::from_arguments(&serde_json::json!({ "runs": 10, "timeout_ms": 30_000 })).is_err());
+        let traversal = StartupOptions::from_arguments(&serde_json::json!({ "cold": true, "identifier": "../" }))?;
+        assert!(measure_startup(&manager, "/bin/true", vec![], Default::default(), &traversal).await.is_err());
+        
+        let options = StartupOptions::from_arguments(&serde_json::json!({
+            "runs": 2,
+            "cold": true,
+            "cache_dirs": [cache_dir],
+            "timeout_ms": 2000,
+            "peak_rss_ms": 300,
+        }))?;
+        
+        // The app recreates its cache, so every cold run has one to clear
+        let script = format!("mkdir -p {}; echo ready; sleep 10", cache_dir.display());
+        let report = measure_startup(&manager, "/bin/sh", vec!["-c".to_string(), script], Default::default(), &options).await;
+        std::env::remove_var("XDG_CACHE_HOME");
+        let report = report?;
+        
+        let runs = report["runs"].as_array().unwrap();
+        assert_eq!(runs.len(), 2);
+        assert!(runs.iter().all(|run| run["first_log_ms"].is_u64() && run["peak_rss_bytes"].as_u64() > Some(0)), "{}", report);
+        assert!(runs[0].get("caches_cleared").is_none());
+        assert_eq!(runs[1]["caches_cleared"][0], serde_json::json!(cache_dir));
+        assert_eq!(report["first_log_ms"]["runs"], 2);
+        assert!(report["first_log_ms"]["stats"]["max"].as_f64() < Some(2000.0), "{}", report);
+        assert!(manager.read().await.get_running_processes().is_empty());
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -48,7 +738,6 @@ mod input_tests {
 
 #[cfg(test)]
 mod debug_tests {
-    use super::*;
     use tauri_mcp::tools::debug::DebugTools;
     
     #[test]
@@ -57,6 +746,61 @@ mod debug_tests {
     }
 }
 
+#[cfg(test)]
+mod threads_tests {
+    use super::*;
+    
+    #[test]
+    fn test_parse_backtraces() {
+        use tauri_mcp::tools::threads::parse_backtraces;
+        
+        let gdb = r#"[New LWP 4243]
+Thread 2 (Thread 0x7f3a1bfff6c0 (LWP 4243) "tokio-runtime-w"):
+#0  0x00007f3a2051e9ee in __GI___poll (fds=0x1, nfds=1, timeout=-1) at ../sysdeps/unix/sysv/linux/poll.c:29
+#1  0x000055d1c2a1b2c3 in app::worker::run<(u8, u16)> (self=0x0) at src/worker.rs:42
+
+Thread 1 (Thread 0x7f3a1c8a1980 (LWP 4242) "app"):
+#0  futex_wait (private=0, expected=2, futex_word=0x55d1c2) at ../sysdeps/nptl/futex-internal.h:146
+#1  0x00007f3a2046b2a5 in g_mutex_lock () from /lib/x86_64-linux-gnu/libglib-2.0.so.0
+"#;
+        let stacks = parse_backtraces(gdb);
+        assert_eq!(stacks.len(), 2);
+        let main = &stacks[&4242];
+        assert_eq!((main[0].function.as_str(), main[0].address.as_deref()), ("futex_wait", None));
+        assert_eq!(main[1].location.as_deref(), Some("/lib/x86_64-linux-gnu/libglib-2.0.so.0"));
+        assert_eq!(stacks[&4243][1].function, "app::worker::run<(u8, u16)>");
+        assert_eq!(stacks[&4243][1].location.as_deref(), Some("src/worker.rs:42"));
+        
+        let eu_stack = "PID 4242 - process\nTID 4242:\n#0  0x00007f3a2051e9ee __poll\n#1  0x00007f3a2046d3c4 g_main_context_iterate.constprop.0\n";
+        let stacks = parse_backtraces(eu_stack);
+        assert_eq!(stacks[&4242][1].function, "g_main_context_iterate.constprop.0");
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    #[serial]
+    async fn test_dump_threads_from_proc() -> Result<()> {
+        use tauri_mcp::tools::process::ProcessManager;
+        use tauri_mcp::tools::threads::{dump_threads, BacktraceTool};
+        
+        let mut manager = ProcessManager::new();
+        let process_id = manager.launch_app("/bin/sleep", vec!["5".to_string()], Default::default()).await?;
+        let pid = manager.pid_of(&process_id)?;
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        
+        let dump = dump_threads(pid, BacktraceTool::None).await?;
+        assert_eq!(dump["thread_count"], 1);
+        assert_eq!(dump["threads"][0]["tid"], pid);
+        assert_eq!(dump["threads"][0]["main"], true);
+        assert_eq!(dump["threads"][0]["state"], "S", "{}", dump);
+        assert_eq!(dump["main_thread"]["tid"], pid);
+        assert_eq!(dump["backtrace"]["tool"], "none");
+        
+        manager.stop_app(&process_id, Default::default()).await?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod ipc_tests {
     use super::*;
@@ -70,4 +814,163 @@ mod ipc_tests {
         assert!(!handlers.is_empty());
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod manifest_tests {
+    use tauri_mcp::manifest::{dxt_manifest, tools_manifest, ManifestOptions, ServerKind};
+    use tauri_mcp::tools::definitions::tool_names;
+    
+    #[test]
+    fn test_dxt_manifest_lists_every_tool() {
+        let manifest = dxt_manifest(&ManifestOptions::default());
+        let names: Vec<&str> = manifest["tools"].as_array().unwrap()
+            .iter()
+            .filter_map(|tool| tool["name"].as_str())
+            .collect();
+        assert_eq!(names, tool_names());
+        assert_eq!(manifest["version"], env!("CARGO_PKG_VERSION"));
+    }
+    
+    #[test]
+    fn test_manifest_options() {
+        let options = ManifestOptions {
+            server: ServerKind::Binary,
+            package_version: "9.9.9".to_string(),
+            platforms: vec!["linux".to_string()],
+        };
+        let manifest = dxt_manifest(&options);
+        assert_eq!(manifest["version"], "9.9.9");
+        assert_eq!(manifest["server"]["type"], "binary");
+        assert_eq!(manifest["compatibility"]["platforms"][0], "linux");
+        
+        let tools = tools_manifest(&options);
+        assert!(tools["tools"][0]["inputSchema"].is_object());
+    }
+}
+
+#[cfg(test)]
+mod diagnostics_tests {
+    use tauri_mcp::tools::diagnostics::run_environment_checks;
+    
+    #[tokio::test]
+    async fn test_failed_checks_have_remediation() {
+        let checks = run_environment_checks().await;
+        let names: Vec<&str> = checks.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["display", "input", "webdriver", "devtools"]);
+        
+        for check in checks.iter().filter(|c| !c.ok) {
+            assert!(check.remediation.is_some(), "{} has no remediation hint", check.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod app_data_tests {
+    use super::*;
+    use tauri_mcp::tools::app_data::{list_app_data, read_app_data_file, reset_app_data, snapshot_app_data, AppDataTarget, ListAppDataOptions};
+    use tauri_mcp::tools::process::{LaunchOptions, ProcessManager};
+    use tokio::sync::RwLock;
+    
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    #[serial]
+    async fn test_app_data_of_isolated_launch() -> Result<()> {
+        let fixture = tempfile::tempdir()?;
+        let data = fixture.path().join(".local/share/com.example.notes");
+        std::fs::create_dir_all(&data)?;
+        std::fs::write(data.join("settings.json"), "{\"theme\":\"dark\"}")?;
+        let has_sqlite = std::process::Command::new("sqlite3")
+            .arg(data.join("notes.db"))
+            .arg("CREATE TABLE notes (id INTEGER, body TEXT); INSERT INTO notes VALUES (1, 'hi');")
+            .status()
+            .is_ok_and(|status| status.success());
+        
+        let manager = RwLock::new(ProcessManager::new());
+        let options = LaunchOptions::from_arguments(&serde_json::json!({ "isolated": true, "fixture_dir": fixture.path() }))?;
+        let process_id = manager.write().await.launch_app("/bin/sh", vec!["-c".to_string(), "sleep 5".to_string()], options).await?;
+        let profile = manager.read().await.isolated_profile(&process_id).unwrap().clone();
+        
+        let target = AppDataTarget::from_arguments(&serde_json::json!({ "identifier": "com.example.notes", "process_id": process_id }));
+        let dirs = target.resolve(&manager).await?;
+        assert_eq!(dirs.data, profile.data_dir.join("com.example.notes"));
+        assert_eq!(dirs.log, dirs.data.join("logs"));
+        
+        let listing = list_app_data(&dirs, &ListAppDataOptions::from_arguments(&serde_json::json!({})))?;
+        let files = listing["files"].as_array().unwrap();
+        assert!(files.iter().any(|f| f["dir"] == "data" && f["path"] == "settings.json" && f["size"] == 16), "{}", listing);
+        
+        let settings = read_app_data_file(&dirs, "settings.json", None, None).await?;
+        assert_eq!(settings["format"], "json");
+        assert_eq!(settings["json"]["theme"], "dark");
+        assert!(read_app_data_file(&dirs, "../../../../../etc/passwd", None, None).await.is_err());
+        if has_sqlite {
+            let db = read_app_data_file(&dirs, "notes.db", Some("data"), None).await?;
+            assert_eq!(db["format"], "sqlite");
+            assert_eq!(db["sqlite"]["tables"][0]["rows"], 1, "{}", db);
+            assert_eq!(db["sqlite"]["tables"][0]["sample"][0]["body"], "hi");
+        }
+        
+        let snapshot = snapshot_app_data(&dirs, &[])?;
+        let snapshot = PathBuf::from(snapshot["snapshot"].as_str().unwrap());
+        // The app is still running, so resetting its directories needs force
+        assert!(target.ensure_stopped(&manager, false).await.is_err());
+        target.ensure_stopped(&manager, true).await?;
+        let reset = reset_app_data(&dirs, &["data".to_string()], None)?;
+        assert_eq!(reset["removed"][0]["dir"], "data");
+        assert!(!dirs.data.exists());
+        
+        reset_app_data(&dirs, &[], Some(&snapshot))?;
+        assert!(dirs.data.join("settings.json").is_file());
+        
+        std::fs::remove_dir_all(&snapshot)?;
+        manager.write().await.stop_app(&process_id, Default::default()).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod fs_snapshot_tests {
+    use tauri_mcp::tools::fs_snapshot::{fs_diff, fs_snapshot, FsSnapshotOptions, FsSnapshots};
+    
+    #[test]
+    fn test_fs_diff_reports_changes_with_text_diffs() -> tauri_mcp::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        std::fs::write(root.join("config.json"), "{\n  \"theme\": \"light\",\n  \"zoom\": 1\n}\n")?;
+        std::fs::write(root.join("old.log"), "bye\n")?;
+        std::fs::write(root.join("same.txt"), "same\n")?;
+        std::fs::create_dir(root.join("node_modules"))?;
+        
+        let snapshots = parking_lot::Mutex::new(FsSnapshots::new());
+        let options = FsSnapshotOptions::from_arguments(&serde_json::json!({ "paths": [root] }));
+        let before = fs_snapshot(&snapshots, &options)?;
+        assert_eq!((before["files"].as_u64(), before["dirs"].as_u64()), (Some(3), Some(1)));
+        
+        std::fs::write(root.join("config.json"), "{\n  \"theme\": \"dark\",\n  \"zoom\": 1\n}\n")?;
+        std::fs::remove_file(root.join("old.log"))?;
+        std::fs::create_dir(root.join("cache"))?;
+        std::fs::write(root.join("cache/new.bin"), [0u8, 159, 146, 150])?;
+        std::fs::write(root.join("node_modules/ignored.js"), "")?;
+        let same = std::fs::File::options().write(true).open(root.join("same.txt"))?;
+        same.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(60))?;
+        
+        let diff = fs_diff(&snapshots, before["snapshot_id"].as_str().unwrap(), None)?;
+        let paths = |key: &str| diff[key].as_array().unwrap().iter()
+            .map(|change| change["path"].as_str().unwrap().strip_prefix(root.to_str().unwrap()).unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(paths("created"), ["/cache", "/cache/new.bin"], "{}", diff);
+        assert_eq!(paths("modified"), ["/config.json"]);
+        assert_eq!(paths("deleted"), ["/old.log"]);
+        assert_eq!(diff["touched"][0], root.join("same.txt").to_str().unwrap());
+        assert_eq!(diff["modified"][0]["diff"], "@@ -1,4 +1,4 @@\n {\n-  \"theme\": \"light\",\n+  \"theme\": \"dark\",\n   \"zoom\": 1\n }\n");
+        assert_eq!(diff["deleted"][0]["diff"], "@@ -1,1 +0,0 @@\n-bye\n");
+        // Binary contents are only compared by checksum
+        assert!(diff["created"][1].get("diff").is_none());
+        
+        // The current state was kept, so nothing changed since
+        let again = fs_diff(&snapshots, diff["to"].as_str().unwrap(), None)?;
+        assert_eq!(again["summary"], serde_json::json!({ "created": 0, "modified": 0, "deleted": 0, "touched": 0 }));
+        Ok(())
+    }
+}